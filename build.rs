@@ -0,0 +1,83 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Capture build metadata for `--version --verbose`
+//!
+//! Sets `NOEMOJI_COMMIT`, `NOEMOJI_BUILD_DATE`, and `NOEMOJI_RUSTC` for the
+//! crate. Each falls back to "unknown" when it cannot be determined, such as
+//! when building from a source archive without git.
+
+use std::{
+    env,
+    path::Path,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    // Rebuild when HEAD moves, whether to another branch or another commit.
+    // A source archive has no .git, and a missing file would make Cargo
+    // rerun this script on every build.
+    if Path::new(".git/HEAD").exists() {
+        println!("cargo:rerun-if-changed=.git/HEAD");
+    }
+    if let Ok(head) = std::fs::read_to_string(".git/HEAD")
+        && let Some(reference) = head.trim().strip_prefix("ref: ")
+        && Path::new(".git").join(reference).exists()
+    {
+        println!("cargo:rerun-if-changed=.git/{}", reference);
+    }
+
+    let commit = output("git", &["rev-parse", "--short=12", "HEAD"]);
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let rustc = output(&rustc, &["--version"]);
+    println!(
+        "cargo:rustc-env=NOEMOJI_COMMIT={}",
+        commit.as_deref().unwrap_or("unknown")
+    );
+    println!("cargo:rustc-env=NOEMOJI_BUILD_DATE={}", build_date());
+    println!(
+        "cargo:rustc-env=NOEMOJI_RUSTC={}",
+        rustc.as_deref().unwrap_or("unknown")
+    );
+}
+
+/// Run `program` and return its trimmed standard output, if it succeeded
+fn output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    (output.status.success() && !stdout.trim().is_empty()).then(|| stdout.trim().to_owned())
+}
+
+/// Today's date as YYYY-MM-DD in UTC, or the date of `SOURCE_DATE_EPOCH` for
+/// reproducible builds
+fn build_date() -> String {
+    let seconds = env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs())
+        });
+    // Convert days since 1970-01-01 to a civil date (Howard Hinnant's algorithm)
+    let days = seconds / 86400 + 719468;
+    let era = days / 146097;
+    let day_of_era = days % 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// EOF
@@ -0,0 +1,244 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Baselines of known violations
+//!
+//! A baseline records the violations present in a codebase at some point, so
+//! that later runs report only violations introduced since. Entries are keyed
+//! by input name, character, and category, and count occurrences rather than
+//! positions, so editing a file without adding violations keeps it clean:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "entries": [
+//!     { "file": "README.md", "char": "→", "category": "arrows", "count": 2 }
+//!   ]
+//! }
+//! ```
+
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::check::{CharCategory, Violation};
+
+/// Version of the baseline file format written by this crate
+const VERSION: u32 = 1;
+
+/// Errors that can occur while reading or writing a baseline file
+#[derive(Debug, Error)]
+pub enum BaselineError {
+    /// File I/O error while reading or writing the baseline
+    #[error("{0}")]
+    Io(#[from] io::Error),
+    /// The baseline file is not valid baseline JSON
+    #[error("invalid baseline: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+    /// The baseline file was written by an incompatible version
+    #[error("unsupported baseline version {0}, expected {VERSION}")]
+    UnsupportedVersion(u32),
+}
+
+/// A set of known violations, counted per input, character, and category
+///
+/// # Examples
+///
+/// ```
+/// use noemoji::{baseline::Baseline, check_str};
+///
+/// let mut baseline = Baseline::new();
+/// check_str("a → b", |v| baseline.record("notes.txt", v));
+///
+/// // The same violation elsewhere in the file is still known...
+/// let mut new = Vec::new();
+/// check_str("→ a b", |v| {
+///     if !baseline.suppress("notes.txt", v) {
+///         new.push(v.clone());
+///     }
+/// });
+/// assert!(new.is_empty());
+///
+/// // ...but each recorded occurrence suppresses only one violation
+/// check_str("→", |v| assert!(!baseline.suppress("notes.txt", v)));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    /// Remaining occurrences, keyed by input name, character, and category key
+    counts: BTreeMap<(String, char, &'static str), usize>,
+}
+
+/// On-disk form of a [`Baseline`]
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    entries: Vec<Entry>,
+}
+
+/// One baselined character and category within one input
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    file: String,
+    char: char,
+    category: String,
+    count: usize,
+}
+
+impl Baseline {
+    /// Create an empty baseline, which suppresses nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read a baseline written by [`Baseline::save`]
+    ///
+    /// Entries whose category this version does not know are ignored.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, BaselineError> {
+        let file: BaselineFile = serde_json::from_str(&fs::read_to_string(path)?)?;
+        if file.version != VERSION {
+            return Err(BaselineError::UnsupportedVersion(file.version));
+        }
+        let mut baseline = Self::new();
+        for entry in file.entries {
+            let category = CharCategory::ALL
+                .iter()
+                .find(|category| category.key() == entry.category);
+            if let Some(category) = category {
+                *baseline
+                    .counts
+                    .entry((entry.file, entry.char, category.key()))
+                    .or_default() += entry.count;
+            }
+        }
+        Ok(baseline)
+    }
+
+    /// Write this baseline as JSON, sorted by input name
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), BaselineError> {
+        let entries = self
+            .counts
+            .iter()
+            .filter(|&(_, &count)| count > 0)
+            .map(|((file, ch, category), &count)| Entry {
+                file: file.clone(),
+                char: *ch,
+                category: (*category).to_owned(),
+                count,
+            })
+            .collect();
+        let file = BaselineFile {
+            version: VERSION,
+            entries,
+        };
+        let mut json = serde_json::to_string_pretty(&file)?;
+        json.push('\n');
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Add one occurrence of `v` in the input named `file`
+    pub fn record(&mut self, file: &str, v: &Violation) {
+        *self
+            .counts
+            .entry((file.to_owned(), v.ch, v.category.key()))
+            .or_default() += 1;
+    }
+
+    /// Consume one known occurrence of `v` in the input named `file`
+    ///
+    /// Returns true if `v` was known and should not be reported.
+    pub fn suppress(&mut self, file: &str, v: &Violation) -> bool {
+        match self
+            .counts
+            .get_mut(&(file.to_owned(), v.ch, v.category.key()))
+        {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Keep only the occurrences in inputs whose name satisfies `keep`
+    pub fn retain_files(&mut self, mut keep: impl FnMut(&str) -> bool) {
+        self.counts.retain(|(file, _, _), _| keep(file));
+    }
+
+    /// Add every occurrence in `other` to this baseline
+    pub fn merge(&mut self, other: Baseline) {
+        for (key, count) in other.counts {
+            *self.counts.entry(key).or_default() += count;
+        }
+    }
+
+    /// Returns the total number of occurrences in this baseline
+    pub fn len(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Returns true if this baseline suppresses nothing
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_str;
+    use tempfile::TempDir;
+
+    fn record_all(baseline: &mut Baseline, file: &str, input: &str) {
+        check_str(input, |v| baseline.record(file, v));
+    }
+
+    #[test]
+    fn suppress_is_keyed_by_file_char_and_category() {
+        let mut baseline = Baseline::new();
+        record_all(&mut baseline, "a.txt", "→ ✓");
+        assert_eq!(baseline.len(), 2);
+
+        let mut reported = Vec::new();
+        check_str("← ✓ →", |v| {
+            if !baseline.suppress("a.txt", v) {
+                reported.push(v.ch);
+            }
+        });
+        assert_eq!(reported, vec!['←']);
+        assert!(baseline.is_empty());
+
+        record_all(&mut baseline, "a.txt", "→");
+        check_str("→", |v| assert!(!baseline.suppress("b.txt", v)));
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("baseline.json");
+        let mut baseline = Baseline::new();
+        record_all(&mut baseline, "b.txt", "🚀");
+        record_all(&mut baseline, "a.txt", "→ x →");
+
+        baseline.save(&path).unwrap();
+        assert_eq!(Baseline::load(&path).unwrap(), baseline);
+        let json = fs::read_to_string(&path).unwrap();
+        assert!(json.find("a.txt").unwrap() < json.find("b.txt").unwrap());
+        assert!(json.contains("\"category\": \"arrows\""));
+    }
+
+    #[test]
+    fn load_rejects_other_versions() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("baseline.json");
+        fs::write(&path, r#"{"version": 2, "entries": []}"#).unwrap();
+        assert!(matches!(
+            Baseline::load(&path),
+            Err(BaselineError::UnsupportedVersion(2))
+        ));
+    }
+}
+
+// EOF
@@ -6,21 +6,890 @@
 
 use std::{
     borrow::Cow,
-    fs::File,
-    io::{self, BufRead, BufReader},
-    path::PathBuf,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Chain, Cursor, Read, Write},
+    iter::Peekable,
+    mem,
+    ops::{ControlFlow, RangeInclusive},
+    path::{Path, PathBuf},
+    str::{CharIndices, FromStr},
 };
 
+use memmap2::Mmap;
+use serde::{Deserialize, Deserializer};
+
+use crate::{
+    confusables,
+    directive::{self, Allowed, Suppressions},
+    lang::{Language, Unchecked},
+};
 use thiserror::Error;
+use unicode_normalization::{UnicodeNormalization, is_nfc, is_nfd};
+use unicode_properties::{GeneralCategory, GeneralCategoryGroup, UnicodeGeneralCategory};
+use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
+
+/// Inclusive code point ranges of prohibited arrow characters
+const ARROW_RANGES: &[(char, char)] = &[
+    ('\u{2190}', '\u{21FF}'), // Arrows
+    ('\u{27F0}', '\u{27FF}'), // Supplemental Arrows-A
+    ('\u{2900}', '\u{297F}'), // Supplemental Arrows-B
+];
+
+/// Inclusive code point ranges of prohibited emoji characters
+const EMOJI_RANGES: &[(char, char)] = &[
+    ('\u{1F1E6}', '\u{1F1FF}'), // Regional indicator symbols (flags)
+    ('\u{1F300}', '\u{1F5FF}'), // Miscellaneous Symbols and Pictographs
+    ('\u{1F600}', '\u{1F64F}'), // Emoticons
+    ('\u{1F680}', '\u{1F6FF}'), // Transport and Map Symbols
+    ('\u{1F900}', '\u{1F9FF}'), // Supplemental Symbols and Pictographs
+    ('\u{1FA70}', '\u{1FAFF}'), // Symbols and Pictographs Extended-A
+    ('\u{FE0F}', '\u{FE0F}'),   // Variation selector-16 (emoji presentation)
+];
+
+/// Prohibited checkmark and cross mark characters, as single-character ranges
+const CHECKMARK_RANGES: &[(char, char)] = &[
+    ('\u{2611}', '\u{2612}'), // ☑ ☒ ballot boxes with check / x
+    ('\u{2705}', '\u{2705}'), // ✅ white heavy check mark
+    ('\u{2713}', '\u{2714}'), // ✓ ✔ check marks
+    ('\u{2717}', '\u{2718}'), // ✗ ✘ ballot x marks
+    ('\u{274C}', '\u{274C}'), // ❌ cross mark
+];
+
+/// Inclusive code point ranges of prohibited box drawing characters
+const BOX_DRAWING_RANGES: &[(char, char)] = &[
+    ('\u{2500}', '\u{257F}'), // Box Drawing
+    ('\u{2580}', '\u{259F}'), // Block Elements
+];
+
+/// Prohibited math symbols with ASCII equivalents, as single-character ranges
+///
+/// Symbols without a clear ASCII equivalent, such as ∞ and °, are
+/// deliberately absent.
+const MATH_RANGES: &[(char, char)] = &[
+    ('\u{00B1}', '\u{00B1}'), // ± plus-minus sign
+    ('\u{00D7}', '\u{00D7}'), // × multiplication sign
+    ('\u{00F7}', '\u{00F7}'), // ÷ division sign
+    ('\u{2212}', '\u{2212}'), // − minus sign
+    ('\u{2260}', '\u{2260}'), // ≠ not equal to
+    ('\u{2264}', '\u{2265}'), // ≤ ≥ less/greater-than or equal to
+];
+
+/// Inclusive code point ranges of prohibited superscripts and subscripts
+const SUPERSUB_RANGES: &[(char, char)] = &[
+    ('\u{00B2}', '\u{00B3}'), // ² ³ superscript two and three
+    ('\u{00B9}', '\u{00B9}'), // ¹ superscript one
+    ('\u{2070}', '\u{209F}'), // Superscripts and Subscripts
+];
+
+/// Inclusive code point ranges of prohibited vulgar fractions
+const FRACTION_RANGES: &[(char, char)] = &[
+    ('\u{00BC}', '\u{00BE}'), // ¼ ½ ¾
+    ('\u{2150}', '\u{215F}'), // ⅐ through ⅞, and ⅟ fraction numerator one
+    ('\u{2189}', '\u{2189}'), // ↉ vulgar fraction zero thirds
+];
+
+/// Inclusive code point ranges of prohibited decorative characters
+const DECORATIVE_RANGES: &[(char, char)] = &[
+    ('\u{2022}', '\u{2023}'), // • ‣ bullets
+    ('\u{2043}', '\u{2043}'), // ⁃ hyphen bullet
+    ('\u{25A0}', '\u{25FF}'), // Geometric Shapes
+    ('\u{2605}', '\u{2606}'), // ★ ☆ stars
+    ('\u{2660}', '\u{2667}'), // ♠ ♡ ♢ ♣ ♤ ♥ ♦ ♧ card suits
+    ('\u{2726}', '\u{2740}'), // ✦ ✧ ✨ ✩ ... dingbat stars and florettes
+];
+
+/// Inclusive code point ranges of prohibited lookalike punctuation
+const PUNCTUATION_RANGES: &[(char, char)] = &[
+    ('\u{2013}', '\u{2014}'), // – — en and em dashes
+    ('\u{2018}', '\u{201F}'), // ‘ ’ ‚ ‛ “ ” „ ‟ curly quotes
+    ('\u{2026}', '\u{2026}'), // … horizontal ellipsis
+];
+
+/// Inclusive code point ranges of prohibited typographic ligatures
+///
+/// Only the Latin ligatures of the Alphabetic Presentation Forms block are
+/// listed; its Armenian and Hebrew forms are genuine text and stay allowed.
+const LIGATURE_RANGES: &[(char, char)] = &[
+    ('\u{203C}', '\u{203D}'), // ‼ ‽ double exclamation mark, interrobang
+    ('\u{2047}', '\u{2049}'), // ⁇ ⁈ ⁉ double question and exclamation marks
+    ('\u{FB00}', '\u{FB06}'), // ﬀ ﬁ ﬂ ﬃ ﬄ ﬅ ﬆ Latin ligatures
+];
+
+/// Inclusive code point ranges of prohibited whitespace characters
+const WHITESPACE_RANGES: &[(char, char)] = &[
+    ('\u{00A0}', '\u{00A0}'), // no-break space
+    ('\u{1680}', '\u{1680}'), // ogham space mark
+    ('\u{2000}', '\u{200D}'), // en/em spaces through zero width joiner
+    ('\u{2028}', '\u{2029}'), // line and paragraph separators
+    ('\u{202F}', '\u{202F}'), // narrow no-break space
+    ('\u{205F}', '\u{2060}'), // medium mathematical space, word joiner
+    ('\u{3000}', '\u{3000}'), // ideographic space
+];
+
+/// Invisible characters, which are reported by code point and name rather
+/// than printed raw
+const INVISIBLE: &[char] = &[
+    '\u{00A0}', // no-break space
+    '\u{1680}', // ogham space mark
+    '\u{2000}', // en quad
+    '\u{2001}', // em quad
+    '\u{2002}', // en space
+    '\u{2003}', // em space
+    '\u{2004}', // three-per-em space
+    '\u{2005}', // four-per-em space
+    '\u{2006}', // six-per-em space
+    '\u{2007}', // figure space
+    '\u{2008}', // punctuation space
+    '\u{2009}', // thin space
+    '\u{200A}', // hair space
+    '\u{200B}', // zero width space
+    '\u{200C}', // zero width non-joiner
+    '\u{200D}', // zero width joiner
+    '\u{2028}', // line separator
+    '\u{2029}', // paragraph separator
+    '\u{202F}', // narrow no-break space
+    '\u{205F}', // medium mathematical space
+    '\u{2060}', // word joiner
+    '\u{3000}', // ideographic space
+    '\u{FE0F}', // variation selector-16
+    '\u{FEFF}', // zero width no-break space
+];
+
+/// Legal and technical symbols that are always permitted
+const ALLOWED_SYMBOLS: &[char] = &[
+    '©', '®', '™', '℠', // Copyright and trademark
+    '§', '¶', '†', '‡', // Section, paragraph, and footnote marks
+    '°', '∞', // Degree and infinity
+];
+
+/// Category tables in precedence order; the first matching category wins
+const CATEGORY_RANGES: &[(&[(char, char)], CharCategory)] = &[
+    (ARROW_RANGES, CharCategory::Arrow),
+    (CHECKMARK_RANGES, CharCategory::Checkmark),
+    (BOX_DRAWING_RANGES, CharCategory::BoxDrawing),
+    (MATH_RANGES, CharCategory::Math),
+    (SUPERSUB_RANGES, CharCategory::Supersub),
+    (FRACTION_RANGES, CharCategory::Fractions),
+    (DECORATIVE_RANGES, CharCategory::Decorative),
+    (PUNCTUATION_RANGES, CharCategory::Punctuation),
+    (LIGATURE_RANGES, CharCategory::Ligature),
+    (WHITESPACE_RANGES, CharCategory::Whitespace),
+    (EMOJI_RANGES, CharCategory::Emoji),
+];
+
+/// Returns true if `ch` falls within any of the inclusive `ranges`
+fn in_ranges(ch: char, ranges: &[(char, char)]) -> bool {
+    ranges
+        .iter()
+        .any(|&(start, end)| (start..=end).contains(&ch))
+}
+
+/// Category of a prohibited character
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharCategory {
+    /// Arrows such as → and ⇒ (use `->`, `=>`, etc.)
+    Arrow,
+    /// Checkmarks and cross marks such as ✓ and ✗ (use `[x]` and `[ ]`)
+    Checkmark,
+    /// Box drawing and block elements such as ┌ ─ │ █ (use `+`, `-`, `|`)
+    BoxDrawing,
+    /// Math symbols such as ≤ ≥ ≠ × (use `<=`, `>=`, `!=`, `*`)
+    Math,
+    /// Superscripts and subscripts such as ² and ₁ (use `^2` and `_1`)
+    Supersub,
+    /// Vulgar fractions such as ½ and ¾ (use `1/2` and `3/4`)
+    Fractions,
+    /// Decorative stars, bullets, and shapes such as ★ ● ♦ • (use `-` or `*`)
+    Decorative,
+    /// Lookalike punctuation such as curly quotes, dashes, and ellipses
+    Punctuation,
+    /// Typographic ligatures such as ﬁ and ﬀ, and combined marks such as ‽
+    /// (spell out `fi`, `ff`, `?!`)
+    Ligature,
+    /// Non-ASCII and zero-width whitespace (use a regular ASCII space)
+    Whitespace,
+    /// Emoji, pictographs, flags, and emoji presentation selectors
+    Emoji,
+    /// Characters prohibited by a `deny` list in configuration
+    Custom,
+    /// Input whose last line does not end in a newline (opt-in)
+    ///
+    /// Reported once, just past the end of the last line, with `ch` set to
+    /// `'\n'`.
+    MissingFinalNewline,
+    /// UTF-8 byte order mark at the start of the input (opt-in)
+    ///
+    /// A leading BOM is otherwise skipped, and never counted in columns.
+    ByteOrderMark,
+    /// Letters that look like ASCII, such as full-width `Ａ` or Cyrillic `а`,
+    /// used in otherwise ASCII text (opt-in)
+    ///
+    /// Whether a character is reported depends on the rest of its line; see
+    /// [`confusables::find`].
+    Confusable,
+    /// Text not in the [`NormalizationForm`] set with `--normalize`, such as
+    /// an `e` followed by a combining acute accent where NFC wants `é` (opt-in)
+    ///
+    /// Reported once per grapheme cluster, at its first character, with the
+    /// normalized cluster as the suggestion.
+    Normalization,
+    /// Any other non-ASCII character, including the international text,
+    /// currency, and legal symbols otherwise permitted (opt-in, with
+    /// `--ascii-only`)
+    ///
+    /// Characters matching another enabled rule are reported under that
+    /// rule instead, and the `allow` list is still honored.
+    NonAscii,
+}
+
+impl CharCategory {
+    /// All categories, in reporting order
+    pub const ALL: &[CharCategory] = &[
+        Self::Arrow,
+        Self::Checkmark,
+        Self::BoxDrawing,
+        Self::Math,
+        Self::Supersub,
+        Self::Fractions,
+        Self::Decorative,
+        Self::Punctuation,
+        Self::Ligature,
+        Self::Whitespace,
+        Self::Emoji,
+        Self::Custom,
+        Self::MissingFinalNewline,
+        Self::ByteOrderMark,
+        Self::Confusable,
+        Self::Normalization,
+        Self::NonAscii,
+    ];
+
+    /// Returns this category's rank when sorting by severity, lowest first
+    ///
+    /// Emoji, the strongest sign of generated text, rank first and non-ASCII
+    /// whitespace, the most likely to be accidental, ranks last.
+    pub const fn priority(self) -> u8 {
+        match self {
+            Self::Emoji => 0,
+            Self::Arrow => 1,
+            Self::Checkmark => 2,
+            Self::Decorative => 3,
+            Self::BoxDrawing => 4,
+            Self::Math => 5,
+            Self::Supersub => 6,
+            Self::Fractions => 7,
+            Self::Custom => 8,
+            Self::Punctuation => 9,
+            Self::Ligature => 10,
+            Self::ByteOrderMark => 11,
+            Self::MissingFinalNewline => 12,
+            Self::Whitespace => 13,
+            Self::Confusable => 14,
+            Self::Normalization => 15,
+            Self::NonAscii => 16,
+        }
+    }
+
+    /// Returns this category's bit in a [`Checker`] mask
+    const fn bit(self) -> u32 {
+        1 << self as u32
+    }
+
+    /// Returns true if this rule is disabled unless explicitly enabled
+    const fn is_opt_in(self) -> bool {
+        matches!(
+            self,
+            Self::MissingFinalNewline
+                | Self::ByteOrderMark
+                | Self::Confusable
+                | Self::Normalization
+                | Self::NonAscii
+        )
+    }
+
+    /// Returns the human-readable name of this category
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Arrow => "arrow",
+            Self::Checkmark => "checkmark",
+            Self::BoxDrawing => "box drawing",
+            Self::Math => "math symbol",
+            Self::Supersub => "superscript or subscript",
+            Self::Fractions => "vulgar fraction",
+            Self::Decorative => "decorative symbol",
+            Self::Punctuation => "punctuation",
+            Self::Ligature => "ligature",
+            Self::Whitespace => "whitespace",
+            Self::Emoji => "emoji",
+            Self::Custom => "custom character",
+            Self::MissingFinalNewline => "missing final newline",
+            Self::ByteOrderMark => "byte order mark",
+            Self::Confusable => "ASCII confusable",
+            Self::Normalization => "unnormalized text",
+            Self::NonAscii => "non-ASCII character",
+        }
+    }
+
+    /// Returns the key naming this rule in configuration
+    ///
+    /// This is the `[rules]` toggle name, or `deny` for [`Self::Custom`].
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Arrow => "arrows",
+            Self::Checkmark => "checkmarks",
+            Self::BoxDrawing => "box_drawing",
+            Self::Math => "math",
+            Self::Supersub => "supersub",
+            Self::Fractions => "fractions",
+            Self::Decorative => "decorative",
+            Self::Punctuation => "punctuation",
+            Self::Ligature => "ligatures",
+            Self::Whitespace => "whitespace",
+            Self::Emoji => "emoji",
+            Self::Custom => "deny",
+            Self::MissingFinalNewline => "final_newline",
+            Self::ByteOrderMark => "byte_order_mark",
+            Self::Confusable => "confusables",
+            Self::Normalization => "normalization",
+            Self::NonAscii => "non_ascii",
+        }
+    }
+
+    /// Look up a category by its [`key`](Self::key) or [`name`](Self::name)
+    ///
+    /// Matching ignores case, and treats `-`, `_`, and spaces alike.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noemoji::CharCategory;
+    ///
+    /// assert_eq!(CharCategory::parse("arrows"), Some(CharCategory::Arrow));
+    /// assert_eq!(CharCategory::parse("Box-Drawing"), Some(CharCategory::BoxDrawing));
+    /// assert_eq!(CharCategory::parse("math symbol"), Some(CharCategory::Math));
+    /// assert_eq!(CharCategory::parse("sparkles"), None);
+    /// ```
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalize = |s: &str| s.to_ascii_lowercase().replace(['-', ' '], "_");
+        let value = normalize(value);
+        Self::ALL.iter().copied().find(|category| {
+            normalize(category.key()) == value || normalize(category.name()) == value
+        })
+    }
 
-/// Prohibited Unicode characters that should use ASCII equivalents
-const PROHIBITED_CHARS: &[char] = &[
-    '→', // Use -> instead
-    '←', // Use <- instead
-    '↑', // Use ^ instead
-    '↓', // Use v instead
+    /// Returns why this rule exists and what to write instead
+    pub const fn explanation(self) -> &'static str {
+        match self {
+            Self::Arrow => {
+                "Unicode arrows such as → ← ⇒ ↑ are rarely typed by hand and are a\n\
+                 telltale sign of generated text. Use ASCII instead: `->` and `<-`\n\
+                 for arrows, `=>` and `<=` for implications, `<->` for two-way\n\
+                 relations, and `^` or `v` for up and down."
+            }
+            Self::Checkmark => {
+                "Checkmarks and crosses such as ✓ ✔ ✗ ✘ ✅ ❌ are decoration that\n\
+                 ASCII expresses just as clearly. Use `[x]` for done or yes and\n\
+                 `[ ]` for not done or no."
+            }
+            Self::BoxDrawing => {
+                "Box drawing and block characters such as ┌ ─ │ └ █ render\n\
+                 inconsistently across fonts and terminals. Draw diagrams and tables\n\
+                 with ASCII: `+` for corners, `-` for horizontal lines, and `|` for\n\
+                 vertical lines."
+            }
+            Self::Math => {
+                "Math symbols such as ≤ ≥ ≠ × ÷ ± look out of place in code and\n\
+                 plain-text documentation. Use the ASCII operators programmers\n\
+                 already know: `<=`, `>=`, `!=`, `*`, `/`, and `+/-`."
+            }
+            Self::Supersub => {
+                "Superscripts and subscripts such as ² ³ ⁿ ₁ ₂ are raised or lowered\n\
+                 copies of ordinary characters, so `x²` cannot be searched for as\n\
+                 `x^2` and fonts render them unevenly. Write exponents with `^`\n\
+                 and indices with `_`, as in `x^2` and `a_1`, or spell them out."
+            }
+            Self::Fractions => {
+                "Vulgar fractions such as ½ ¾ ⅓ pack a whole fraction into one\n\
+                 character that few keyboards can type and tools cannot parse as\n\
+                 a number. Write the fraction with a slash, as in `1/2` and `3/4`,\n\
+                 or use a decimal such as `0.5`."
+            }
+            Self::Decorative => {
+                "Decorative stars, bullets, and shapes such as ★ ● ♦ • add visual\n\
+                 noise without meaning. Use `-` or `*` for list bullets, and plain\n\
+                 words for emphasis."
+            }
+            Self::Punctuation => {
+                "Curly quotes, long dashes, and ellipses such as ‘ ’ “ ” — – … are\n\
+                 inserted by word processors and generators, but break searching\n\
+                 and copy-paste in code. Use the ASCII forms: `'` and `\"` for\n\
+                 quotes, `...` for an ellipsis, `--` for an em dash — setting off a\n\
+                 clause, and `-` for an en dash – joining a range such as 1-10."
+            }
+            Self::Ligature => {
+                "Typographic ligatures such as ﬁ ﬂ ﬀ are single characters that\n\
+                 only look like the letters they join, so words containing them\n\
+                 cannot be searched for or spell-checked, and marks such as ‽ ‼\n\
+                 fold two punctuation marks into one. Spell them out: `fi`, `fl`,\n\
+                 `ff`, `?!`, and `!!`."
+            }
+            Self::Whitespace => {
+                "Non-breaking, zero-width, and other Unicode spaces are invisible in\n\
+                 most editors, yet change how text compares, wraps, and tokenizes.\n\
+                 Use a regular ASCII space, or remove zero-width characters\n\
+                 entirely."
+            }
+            Self::Emoji => {
+                "Emoji are the most recognizable signature of AI-generated text and\n\
+                 rarely belong in code, commit messages, or technical documentation.\n\
+                 Remove them, or say what they meant in words."
+            }
+            Self::Custom => {
+                "This character is listed in the `deny` setting of a `.noemoji.toml`\n\
+                 configuration file, so this project prohibits it even though it is\n\
+                 permitted by default. Remove it or rewrite the text without it."
+            }
+            Self::MissingFinalNewline => {
+                "Text files should end with a newline, as POSIX tools expect, so that\n\
+                 concatenation and diffs behave. This rule is off by default; enable\n\
+                 it with `--require-final-newline` or `final_newline = true`."
+            }
+            Self::ByteOrderMark => {
+                "UTF-8 files do not need a byte order mark, and a leading U+FEFF\n\
+                 confuses tools that expect text to start with its first character.\n\
+                 This rule is off by default; enable it with `--check-bom` or\n\
+                 `byte_order_mark = true`; with the rule on, `--fix` strips the mark."
+            }
+            Self::Confusable => {
+                "Full-width forms such as Ａ and Cyrillic or Greek letters such as а\n\
+                 and ο look exactly like ASCII, but break searching, linking, and\n\
+                 compiling when mixed into English words and identifiers. Use the\n\
+                 ASCII letter. Genuine Cyrillic or Greek text is not reported. This\n\
+                 rule is off by default; enable it with `--check-confusables` or\n\
+                 `confusables = true`."
+            }
+            Self::Normalization => {
+                "The same accented letter can be stored precomposed, as é, or as an\n\
+                 e followed by a combining accent. Both look identical, yet differ\n\
+                 in bytes, so they defeat searching and add noise to diffs. Keep\n\
+                 each file in one normalization form, usually NFC. This rule is off\n\
+                 by default; enable it with `--normalize nfc` or `normalize = \"nfc\"`,\n\
+                 and `--fix` rewrites text into that form."
+            }
+            Self::NonAscii => {
+                "Strict mode reports every character outside ASCII, including\n\
+                 accented letters, other scripts, currency signs, and the legal\n\
+                 symbols that are otherwise permitted, for projects that must stay\n\
+                 pure ASCII. Write the text in ASCII, or list deliberate exceptions\n\
+                 in `allow`. This rule is off by default; enable it with\n\
+                 `--ascii-only` or `non_ascii = true`."
+            }
+        }
+    }
+
+    /// Classify a character, returning its category if it is prohibited
+    ///
+    /// Only the built-in rules are consulted, regardless of which are enabled,
+    /// so this never returns [`Self::Custom`]. Confusables depend on their
+    /// surrounding text, and normalization on the characters that follow, so
+    /// this never returns [`Self::Confusable`] or [`Self::Normalization`]
+    /// either. Nor does it return [`Self::NonAscii`], which only a
+    /// [`Checker`] in strict mode reports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noemoji::CharCategory;
+    ///
+    /// assert_eq!(CharCategory::classify('→'), Some(CharCategory::Arrow));
+    /// assert_eq!(CharCategory::classify('é'), None);
+    /// ```
+    pub fn classify(ch: char) -> Option<Self> {
+        // Fast path: ASCII is never prohibited
+        if ch.is_ascii() {
+            return None;
+        }
+        // Latin ligatures and modifier letters such as ⁿ are letters, so check
+        // them before letters are let through as international text
+        if in_ranges(ch, LIGATURE_RANGES) {
+            return Some(Self::Ligature);
+        }
+        if in_ranges(ch, SUPERSUB_RANGES) {
+            return Some(Self::Supersub);
+        }
+        if is_international_text(ch) || is_allowed_exception(ch) {
+            return None;
+        }
+        CATEGORY_RANGES
+            .iter()
+            .find(|(ranges, _)| in_ranges(ch, ranges))
+            .map(|&(_, category)| category)
+    }
+}
+
+impl fmt::Display for CharCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Display adapter that renders a character for diagnostics
+///
+/// Visible characters are shown quoted, followed by their code point and
+/// Unicode name (`'→' U+2192 RIGHTWARDS ARROW`). Invisible characters such as
+/// non-breaking or zero-width spaces are shown by code point and name alone
+/// (`U+00A0 NO-BREAK SPACE`), since printing them raw would be unreadable.
+/// The name is omitted for characters that have none, such as controls.
+///
+/// # Examples
+///
+/// ```
+/// use noemoji::check::DisplayChar;
+///
+/// assert_eq!(DisplayChar('→').to_string(), "'→' U+2192 RIGHTWARDS ARROW");
+/// assert_eq!(DisplayChar('\u{00A0}').to_string(), "U+00A0 NO-BREAK SPACE");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayChar(pub char);
+
+impl fmt::Display for DisplayChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ch = self.0;
+        if is_visible(ch) {
+            write!(f, "'{}' ", ch)?;
+        }
+        write!(f, "U+{:04X}", ch as u32)?;
+        match unicode_names2::name(ch) {
+            Some(name) => write!(f, " {}", name),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns true if `ch` can be printed raw in diagnostics, rather than only
+/// by code point and name
+pub fn is_visible(ch: char) -> bool {
+    !ch.is_control() && !INVISIBLE.contains(&ch)
+}
+
+/// Returns true if `ch` is a letter, combining mark, or decimal digit in any
+/// script, such as the letters of `café`, `世界`, or `Москва`
+///
+/// Such characters are ordinary text and are never prohibited. The emoji
+/// presentation selector U+FE0F is a mark but only styles emoji, so it is
+/// excluded.
+fn is_international_text(ch: char) -> bool {
+    if ch == '\u{FE0F}' {
+        return false;
+    }
+    match ch.general_category_group() {
+        GeneralCategoryGroup::Letter | GeneralCategoryGroup::Mark => true,
+        _ => ch.general_category() == GeneralCategory::DecimalNumber,
+    }
+}
+
+/// Returns true if `ch` is always permitted, even inside a detected block
+///
+/// This covers every currency symbol (Unicode general category `Sc`) plus a
+/// fixed set of legal and technical symbols such as `©`, `§`, and `°`.
+///
+/// # Examples
+///
+/// ```
+/// use noemoji::check::is_allowed_exception;
+///
+/// assert!(is_allowed_exception('€'));
+/// assert!(is_allowed_exception('§'));
+/// assert!(!is_allowed_exception('★'));
+/// ```
+pub fn is_allowed_exception(ch: char) -> bool {
+    ch.general_category() == GeneralCategory::CurrencySymbol || ALLOWED_SYMBOLS.contains(&ch)
+}
+
+/// ASCII replacements for characters with a clear equivalent, sorted by
+/// character for binary search
+const SUGGESTIONS: &[(char, &str)] = &[
+    ('\u{00A0}', " "),    // no-break space
+    ('\u{00B1}', "+/-"),  // ± plus-minus sign
+    ('\u{00B2}', "^2"),   // ² superscript two
+    ('\u{00B3}', "^3"),   // ³ superscript three
+    ('\u{00B9}', "^1"),   // ¹ superscript one
+    ('\u{00BC}', "1/4"),  // ¼ vulgar fraction one quarter
+    ('\u{00BD}', "1/2"),  // ½ vulgar fraction one half
+    ('\u{00BE}', "3/4"),  // ¾ vulgar fraction three quarters
+    ('\u{00D7}', "*"),    // × multiplication sign
+    ('\u{00F7}', "/"),    // ÷ division sign
+    ('\u{1680}', " "),    // ogham space mark
+    ('\u{2000}', " "),    // en quad
+    ('\u{2001}', " "),    // em quad
+    ('\u{2002}', " "),    // en space
+    ('\u{2003}', " "),    // em space
+    ('\u{2004}', " "),    // three-per-em space
+    ('\u{2005}', " "),    // four-per-em space
+    ('\u{2006}', " "),    // six-per-em space
+    ('\u{2007}', " "),    // figure space
+    ('\u{2008}', " "),    // punctuation space
+    ('\u{2009}', " "),    // thin space
+    ('\u{200A}', " "),    // hair space
+    ('\u{2013}', "-"),    // – en dash
+    ('\u{2014}', "--"),   // — em dash
+    ('\u{2018}', "'"),    // ‘ left single quotation mark
+    ('\u{2019}', "'"),    // ’ right single quotation mark
+    ('\u{201A}', "'"),    // ‚ single low-9 quotation mark
+    ('\u{201B}', "'"),    // ‛ single high-reversed-9 quotation mark
+    ('\u{201C}', "\""),   // “ left double quotation mark
+    ('\u{201D}', "\""),   // ” right double quotation mark
+    ('\u{201E}', "\""),   // „ double low-9 quotation mark
+    ('\u{201F}', "\""),   // ‟ double high-reversed-9 quotation mark
+    ('\u{2022}', "-"),    // • bullet
+    ('\u{2023}', "-"),    // ‣ triangular bullet
+    ('\u{2026}', "..."),  // … horizontal ellipsis
+    ('\u{202F}', " "),    // narrow no-break space
+    ('\u{203C}', "!!"),   // ‼ double exclamation mark
+    ('\u{203D}', "?!"),   // ‽ interrobang
+    ('\u{2043}', "-"),    // ⁃ hyphen bullet
+    ('\u{2047}', "??"),   // ⁇ double question mark
+    ('\u{2048}', "?!"),   // ⁈ question exclamation mark
+    ('\u{2049}', "!?"),   // ⁉ exclamation question mark
+    ('\u{205F}', " "),    // medium mathematical space
+    ('\u{2070}', "^0"),   // ⁰ superscript zero
+    ('\u{2071}', "^i"),   // ⁱ superscript Latin small letter i
+    ('\u{2074}', "^4"),   // ⁴ superscript four
+    ('\u{2075}', "^5"),   // ⁵ superscript five
+    ('\u{2076}', "^6"),   // ⁶ superscript six
+    ('\u{2077}', "^7"),   // ⁷ superscript seven
+    ('\u{2078}', "^8"),   // ⁸ superscript eight
+    ('\u{2079}', "^9"),   // ⁹ superscript nine
+    ('\u{207A}', "^+"),   // ⁺ superscript plus sign
+    ('\u{207B}', "^-"),   // ⁻ superscript minus
+    ('\u{207C}', "^="),   // ⁼ superscript equals sign
+    ('\u{207D}', "^("),   // ⁽ superscript left parenthesis
+    ('\u{207E}', "^)"),   // ⁾ superscript right parenthesis
+    ('\u{207F}', "^n"),   // ⁿ superscript Latin small letter n
+    ('\u{2080}', "_0"),   // ₀ subscript zero
+    ('\u{2081}', "_1"),   // ₁ subscript one
+    ('\u{2082}', "_2"),   // ₂ subscript two
+    ('\u{2083}', "_3"),   // ₃ subscript three
+    ('\u{2084}', "_4"),   // ₄ subscript four
+    ('\u{2085}', "_5"),   // ₅ subscript five
+    ('\u{2086}', "_6"),   // ₆ subscript six
+    ('\u{2087}', "_7"),   // ₇ subscript seven
+    ('\u{2088}', "_8"),   // ₈ subscript eight
+    ('\u{2089}', "_9"),   // ₉ subscript nine
+    ('\u{208A}', "_+"),   // ₊ subscript plus sign
+    ('\u{208B}', "_-"),   // ₋ subscript minus
+    ('\u{208C}', "_="),   // ₌ subscript equals sign
+    ('\u{208D}', "_("),   // ₍ subscript left parenthesis
+    ('\u{208E}', "_)"),   // ₎ subscript right parenthesis
+    ('\u{2090}', "_a"),   // ₐ Latin subscript small letter a
+    ('\u{2091}', "_e"),   // ₑ Latin subscript small letter e
+    ('\u{2092}', "_o"),   // ₒ Latin subscript small letter o
+    ('\u{2093}', "_x"),   // ₓ Latin subscript small letter x
+    ('\u{2095}', "_h"),   // ₕ Latin subscript small letter h
+    ('\u{2096}', "_k"),   // ₖ Latin subscript small letter k
+    ('\u{2097}', "_l"),   // ₗ Latin subscript small letter l
+    ('\u{2098}', "_m"),   // ₘ Latin subscript small letter m
+    ('\u{2099}', "_n"),   // ₙ Latin subscript small letter n
+    ('\u{209A}', "_p"),   // ₚ Latin subscript small letter p
+    ('\u{209B}', "_s"),   // ₛ Latin subscript small letter s
+    ('\u{209C}', "_t"),   // ₜ Latin subscript small letter t
+    ('\u{2150}', "1/7"),  // ⅐ vulgar fraction one seventh
+    ('\u{2151}', "1/9"),  // ⅑ vulgar fraction one ninth
+    ('\u{2152}', "1/10"), // ⅒ vulgar fraction one tenth
+    ('\u{2153}', "1/3"),  // ⅓ vulgar fraction one third
+    ('\u{2154}', "2/3"),  // ⅔ vulgar fraction two thirds
+    ('\u{2155}', "1/5"),  // ⅕ vulgar fraction one fifth
+    ('\u{2156}', "2/5"),  // ⅖ vulgar fraction two fifths
+    ('\u{2157}', "3/5"),  // ⅗ vulgar fraction three fifths
+    ('\u{2158}', "4/5"),  // ⅘ vulgar fraction four fifths
+    ('\u{2159}', "1/6"),  // ⅙ vulgar fraction one sixth
+    ('\u{215A}', "5/6"),  // ⅚ vulgar fraction five sixths
+    ('\u{215B}', "1/8"),  // ⅛ vulgar fraction one eighth
+    ('\u{215C}', "3/8"),  // ⅜ vulgar fraction three eighths
+    ('\u{215D}', "5/8"),  // ⅝ vulgar fraction five eighths
+    ('\u{215E}', "7/8"),  // ⅞ vulgar fraction seven eighths
+    ('\u{2189}', "0/3"),  // ↉ vulgar fraction zero thirds
+    ('\u{2190}', "<-"),   // ← leftwards arrow
+    ('\u{2191}', "^"),    // ↑ upwards arrow
+    ('\u{2192}', "->"),   // → rightwards arrow
+    ('\u{2193}', "v"),    // ↓ downwards arrow
+    ('\u{2194}', "<->"),  // ↔ left right arrow
+    ('\u{21A4}', "<-"),   // ↤ leftwards arrow from bar
+    ('\u{21A6}', "->"),   // ↦ rightwards arrow from bar
+    ('\u{21D0}', "<="),   // ⇐ leftwards double arrow
+    ('\u{21D2}', "=>"),   // ⇒ rightwards double arrow
+    ('\u{21D4}', "<=>"),  // ⇔ left right double arrow
+    ('\u{21FD}', "<-"),   // ⇽ leftwards open-headed arrow
+    ('\u{21FE}', "->"),   // ⇾ rightwards open-headed arrow
+    ('\u{21FF}', "<->"),  // ⇿ left right open-headed arrow
+    ('\u{2212}', "-"),    // − minus sign
+    ('\u{2260}', "!="),   // ≠ not equal to
+    ('\u{2264}', "<="),   // ≤ less-than or equal to
+    ('\u{2265}', ">="),   // ≥ greater-than or equal to
+    ('\u{2611}', "[x]"),  // ☑ ballot box with check
+    ('\u{2612}', "[ ]"),  // ☒ ballot box with x
+    ('\u{2705}', "[x]"),  // ✅ white heavy check mark
+    ('\u{2713}', "[x]"),  // ✓ check mark
+    ('\u{2714}', "[x]"),  // ✔ heavy check mark
+    ('\u{2717}', "[ ]"),  // ✗ ballot x
+    ('\u{2718}', "[ ]"),  // ✘ heavy ballot x
+    ('\u{274C}', "[ ]"),  // ❌ cross mark
+    ('\u{27F5}', "<-"),   // ⟵ long leftwards arrow
+    ('\u{27F6}', "->"),   // ⟶ long rightwards arrow
+    ('\u{27F7}', "<->"),  // ⟷ long left right arrow
+    ('\u{27F8}', "<="),   // ⟸ long leftwards double arrow
+    ('\u{27F9}', "=>"),   // ⟹ long rightwards double arrow
+    ('\u{27FA}', "<=>"),  // ⟺ long left right double arrow
+    ('\u{27FB}', "<-"),   // ⟻ long leftwards arrow from bar
+    ('\u{27FC}', "->"),   // ⟼ long rightwards arrow from bar
+    ('\u{3000}', " "),    // ideographic space
+    ('\u{FB00}', "ff"),   // ﬀ Latin small ligature ff
+    ('\u{FB01}', "fi"),   // ﬁ Latin small ligature fi
+    ('\u{FB02}', "fl"),   // ﬂ Latin small ligature fl
+    ('\u{FB03}', "ffi"),  // ﬃ Latin small ligature ffi
+    ('\u{FB04}', "ffl"),  // ﬄ Latin small ligature ffl
+    ('\u{FB05}', "st"),   // ﬅ Latin small ligature long s t
+    ('\u{FB06}', "st"),   // ﬆ Latin small ligature st
+];
+
+/// Returns the ASCII replacement for a character, if one exists
+///
+/// Only characters with a clear, unambiguous ASCII equivalent have a
+/// suggestion; everything else, such as emoji and box drawing, should simply
+/// be removed or rewritten. Both reports and `--fix` use this table.
+///
+/// # Examples
+///
+/// ```
+/// use noemoji::check::suggest;
+///
+/// assert_eq!(suggest('→').as_deref(), Some("->"));
+/// assert_eq!(suggest('½').as_deref(), Some("1/2"));
+/// assert_eq!(suggest('Ａ').as_deref(), Some("A"));
+/// assert_eq!(suggest('🚀'), None);
+/// ```
+pub fn suggest(ch: char) -> Option<Cow<'static, str>> {
+    match SUGGESTIONS.binary_search_by_key(&ch, |&(from, _)| from) {
+        Ok(index) => Some(Cow::Borrowed(SUGGESTIONS[index].1)),
+        Err(_) => confusables::confusable(ch).map(|ascii| Cow::Owned(ascii.to_string())),
+    }
+}
+
+/// Returns the ASCII replacement for a character, if one exists
+///
+/// Replacements derived from the confusables table are not returned, since
+/// they are not `'static`; [`suggest`] covers them.
+///
+/// # Examples
+///
+/// ```
+/// #![allow(deprecated)]
+/// use noemoji::check::suggestion;
+///
+/// assert_eq!(suggestion('→'), Some("->"));
+/// assert_eq!(suggestion('a'), None);
+/// ```
+#[deprecated(since = "0.1.0", note = "use `suggest`, which covers more characters")]
+pub fn suggestion(ch: char) -> Option<&'static str> {
+    match suggest(ch)? {
+        Cow::Borrowed(replacement) => Some(replacement),
+        Cow::Owned(_) => None,
+    }
+}
+
+/// Usage notes for characters whose replacement depends on what they mean,
+/// short and in full, sorted by character for binary search
+const NOTES: &[(char, &str, &str)] = &[
+    (
+        '\u{2013}',
+        "for ranges",
+        "An en dash joins ranges and pairs, so it becomes a single hyphen:\n\
+         write `pages 1-10` or `pages 1 to 10`.",
+    ),
+    (
+        '\u{2014}',
+        "for a break in a sentence",
+        "An em dash sets off a clause, so it becomes a double hyphen, which\n\
+         cannot be mistaken for the hyphen in a compound such as `x-y`:\n\
+         write `wait -- really?`, or split the sentence.",
+    ),
 ];
 
+/// Returns the entry of [`NOTES`] for `ch`, if any
+fn find_note(ch: char) -> Option<&'static (char, &'static str, &'static str)> {
+    NOTES
+        .binary_search_by_key(&ch, |&(from, _, _)| from)
+        .ok()
+        .map(|index| &NOTES[index])
+}
+
+/// Returns a note on how to replace a character, beyond its suggestion
+///
+/// Used by `--explain` to tell apart characters that look alike but have
+/// different ASCII forms, such as the en dash and em dash.
+///
+/// # Examples
+///
+/// ```
+/// use noemoji::check::note;
+///
+/// assert!(note('—').unwrap().contains("double hyphen"));
+/// assert_eq!(note('→'), None);
+/// ```
+pub fn note(ch: char) -> Option<&'static str> {
+    find_note(ch).map(|&(_, _, note)| note)
+}
+
+/// Returns a few words on when a character's suggestion applies, for
+/// violation messages
+///
+/// This is the short form of [`note`].
+///
+/// # Examples
+///
+/// ```
+/// use noemoji::check::short_note;
+///
+/// assert_eq!(short_note('–'), Some("for ranges"));
+/// assert_eq!(short_note('→'), None);
+/// ```
+pub fn short_note(ch: char) -> Option<&'static str> {
+    find_note(ch).map(|&(_, short, _)| short)
+}
+
+/// A prohibited character found while checking input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column number, starting at 1, counted as set by [`ColumnMode`]
+    pub column: usize,
+    /// Offset in bytes from the start of the input, starting at 0
+    pub byte_offset: usize,
+    /// The prohibited character
+    pub ch: char,
+    /// Category of rule that flagged the character
+    pub category: CharCategory,
+    /// ASCII replacement, if the character has a clear equivalent
+    pub suggestion: Option<String>,
+    /// The extended grapheme cluster containing the character, if it has
+    /// other characters too, such as the whole of a ZWJ emoji sequence
+    pub grapheme: Option<String>,
+}
+
+/// A single-line change that `--fix` would make
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineEdit {
+    /// Line number, starting at 1
+    pub line: usize,
+    /// The original line, without its line ending
+    pub original: String,
+    /// The line with suggestions applied, without its line ending
+    pub fixed: String,
+}
+
 /// Errors that can occur during input processing
 #[derive(Debug, Error)]
 pub enum CheckError {
@@ -34,6 +903,13 @@ pub enum CheckError {
         source: io::Error,
     },
 
+    /// Path names a directory, which is only walked with `-r`
+    #[error("{}: is a directory; use -r to recurse", path.display())]
+    IsDirectory {
+        /// Path to the directory
+        path: PathBuf,
+    },
+
     /// Failed to read line
     #[error("{source}")]
     ReadLine {
@@ -41,6 +917,24 @@ pub enum CheckError {
         #[source]
         source: io::Error,
     },
+
+    /// Failed to write fixed contents back to a file
+    #[error("{}: {source}", path.display())]
+    WriteFile {
+        /// Path to the file that could not be written
+        path: PathBuf,
+        /// The underlying I/O error
+        #[source]
+        source: io::Error,
+    },
+
+    /// Failed to write fixed contents to stdout
+    #[error("{source}")]
+    WriteOutput {
+        /// The underlying I/O error
+        #[source]
+        source: io::Error,
+    },
 }
 
 /// Represents an input source for processing
@@ -61,162 +955,3153 @@ impl InputSource {
         }
     }
 
+    /// Open this input source for buffered reading
+    fn open(&self) -> Result<Box<dyn BufRead>, CheckError> {
+        match self {
+            InputSource::File(path) => Ok(Box::new(BufReader::new(open_file(path)?))),
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
     /// Check this input source for Unicode compliance, streaming output.
     ///
-    /// Calls `on_violation` for each prohibited character found.
-    /// Returns `Ok(true)` if violations were found, `Ok(false)` if clean.
+    /// Calls `on_violation` with a [`Violation`] for each prohibited character
+    /// found. Returns `Ok(true)` if violations were found, `Ok(false)` if clean.
+    /// Only the default rules are checked; [`Checker::check_input`] takes the
+    /// rules and options to check with.
+    ///
+    /// Earlier versions passed `(line, column, char)` to the callback; those
+    /// values are now the `line`, `column`, and `ch` fields of [`Violation`].
+    #[deprecated(
+        since = "0.1.0",
+        note = "use `Checker::check_input`, which honors the checker's options"
+    )]
     pub fn check<F>(&self, on_violation: F) -> Result<bool, CheckError>
     where
-        F: FnMut(usize, usize, char),
+        F: FnMut(&Violation),
     {
-        match self {
-            InputSource::File(path) => {
-                let file = File::open(path).map_err(|source| CheckError::OpenFile {
-                    path: path.clone(),
-                    source,
-                })?;
-                check_reader(BufReader::new(file), on_violation)
-            }
-            InputSource::Stdin => {
-                let stdin = io::stdin().lock();
-                check_reader(stdin, on_violation)
-            }
-        }
+        Checker::default().check_input(self, on_violation)
+    }
+
+    /// Fix this input source by replacing prohibited characters with their
+    /// ASCII suggestions.
+    ///
+    /// See [`Checker::fix_input`] for details. All categories are fixed.
+    pub fn fix<F>(&self, on_violation: F) -> Result<bool, CheckError>
+    where
+        F: FnMut(&Violation),
+    {
+        Checker::default().fix_input(self, on_violation)
+    }
+
+    /// Preview the changes [`InputSource::fix`] would make, without writing.
+    ///
+    /// Returns one [`LineEdit`] per line that would change, in line order.
+    pub fn fix_preview(&self) -> Result<Vec<LineEdit>, CheckError> {
+        Checker::default().preview_input(self)
     }
 }
 
-/// Check a buffered reader for prohibited characters, streaming results.
-fn check_reader<R, F>(reader: R, mut on_violation: F) -> Result<bool, CheckError>
-where
-    R: BufRead,
-    F: FnMut(usize, usize, char),
-{
-    let mut found_violations = false;
+/// Unit in which reported columns are counted
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnMode {
+    /// Count Unicode scalar values (`char`s)
+    #[default]
+    Char,
+    /// Count extended grapheme clusters, matching what most editors show
+    Grapheme,
+    /// Count UTF-8 bytes
+    Byte,
+}
 
-    for (line_idx, line_result) in reader.lines().enumerate() {
-        let line = line_result.map_err(|source| CheckError::ReadLine { source })?;
+impl ColumnMode {
+    /// Accepted values, for error messages
+    pub const EXPECTED: &'static str = "char, grapheme, byte";
 
-        for (col_idx, ch) in line.chars().enumerate() {
-            if PROHIBITED_CHARS.contains(&ch) {
-                found_violations = true;
-                on_violation(line_idx + 1, col_idx + 1, ch);
-            }
+    /// Returns the configuration name of this mode
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Char => "char",
+            Self::Grapheme => "grapheme",
+            Self::Byte => "byte",
         }
     }
-
-    Ok(found_violations)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::io::Cursor;
+/// Error returned when parsing an invalid column mode string
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid column mode '{value}', expected: {}", ColumnMode::EXPECTED)]
+pub struct ParseColumnModeError {
+    /// The invalid value that was provided
+    pub value: String,
+}
 
-    #[test]
-    fn check_reader_finds_single_violation() {
-        let input = Cursor::new("text → more");
-        let mut violations = Vec::new();
+impl FromStr for ColumnMode {
+    type Err = ParseColumnModeError;
 
-        let result = check_reader(input, |line, col, ch| {
-            violations.push((line, col, ch));
-        });
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "char" => Ok(Self::Char),
+            "grapheme" => Ok(Self::Grapheme),
+            "byte" => Ok(Self::Byte),
+            _ => Err(ParseColumnModeError {
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
 
-        assert!(result.unwrap());
-        assert_eq!(violations, vec![(1, 6, '→')]);
+impl<'de> Deserialize<'de> for ColumnMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        ColumnMode::from_str(&s).map_err(serde::de::Error::custom)
     }
+}
 
-    #[test]
-    fn check_reader_finds_multiple_violations_same_line() {
-        let input = Cursor::new("a → b ← c");
-        let mut violations = Vec::new();
+/// Unicode normalization form that text is required to be in
+///
+/// Only the canonical forms are offered, since the compatibility forms would
+/// also rewrite characters that merely look alike, such as `ﬁ` to `fi`.
+///
+/// # Examples
+///
+/// ```
+/// use noemoji::check::NormalizationForm;
+///
+/// let nfd = "e\u{301}";
+/// assert!(!NormalizationForm::Nfc.is_normalized(nfd));
+/// assert_eq!(NormalizationForm::Nfc.normalize(nfd), "\u{e9}");
+/// assert_eq!("nfd".parse(), Ok(NormalizationForm::Nfd));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizationForm {
+    /// Canonical composition, as most editors and keyboards produce
+    Nfc,
+    /// Canonical decomposition
+    Nfd,
+}
 
-        let result = check_reader(input, |line, col, ch| {
-            violations.push((line, col, ch));
-        });
+impl NormalizationForm {
+    /// Accepted values, for error messages
+    pub const EXPECTED: &'static str = "nfc, nfd";
 
-        assert!(result.unwrap());
-        assert_eq!(violations, vec![(1, 3, '→'), (1, 7, '←')]);
+    /// Returns the configuration name of this form
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Nfc => "nfc",
+            Self::Nfd => "nfd",
+        }
     }
 
-    #[test]
-    fn check_reader_finds_violations_across_lines() {
-        let input = Cursor::new("line one →\nline two ←\nline three");
-        let mut violations = Vec::new();
+    /// Returns true if `text` is already in this form
+    pub fn is_normalized(self, text: &str) -> bool {
+        match self {
+            Self::Nfc => is_nfc(text),
+            Self::Nfd => is_nfd(text),
+        }
+    }
 
-        let result = check_reader(input, |line, col, ch| {
-            violations.push((line, col, ch));
-        });
+    /// Returns `text` converted to this form
+    pub fn normalize(self, text: &str) -> String {
+        match self {
+            Self::Nfc => text.nfc().collect(),
+            Self::Nfd => text.nfd().collect(),
+        }
+    }
+}
 
-        assert!(result.unwrap());
-        assert_eq!(violations, vec![(1, 10, '→'), (2, 10, '←')]);
+impl fmt::Display for NormalizationForm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
     }
+}
 
-    #[test]
-    fn check_reader_clean_input() {
-        let input = Cursor::new("clean text with no violations");
-        let mut violations = Vec::new();
+/// Error returned when parsing an invalid normalization form string
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error(
+    "invalid normalization form '{value}', expected: {}",
+    NormalizationForm::EXPECTED
+)]
+pub struct ParseNormalizationFormError {
+    /// The invalid value that was provided
+    pub value: String,
+}
 
-        let result = check_reader(input, |line, col, ch| {
-            violations.push((line, col, ch));
-        });
+impl FromStr for NormalizationForm {
+    type Err = ParseNormalizationFormError;
 
-        assert!(!result.unwrap());
-        assert!(violations.is_empty());
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "nfc" => Ok(Self::Nfc),
+            "nfd" => Ok(Self::Nfd),
+            _ => Err(ParseNormalizationFormError {
+                value: s.to_owned(),
+            }),
+        }
     }
+}
 
-    #[test]
-    fn check_reader_empty_input() {
-        let input = Cursor::new("");
-        let mut violations = Vec::new();
+impl<'de> Deserialize<'de> for NormalizationForm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        NormalizationForm::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
 
-        let result = check_reader(input, |line, col, ch| {
-            violations.push((line, col, ch));
-        });
+/// How seriously a violation is treated
+///
+/// Only [`Severity::Error`] violations fail a run by default; warnings are
+/// reported but leave the exit status alone unless `--warnings-as-errors` is
+/// given. Variants are ordered most severe first.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Reported, and fails the run
+    #[default]
+    Error,
+    /// Reported without failing the run
+    Warning,
+}
 
-        assert!(!result.unwrap());
-        assert!(violations.is_empty());
+impl Severity {
+    /// Returns the configuration name of this severity, as printed in reports
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+        }
     }
+}
 
-    #[test]
-    fn check_reader_all_prohibited_chars() {
-        let input = Cursor::new("→←↑↓");
-        let mut violations = Vec::new();
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
 
-        let result = check_reader(input, |line, col, ch| {
-            violations.push((line, col, ch));
-        });
+/// Computes reported columns while scanning a line character by character
+struct Columns<'a> {
+    mode: ColumnMode,
+    /// Columns per tab stop
+    tab_width: usize,
+    /// Remaining characters, used in char mode
+    chars: Peekable<CharIndices<'a>>,
+    /// Remaining grapheme clusters, used in grapheme mode
+    graphemes: Peekable<GraphemeIndices<'a>>,
+    /// Column of the most recently counted unit
+    column: usize,
+    /// Column at which the next unit starts
+    next: usize,
+}
 
-        assert!(result.unwrap());
-        assert_eq!(
-            violations,
-            vec![(1, 1, '→'), (1, 2, '←'), (1, 3, '↑'), (1, 4, '↓')]
-        );
+impl<'a> Columns<'a> {
+    fn new(line: &'a str, mode: ColumnMode, tab_width: usize) -> Self {
+        Columns {
+            mode,
+            tab_width,
+            chars: line.char_indices().peekable(),
+            graphemes: line.grapheme_indices(true).peekable(),
+            column: 0,
+            next: 1,
+        }
     }
 
-    #[test]
+    /// Returns the 1-based column of the character starting at `byte_idx`.
+    /// Must be called with increasing positions.
+    fn column(&mut self, byte_idx: usize) -> usize {
+        match self.mode {
+            ColumnMode::Byte => return byte_idx + 1,
+            ColumnMode::Char => {
+                while let Some((_, ch)) = self.chars.next_if(|&(start, _)| start <= byte_idx) {
+                    self.step(ch == '\t');
+                }
+            }
+            ColumnMode::Grapheme => {
+                while let Some((_, g)) = self.graphemes.next_if(|&(start, _)| start <= byte_idx) {
+                    self.step(g == "\t");
+                }
+            }
+        }
+        self.column
+    }
+
+    /// Returns the 1-based column just past the end of a line of `len` bytes
+    fn end(&mut self, len: usize) -> usize {
+        match self.mode {
+            ColumnMode::Byte => len + 1,
+            ColumnMode::Char | ColumnMode::Grapheme => {
+                self.column(len);
+                self.next
+            }
+        }
+    }
+
+    /// Count one unit, advancing to the next tab stop if it is a tab
+    fn step(&mut self, tab: bool) {
+        self.column = self.next;
+        self.next = if tab {
+            (self.next - 1) / self.tab_width * self.tab_width + self.tab_width + 1
+        } else {
+            self.next + 1
+        };
+    }
+}
+
+/// Finds the grapheme cluster around each violation while scanning a line
+struct Clusters<'a> {
+    /// Remaining grapheme clusters
+    graphemes: Peekable<GraphemeIndices<'a>>,
+}
+
+impl<'a> Clusters<'a> {
+    fn new(line: &'a str) -> Self {
+        Clusters {
+            graphemes: line.grapheme_indices(true).peekable(),
+        }
+    }
+
+    /// Returns the cluster containing the character at `byte_idx`, if it
+    /// holds more than that one character. Must be called with increasing
+    /// positions.
+    fn at(&mut self, byte_idx: usize) -> Option<String> {
+        while self
+            .graphemes
+            .next_if(|&(start, g)| start + g.len() <= byte_idx)
+            .is_some()
+        {}
+        let &(_, g) = self.graphemes.peek()?;
+        g.chars().nth(1).is_some().then(|| g.to_owned())
+    }
+}
+
+/// A grapheme cluster that is not in the required normalization form
+struct Unnormalized {
+    /// Byte offset of the cluster within its line
+    start: usize,
+    /// Length of the cluster in bytes
+    len: usize,
+    /// The cluster in the required form
+    normalized: String,
+}
+
+/// Returns the unnormalized cluster starting at `byte_idx`, if any, from
+/// `spans` sorted by offset
+fn unnormalized_at(spans: &[Unnormalized], byte_idx: usize) -> Option<&Unnormalized> {
+    spans
+        .binary_search_by_key(&byte_idx, |span| span.start)
+        .ok()
+        .map(|index| &spans[index])
+}
+
+/// Files at least this many bytes long are memory-mapped when checked
+pub const MMAP_THRESHOLD: u64 = 1 << 20;
+
+/// Number of leading bytes sampled when deciding whether an input is binary
+pub const BINARY_SAMPLE: usize = 8 * 1024;
+
+/// Returns true if `sample` looks like binary rather than text, at the
+/// default [`BinaryThreshold`]
+pub fn is_binary(sample: &[u8]) -> bool {
+    BinaryThreshold::default().is_binary(sample)
+}
+
+/// Share of non-text bytes above which an input is treated as binary
+///
+/// Kept in millionths rather than as a float, so that checkers and
+/// configurations can still be compared with `Eq`. The default is 0.1.
+///
+/// # Examples
+///
+/// ```
+/// use noemoji::check::BinaryThreshold;
+///
+/// let sample = b"ab\x01\x02cd";
+/// assert!(BinaryThreshold::default().is_binary(sample));
+/// assert!(!"0.5".parse::<BinaryThreshold>().unwrap().is_binary(sample));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinaryThreshold(u32);
+
+impl BinaryThreshold {
+    /// Accepted values, for error messages
+    pub const EXPECTED: &'static str = "a ratio from 0.0 to 1.0";
+
+    /// Units per whole ratio
+    const SCALE: u32 = 1_000_000;
+
+    /// Returns the threshold for `ratio`, or `None` if it is not between 0.0
+    /// and 1.0
+    pub fn new(ratio: f64) -> Option<Self> {
+        (0.0..=1.0)
+            .contains(&ratio)
+            .then(|| Self((ratio * f64::from(Self::SCALE)).round() as u32))
+    }
+
+    /// Returns the threshold as a ratio from 0.0 to 1.0
+    pub fn ratio(self) -> f64 {
+        f64::from(self.0) / f64::from(Self::SCALE)
+    }
+
+    /// Returns true if `sample` looks like binary rather than text
+    ///
+    /// A sample is binary if it contains a NUL byte, or if more than the
+    /// threshold's share of its bytes are ASCII control characters other than
+    /// common whitespace and escape. Bytes above 0x7F are not counted, so any
+    /// UTF-8 text passes. A threshold of 1.0 treats nothing as binary, even
+    /// with NUL bytes.
+    pub fn is_binary(self, sample: &[u8]) -> bool {
+        if self.0 >= Self::SCALE {
+            return false;
+        }
+        if sample.contains(&0) {
+            return true;
+        }
+        let control = sample
+            .iter()
+            .filter(|&&b| {
+                (b < 0x20 || b == 0x7f) && !matches!(b, b'\t' | b'\n' | b'\r' | 0x0c | 0x1b)
+            })
+            .count();
+        control as u64 * u64::from(Self::SCALE) > sample.len() as u64 * u64::from(self.0)
+    }
+}
+
+impl Default for BinaryThreshold {
+    fn default() -> Self {
+        Self(Self::SCALE / 10)
+    }
+}
+
+/// Writes the ratio as a TOML float, such as `0.1` or `1.0`
+impl fmt::Display for BinaryThreshold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.ratio())
+    }
+}
+
+/// Error returned when parsing an invalid binary threshold
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error(
+    "invalid binary threshold '{value}', expected {}",
+    BinaryThreshold::EXPECTED
+)]
+pub struct ParseBinaryThresholdError {
+    /// The invalid value that was provided
+    pub value: String,
+}
+
+impl FromStr for BinaryThreshold {
+    type Err = ParseBinaryThresholdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse()
+            .ok()
+            .and_then(Self::new)
+            .ok_or_else(|| ParseBinaryThresholdError {
+                value: s.to_owned(),
+            })
+    }
+}
+
+impl<'de> Deserialize<'de> for BinaryThreshold {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ratio = f64::deserialize(deserializer)?;
+        BinaryThreshold::new(ratio).ok_or_else(|| {
+            serde::de::Error::custom(ParseBinaryThresholdError {
+                value: ratio.to_string(),
+            })
+        })
+    }
+}
+
+/// Configurable checker that reports only the enabled categories
+///
+/// A new checker has every category enabled. Individual categories can be
+/// switched off (or back on) with the builder methods:
+///
+/// ```
+/// use noemoji::check::Checker;
+///
+/// let checker = Checker::new().with_emoji(false).with_punctuation(false);
+///
+/// assert!(!checker.check_str("done 🚀 “quoted”", |_| {}));
+/// assert!(checker.check_str("a → b", |_| {}));
+/// ```
+///
+/// A checker holds every setting that affects checking: enabled categories,
+/// allow and deny lists, suggestions, column mode, tab width, source
+/// language, and byte order mark and binary handling. Its [`Default`] matches
+/// the command line's defaults, so configuration is merged by building one with
+/// [`Config::checker`](crate::config::Config::checker) and then applying
+/// command line overrides with [`Options::apply`](crate::cli::Options::apply).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checker {
+    /// Bit mask of enabled categories, indexed by [`CharCategory::bit`]
+    enabled: u32,
+    /// Bit mask of categories reported as [`Severity::Warning`]
+    warnings: u32,
+    /// Characters that are never reported, regardless of category
+    allowed: HashSet<char>,
+    /// Additional characters reported as [`CharCategory::Custom`]
+    denied: HashSet<char>,
+    /// True if any denied character is ASCII, disabling the ASCII fast path
+    denies_ascii: bool,
+    /// Replacements that take precedence over the built-in [`suggest`] table
+    suggestions: HashMap<char, String>,
+    /// Unit in which reported columns are counted
+    column_mode: ColumnMode,
+    /// Columns per tab stop when counting chars or graphemes
+    tab_width: usize,
+    /// Whether inputs that look binary are skipped rather than scanned
+    skip_binary: bool,
+    /// Share of non-text bytes above which an input looks binary
+    binary_threshold: BinaryThreshold,
+    /// Lines to report violations on, or `None` for every line
+    lines: Option<RangeInclusive<usize>>,
+    /// Language whose string literals and comments are left unchecked
+    language: Option<Language>,
+    /// Normalization form text must be in, for [`CharCategory::Normalization`]
+    normalization: Option<NormalizationForm>,
+}
+
+/// Another name for [`Checker`], for code written against an options struct
+///
+/// A checker already bundles every setting that affects checking, and
+/// its methods take the place of free functions taking options:
+///
+/// ```
+/// use noemoji::check::CheckOptions;
+///
+/// let options = CheckOptions::default().with_arrows(false);
+/// assert!(!options.check_str("a → b", |_| {}));
+/// ```
+pub type CheckOptions = Checker;
+
+impl Default for Checker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checker {
+    /// Default tab stop width for reported columns
+    pub const DEFAULT_TAB_WIDTH: usize = 8;
+
+    /// Create a checker with every category enabled, except opt-in rules
+    /// such as [`CharCategory::MissingFinalNewline`]
+    pub fn new() -> Self {
+        Checker {
+            enabled: CharCategory::ALL
+                .iter()
+                .filter(|c| !c.is_opt_in())
+                .fold(0, |mask, c| mask | c.bit()),
+            warnings: 0,
+            allowed: HashSet::new(),
+            denied: HashSet::new(),
+            denies_ascii: false,
+            suggestions: HashMap::new(),
+            column_mode: ColumnMode::default(),
+            tab_width: Self::DEFAULT_TAB_WIDTH,
+            skip_binary: true,
+            binary_threshold: BinaryThreshold::default(),
+            lines: None,
+            language: None,
+            normalization: None,
+        }
+    }
+
+    /// Set whether inputs that look binary are skipped (the default)
+    ///
+    /// See [`BinaryThreshold::is_binary`] for the heuristic, applied to the first
+    /// [`BINARY_SAMPLE`] bytes of each input.
+    pub fn with_binary_skip(mut self, skip: bool) -> Self {
+        self.skip_binary = skip;
+        self
+    }
+
+    /// Set the share of non-text bytes above which an input looks binary
+    pub fn with_binary_threshold(mut self, threshold: BinaryThreshold) -> Self {
+        self.binary_threshold = threshold;
+        self
+    }
+
+    /// Set the unit in which reported columns are counted
+    pub fn with_column_mode(mut self, mode: ColumnMode) -> Self {
+        self.column_mode = mode;
+        self
+    }
+
+    /// Set the tab stop width used when reporting columns
+    ///
+    /// Tabs advance the column to the next multiple of `width` (plus one).
+    /// A width of 1 counts each tab as a single column. Byte columns and
+    /// byte offsets are not affected. Widths below 1 are treated as 1.
+    pub fn with_tab_width(mut self, width: usize) -> Self {
+        self.tab_width = width.max(1);
+        self
+    }
+
+    /// Report only violations on the given 1-based lines
+    ///
+    /// Other lines are still read, so line numbers, columns, and byte
+    /// offsets are unaffected.
+    pub fn with_lines(mut self, lines: RangeInclusive<usize>) -> Self {
+        self.lines = Some(lines);
+        self
+    }
+
+    /// Leave the string literals and comments of `language` source unchecked
+    ///
+    /// Input that cannot be lexed as `language` is checked in full.
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Never report the given characters, even if their category is enabled
+    pub fn with_allowed(mut self, chars: impl IntoIterator<Item = char>) -> Self {
+        self.allowed.extend(chars);
+        self
+    }
+
+    /// Also report the given characters, as [`CharCategory::Custom`]
+    ///
+    /// Denied characters take precedence over both the built-in categories
+    /// and the allowlist.
+    pub fn with_denied(mut self, chars: impl IntoIterator<Item = char>) -> Self {
+        self.denied.extend(chars);
+        self.denies_ascii = self.denied.iter().any(char::is_ascii);
+        self
+    }
+
+    /// Replace characters with these strings in reports and `--fix`, instead
+    /// of their built-in suggestions
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noemoji::Checker;
+    ///
+    /// let checker = Checker::new().with_suggestions([('↑', "up".to_owned())]);
+    /// assert_eq!(checker.suggestion('↑').as_deref(), Some("up"));
+    /// assert_eq!(checker.suggestion('↓').as_deref(), Some("v"));
+    /// ```
+    pub fn with_suggestions(
+        mut self,
+        suggestions: impl IntoIterator<Item = (char, String)>,
+    ) -> Self {
+        self.suggestions.extend(suggestions);
+        self
+    }
+
+    /// Returns the ASCII replacement for `ch`, from the configured
+    /// suggestions or else the built-in table
+    pub fn suggestion(&self, ch: char) -> Option<Cow<'_, str>> {
+        match self.suggestions.get(&ch) {
+            Some(replacement) => Some(Cow::Borrowed(replacement)),
+            None => suggest(ch),
+        }
+    }
+
+    /// Enable or disable a single category
+    pub fn with_category(mut self, category: CharCategory, enabled: bool) -> Self {
+        if enabled {
+            self.enabled |= category.bit();
+        } else {
+            self.enabled &= !category.bit();
+        }
+        self
+    }
+
+    /// Enable or disable [`CharCategory::Arrow`]
+    pub fn with_arrows(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::Arrow, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::Checkmark`]
+    pub fn with_checkmarks(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::Checkmark, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::BoxDrawing`]
+    pub fn with_box_drawing(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::BoxDrawing, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::Math`]
+    pub fn with_math(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::Math, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::Supersub`]
+    pub fn with_supersub(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::Supersub, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::Fractions`]
+    pub fn with_fractions(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::Fractions, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::Decorative`]
+    pub fn with_decorative(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::Decorative, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::Punctuation`]
+    pub fn with_punctuation(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::Punctuation, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::Ligature`]
+    pub fn with_ligatures(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::Ligature, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::Whitespace`]
+    pub fn with_whitespace(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::Whitespace, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::Emoji`]
+    pub fn with_emoji(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::Emoji, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::Custom`]
+    pub fn with_custom(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::Custom, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::MissingFinalNewline`]
+    pub fn with_final_newline(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::MissingFinalNewline, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::ByteOrderMark`]
+    pub fn with_byte_order_mark(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::ByteOrderMark, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::Confusable`]
+    pub fn with_confusables(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::Confusable, enabled)
+    }
+
+    /// Enable or disable [`CharCategory::NonAscii`], reporting every
+    /// non-ASCII character not covered by another rule
+    pub fn with_non_ascii(self, enabled: bool) -> Self {
+        self.with_category(CharCategory::NonAscii, enabled)
+    }
+
+    /// Report text not in `form`, enabling [`CharCategory::Normalization`]
+    ///
+    /// Only sequences that are canonically equivalent to their replacement
+    /// are reported, so `--fix` never changes what the text means.
+    pub fn with_normalization(mut self, form: NormalizationForm) -> Self {
+        self.normalization = Some(form);
+        self.with_category(CharCategory::Normalization, true)
+    }
+
+    /// Returns true if `category` is reported by this checker
+    pub fn is_enabled(&self, category: CharCategory) -> bool {
+        self.enabled & category.bit() != 0
+    }
+
+    /// Set the severity of a single category, [`Severity::Error`] by default
+    pub fn with_severity(mut self, category: CharCategory, severity: Severity) -> Self {
+        match severity {
+            Severity::Error => self.warnings &= !category.bit(),
+            Severity::Warning => self.warnings |= category.bit(),
+        }
+        self
+    }
+
+    /// Returns the severity of violations in `category`
+    pub fn severity(&self, category: CharCategory) -> Severity {
+        if self.warnings & category.bit() != 0 {
+            Severity::Warning
+        } else {
+            Severity::Error
+        }
+    }
+
+    /// Returns true if `ch` is on this checker's allowlist
+    pub fn is_allowed(&self, ch: char) -> bool {
+        self.allowed.contains(&ch)
+    }
+
+    /// Returns true if `ch` is on this checker's deny list
+    pub fn is_denied(&self, ch: char) -> bool {
+        self.denied.contains(&ch)
+    }
+
+    /// Returns true if no character of `text` can be a violation, so the
+    /// per-character scan can be skipped
+    ///
+    /// Most lines are pure ASCII, which is never prohibited unless denied, and
+    /// checking that is much cheaper than classifying each character.
+    fn is_trivially_clean(&self, text: &str) -> bool {
+        text.is_ascii() && !self.denies_ascii
+    }
+
+    /// Classify `ch`, returning its category only if it is prohibited, not
+    /// allowlisted, and that category is enabled
+    fn classify(&self, ch: char) -> Option<CharCategory> {
+        let category = if self.is_denied(ch) {
+            Some(CharCategory::Custom)
+        } else if self.is_allowed(ch) {
+            None
+        } else {
+            CharCategory::classify(ch)
+        };
+        category.filter(|&category| self.is_enabled(category))
+    }
+
+    /// Returns the byte offsets of the confusables to report in `line`, or
+    /// none if that rule is disabled
+    fn confusables(&self, line: &str) -> Vec<usize> {
+        if self.is_enabled(CharCategory::Confusable) {
+            confusables::find(line)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Returns the grapheme clusters of `line` that are not in the required
+    /// normalization form, in order, or none if that rule is disabled
+    fn unnormalized(&self, line: &str) -> Vec<Unnormalized> {
+        let Some(form) = self
+            .normalization
+            .filter(|_| self.is_enabled(CharCategory::Normalization))
+        else {
+            return Vec::new();
+        };
+        line.grapheme_indices(true)
+            .filter(|&(_, g)| !g.is_ascii() && !form.is_normalized(g))
+            .map(|(start, g)| Unnormalized {
+                start,
+                len: g.len(),
+                normalized: form.normalize(g),
+            })
+            .collect()
+    }
+
+    /// Classify `ch` at byte offset `index` of a line, as for
+    /// [`Checker::classify`], also reporting it as a confusable if its offset
+    /// is among `confusables`, or else as non-ASCII in strict mode
+    fn classify_at(&self, ch: char, index: usize, confusables: &[usize]) -> Option<CharCategory> {
+        self.classify(ch).or_else(|| {
+            if ch.is_ascii() || self.is_allowed(ch) {
+                None
+            } else if confusables.binary_search(&index).is_ok() {
+                Some(CharCategory::Confusable)
+            } else {
+                self.is_enabled(CharCategory::NonAscii)
+                    .then_some(CharCategory::NonAscii)
+            }
+        })
+    }
+
+    /// Check an input source, streaming violations to `on_violation`.
+    ///
+    /// Returns `Ok(true)` if violations were found, `Ok(false)` if clean.
+    ///
+    /// Files of at least [`MMAP_THRESHOLD`] bytes are memory-mapped and
+    /// scanned in place rather than read line by line. Inputs that look
+    /// binary are skipped as clean unless disabled with
+    /// [`Checker::with_binary_skip`].
+    pub fn check_input<F>(&self, input: &InputSource, on_violation: F) -> Result<bool, CheckError>
+    where
+        F: FnMut(&Violation),
+    {
+        self.try_check_input(input, continuing(on_violation))
+    }
+
+    /// Check an input source, stopping early if `on_violation` returns
+    /// [`ControlFlow::Break`].
+    ///
+    /// Returns `Ok(true)` if violations were found, including when stopped
+    /// early, or `Ok(false)` if clean.
+    pub fn try_check_input<F>(
+        &self,
+        input: &InputSource,
+        on_violation: F,
+    ) -> Result<bool, CheckError>
+    where
+        F: FnMut(&Violation) -> ControlFlow<()>,
+    {
+        let InputSource::File(path) = input else {
+            let (binary, reader) = self.sniff(input, input.open()?)?;
+            if binary {
+                return Ok(false);
+            }
+            return self.try_check_reader(reader, on_violation);
+        };
+
+        let file = open_file(path)?;
+        let len = file
+            .metadata()
+            .map_err(|source| CheckError::OpenFile {
+                path: path.clone(),
+                source,
+            })?
+            .len();
+        if len >= MMAP_THRESHOLD {
+            // SAFETY: the map is read-only and dropped before returning. If
+            // another process modifies the file while it is mapped, the scan
+            // may see inconsistent contents, which is accepted as it is for
+            // any concurrently modified input. If another process truncates
+            // the file, reading the pages past its new end raises SIGBUS and
+            // kills this process. Files large enough to be mapped are rarely
+            // truncated while being linted, so that risk is accepted for the
+            // speed of mapping.
+            match unsafe { Mmap::map(&file) } {
+                Ok(map) => {
+                    let sample = &map[..map.len().min(BINARY_SAMPLE)];
+                    if self.skip_binary && self.binary_threshold.is_binary(sample) {
+                        log::debug!("skipping binary input {}", input.name());
+                        return Ok(false);
+                    }
+                    return self.check_bytes(&map, on_violation);
+                }
+                Err(err) => log::debug!("cannot map {}: {}", path.display(), err),
+            }
+        }
+        let (binary, reader) = self.sniff(input, BufReader::new(file))?;
+        if binary {
+            return Ok(false);
+        }
+        self.try_check_reader(reader, on_violation)
+    }
+
+    /// Sample the start of `reader` to decide whether `input` should be
+    /// skipped as binary
+    ///
+    /// Returns whether to skip, along with a reader that yields the whole
+    /// input again, sample included.
+    fn sniff<R: BufRead>(
+        &self,
+        input: &InputSource,
+        mut reader: R,
+    ) -> Result<(bool, impl BufRead), CheckError> {
+        let mut sample = Vec::new();
+        if self.skip_binary {
+            reader
+                .by_ref()
+                .take(BINARY_SAMPLE as u64)
+                .read_to_end(&mut sample)
+                .map_err(|source| CheckError::ReadLine { source })?;
+        }
+        let binary = self.skip_binary && self.binary_threshold.is_binary(&sample);
+        if binary {
+            log::debug!("skipping binary input {}", input.name());
+        }
+        Ok((binary, Cursor::new(sample).chain(reader)))
+    }
+
+    /// Check an in-memory byte buffer, which must be valid UTF-8
+    fn check_bytes<F>(&self, bytes: &[u8], on_violation: F) -> Result<bool, CheckError>
+    where
+        F: FnMut(&Violation) -> ControlFlow<()>,
+    {
+        let text = std::str::from_utf8(bytes).map_err(|err| CheckError::ReadLine {
+            source: io::Error::new(io::ErrorKind::InvalidData, err),
+        })?;
+        Ok(self.try_check_str(text, on_violation))
+    }
+
+    /// Fix an input source by replacing prohibited characters with their
+    /// ASCII suggestions.
+    ///
+    /// Files are rewritten atomically via a temporary file and rename, and
+    /// only if at least one replacement was made, so clean files are left
+    /// untouched. Stdin is fixed line by line and streamed to stdout.
+    ///
+    /// Characters without a suggestion (emoji, box drawing, etc.) are left in
+    /// place and passed to `on_violation`, positioned where they end up in
+    /// the fixed output. Returns `Ok(true)` if any such unfixed violations
+    /// remain, `Ok(false)` otherwise.
+    pub fn fix_input<F>(&self, input: &InputSource, on_violation: F) -> Result<bool, CheckError>
+    where
+        F: FnMut(&Violation),
+    {
+        match input {
+            InputSource::File(path) => {
+                let (binary, reader) = self.sniff(input, input.open()?)?;
+                if binary {
+                    return Ok(false);
+                }
+                let mut fixed = Vec::new();
+                let (changed, remaining) = self.fix_reader(reader, &mut fixed, on_violation)?;
+                if changed {
+                    write_atomically(path, &fixed).map_err(|source| CheckError::WriteFile {
+                        path: path.clone(),
+                        source,
+                    })?;
+                }
+                Ok(remaining)
+            }
+            InputSource::Stdin => {
+                let mut stdout = io::stdout().lock();
+                let (binary, mut reader) = self.sniff(input, input.open()?)?;
+                let remaining = if binary {
+                    // Pass binary input through untouched
+                    io::copy(&mut reader, &mut stdout)
+                        .map_err(|source| CheckError::WriteOutput { source })?;
+                    false
+                } else {
+                    self.fix_reader(reader, &mut stdout, on_violation)?.1
+                };
+                stdout
+                    .flush()
+                    .map_err(|source| CheckError::WriteOutput { source })?;
+                Ok(remaining)
+            }
+        }
+    }
+
+    /// Preview the changes [`Checker::fix_input`] would make, without writing.
+    ///
+    /// Returns one [`LineEdit`] per line that would change, in line order.
+    pub fn preview_input(&self, input: &InputSource) -> Result<Vec<LineEdit>, CheckError> {
+        let (binary, reader) = self.sniff(input, input.open()?)?;
+        if binary {
+            return Ok(Vec::new());
+        }
+        self.preview_reader(reader)
+    }
+
+    /// Check a buffered reader for prohibited characters, streaming results.
+    ///
+    /// Returns `Ok(true)` if violations were found, `Ok(false)` if clean.
+    /// Input with a `noemoji: disable-file` header directive is always clean.
+    pub fn check_reader<R, F>(&self, reader: R, on_violation: F) -> Result<bool, CheckError>
+    where
+        R: BufRead,
+        F: FnMut(&Violation),
+    {
+        self.try_check_reader(reader, continuing(on_violation))
+    }
+
+    /// Check a buffered reader, stopping early if `on_violation` returns
+    /// [`ControlFlow::Break`].
+    ///
+    /// Returns `Ok(true)` if violations were found, including when stopped
+    /// early, or `Ok(false)` if clean.
+    pub fn try_check_reader<R, F>(&self, reader: R, mut on_violation: F) -> Result<bool, CheckError>
+    where
+        R: BufRead,
+        F: FnMut(&Violation) -> ControlFlow<()>,
+    {
+        let mut found_violations = false;
+        for v in self.violations(reader) {
+            found_violations = true;
+            if on_violation(&v?).is_break() {
+                break;
+            }
+        }
+        Ok(found_violations)
+    }
+
+    /// Iterate over the violations in a buffered reader
+    ///
+    /// Input is read lazily, a line at a time, as the iterator is advanced,
+    /// so stopping early leaves the rest unread. A read error is yielded as
+    /// an `Err` item and ends the iteration. Input with a
+    /// `noemoji: disable-file` header directive yields nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noemoji::{CharCategory, Checker};
+    ///
+    /// let checker = Checker::new();
+    /// let mut violations = checker.violations("ok\na → b 🚀\n".as_bytes());
+    ///
+    /// let first = violations.next().unwrap().unwrap();
+    /// assert_eq!((first.line, first.column), (2, 3));
+    /// let emoji = violations
+    ///     .filter_map(Result::ok)
+    ///     .find(|v| v.category == CharCategory::Emoji);
+    /// assert!(emoji.is_some());
+    /// ```
+    pub fn violations<R: BufRead>(&self, reader: R) -> Violations<'_, R> {
+        Violations {
+            checker: self,
+            state: ReaderState::Start(reader),
+            suppressions: Suppressions::default(),
+            unchecked: Unchecked::default(),
+            line: String::new(),
+            line_no: 0,
+            offset: 0,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Check an in-memory string for prohibited characters, streaming results.
+    ///
+    /// Uses the same line and column numbering as [`Checker::check_reader`].
+    /// Returns true if any violations were found.
+    pub fn check_str(&self, input: &str, on_violation: impl FnMut(&Violation)) -> bool {
+        self.try_check_str(input, continuing(on_violation))
+    }
+
+    /// Check an in-memory string, stopping early if `on_violation` returns
+    /// [`ControlFlow::Break`].
+    ///
+    /// Returns true if any violations were found, including when stopped early.
+    pub fn try_check_str(
+        &self,
+        input: &str,
+        mut on_violation: impl FnMut(&Violation) -> ControlFlow<()>,
+    ) -> bool {
+        if directive::is_file_disabled(input) {
+            return false;
+        }
+
+        let mut found_violations = false;
+        let mut suppressions = Suppressions::default();
+        let mut unchecked = self.unchecked(input);
+        let mut offset = 0;
+
+        for (line_idx, line) in input.split_inclusive('\n').enumerate() {
+            let allowed = suppressions
+                .line(line)
+                .with_spans(unchecked.line(offset, line.len()));
+            match self.check_line(line, line_idx + 1, offset, &allowed, &mut on_violation) {
+                ControlFlow::Continue(found) => found_violations |= found,
+                ControlFlow::Break(()) => return true,
+            }
+            offset += line.len();
+        }
+
+        found_violations
+    }
+
+    /// Check a single line (including its line ending, if any) for prohibited
+    /// characters
+    ///
+    /// `line_offset` is the byte offset of the start of the line in the input.
+    /// A line without a trailing `\n` is the last line of the input. Characters
+    /// in `allowed` were suppressed by a directive and are not reported.
+    ///
+    /// Returns whether violations were found, or `Break` if `on_violation`
+    /// asked to stop.
+    fn check_line<F>(
+        &self,
+        line: &str,
+        line_no: usize,
+        line_offset: usize,
+        allowed: &Allowed,
+        on_violation: &mut F,
+    ) -> ControlFlow<(), bool>
+    where
+        F: FnMut(&Violation) -> ControlFlow<()>,
+    {
+        if self
+            .lines
+            .as_ref()
+            .is_some_and(|lines| !lines.contains(&line_no))
+        {
+            return ControlFlow::Continue(false);
+        }
+        let mut found_violations = false;
+        let (bom, line) = split_bom(line, line_offset);
+        if bom && self.is_enabled(CharCategory::ByteOrderMark) {
+            found_violations = true;
+            on_violation(&Violation {
+                line: line_no,
+                column: 1,
+                byte_offset: line_offset,
+                ch: BOM,
+                category: CharCategory::ByteOrderMark,
+                suggestion: None,
+                grapheme: None,
+            })?;
+        }
+        let bom_len = if bom { BOM.len_utf8() } else { 0 };
+        let line_offset = line_offset + bom_len;
+        let content = strip_eol(line);
+        let mut columns = Columns::new(content, self.column_mode, self.tab_width);
+        if !self.is_trivially_clean(content) {
+            let confusables = self.confusables(content);
+            let unnormalized = self.unnormalized(content);
+            let mut clusters = Clusters::new(content);
+            for (byte_idx, ch) in content.char_indices() {
+                if allowed.contains_at(ch, bom_len + byte_idx) {
+                    continue;
+                }
+                let span = unnormalized_at(&unnormalized, byte_idx);
+                let classified = self.classify_at(ch, byte_idx, &confusables);
+                let (category, suggestion) = match (classified, span) {
+                    (Some(category), _) => (category, self.suggestion(ch).map(Cow::into_owned)),
+                    (None, Some(span)) => {
+                        (CharCategory::Normalization, Some(span.normalized.clone()))
+                    }
+                    (None, None) => continue,
+                };
+                found_violations = true;
+                on_violation(&Violation {
+                    line: line_no,
+                    column: columns.column(byte_idx),
+                    byte_offset: line_offset + byte_idx,
+                    ch,
+                    category,
+                    suggestion,
+                    grapheme: clusters.at(byte_idx),
+                })?;
+            }
+        }
+
+        if self.is_missing_final_newline(line) {
+            found_violations = true;
+            on_violation(&Violation {
+                line: line_no,
+                column: columns.end(content.len()),
+                byte_offset: line_offset + line.len(),
+                ch: '\n',
+                category: CharCategory::MissingFinalNewline,
+                suggestion: Some("\n".to_owned()),
+                grapheme: None,
+            })?;
+        }
+
+        ControlFlow::Continue(found_violations)
+    }
+
+    /// Returns true if `line` is an unterminated last line and the final
+    /// newline rule is enabled
+    fn is_missing_final_newline(&self, line: &str) -> bool {
+        self.is_enabled(CharCategory::MissingFinalNewline)
+            && !line.is_empty()
+            && !line.ends_with('\n')
+    }
+
+    /// Copy `reader` to `writer`, replacing prohibited characters that have a
+    /// suggestion and reporting the rest.
+    ///
+    /// Line endings are preserved exactly. Returns `(changed, remaining)`, where
+    /// `changed` is true if any replacement was made and `remaining` is true if
+    /// any unfixable violation was reported.
+    fn fix_reader<R, W, F>(
+        &self,
+        reader: R,
+        writer: &mut W,
+        mut on_violation: F,
+    ) -> Result<(bool, bool), CheckError>
+    where
+        R: BufRead,
+        W: Write,
+        F: FnMut(&Violation),
+    {
+        let (disabled, mut reader) = read_header(reader)?;
+        if disabled {
+            io::copy(&mut reader, writer).map_err(|source| CheckError::WriteOutput { source })?;
+            return Ok((false, false));
+        }
+
+        let (mut unchecked, mut reader) = self.read_unchecked(reader)?;
+        let mut changed = false;
+        let mut remaining = false;
+        let mut line = String::new();
+        let mut fixed = String::new();
+        let mut suppressions = Suppressions::default();
+        let mut line_no = 0;
+        let mut offset = 0;
+        // Leftovers are reported where they end up in the output
+        let mut output_offset = 0;
+
+        loop {
+            line.clear();
+            let read = reader
+                .read_line(&mut line)
+                .map_err(|source| CheckError::ReadLine { source })?;
+            if read == 0 {
+                break;
+            }
+            line_no += 1;
+
+            let allowed = suppressions
+                .line(&line)
+                .with_spans(unchecked.line(offset, read));
+            let (line_changed, line_remaining) = self.fix_line(
+                &line,
+                line_no,
+                output_offset,
+                &allowed,
+                &mut fixed,
+                &mut on_violation,
+            );
+            changed |= line_changed;
+            remaining |= line_remaining;
+            offset += read;
+            output_offset += fixed.len();
+
+            writer
+                .write_all(fixed.as_bytes())
+                .map_err(|source| CheckError::WriteOutput { source })?;
+        }
+
+        Ok((changed, remaining))
+    }
+
+    /// Write the fixed form of `line` into `fixed`, replacing prohibited
+    /// characters that have a suggestion and reporting the rest.
+    ///
+    /// Characters left in place are reported at their column in the fixed
+    /// line, since earlier replacements such as `…` to `...` shift them, and
+    /// at a byte offset counted from `line_offset`, where the fixed line
+    /// starts in the output.
+    ///
+    /// Returns `(changed, remaining)` for this line, as for `fix_reader`.
+    fn fix_line<F>(
+        &self,
+        line: &str,
+        line_no: usize,
+        line_offset: usize,
+        allowed: &Allowed,
+        fixed: &mut String,
+        on_violation: &mut F,
+    ) -> (bool, bool)
+    where
+        F: FnMut(&Violation),
+    {
+        let mut changed = false;
+        let mut remaining = false;
+
+        fixed.clear();
+        let (bom, line) = split_bom(line, line_offset);
+        if bom {
+            if self.is_enabled(CharCategory::ByteOrderMark) {
+                changed = true;
+            } else {
+                fixed.push(BOM);
+            }
+        }
+        let bom_len = if bom { BOM.len_utf8() } else { 0 };
+        let start = fixed.len();
+        // Leftover characters, with their byte index in the fixed line
+        let mut leftovers = Vec::new();
+        if self.is_trivially_clean(line) {
+            fixed.push_str(line);
+        } else {
+            let confusables = self.confusables(line);
+            let unnormalized = self.unnormalized(line);
+            // End of the last cluster replaced by its normalized form
+            let mut normalized_to = 0;
+            for (byte_idx, ch) in line.char_indices() {
+                if byte_idx < normalized_to {
+                    continue;
+                }
+                if allowed.contains_at(ch, bom_len + byte_idx) {
+                    fixed.push(ch);
+                    continue;
+                }
+                match self.classify_at(ch, byte_idx, &confusables) {
+                    Some(category) => match self.suggestion(ch) {
+                        Some(replacement) => {
+                            changed = true;
+                            fixed.push_str(&replacement);
+                        }
+                        None => {
+                            leftovers.push((fixed.len() - start, ch, category));
+                            fixed.push(ch);
+                        }
+                    },
+                    None => match unnormalized_at(&unnormalized, byte_idx) {
+                        Some(span) => {
+                            changed = true;
+                            fixed.push_str(&span.normalized);
+                            normalized_to = span.start + span.len;
+                        }
+                        None => fixed.push(ch),
+                    },
+                }
+            }
+        }
+
+        let mut columns = Columns::new(&fixed[start..], self.column_mode, self.tab_width);
+        let mut clusters = Clusters::new(&fixed[start..]);
+        for (fixed_idx, ch, category) in leftovers {
+            remaining = true;
+            on_violation(&Violation {
+                line: line_no,
+                column: columns.column(fixed_idx),
+                byte_offset: line_offset + start + fixed_idx,
+                ch,
+                category,
+                suggestion: None,
+                grapheme: clusters.at(fixed_idx),
+            });
+        }
+
+        if self.is_missing_final_newline(line) {
+            changed = true;
+            fixed.push('\n');
+        }
+
+        (changed, remaining)
+    }
+
+    /// Find the spans of `text` left unchecked under `--lang`
+    fn unchecked(&self, text: &str) -> Unchecked {
+        let Some(language) = self.language else {
+            return Unchecked::default();
+        };
+        match language.unchecked(text) {
+            Some(spans) => Unchecked::new(spans),
+            None => {
+                log::debug!("cannot lex input as {}; checking all of it", language);
+                Unchecked::default()
+            }
+        }
+    }
+
+    /// Find the spans of the input from `reader` left unchecked under
+    /// `--lang`, reading it all into memory first if a language is set
+    ///
+    /// Returns a reader that yields the whole input again.
+    fn read_unchecked<R: Read>(
+        &self,
+        mut reader: Rewound<R>,
+    ) -> Result<(Unchecked, Rewound<R>), CheckError> {
+        if self.language.is_none() {
+            return Ok((Unchecked::default(), reader));
+        }
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .map_err(|source| CheckError::ReadLine { source })?;
+        // Invalid UTF-8 is reported when its line is read
+        let unchecked = match std::str::from_utf8(&bytes) {
+            Ok(text) => self.unchecked(text),
+            Err(_) => Unchecked::default(),
+        };
+        let (_, rest) = reader.into_inner();
+        Ok((unchecked, Cursor::new(bytes).chain(rest)))
+    }
+
+    /// Collect the line edits that fixing `reader` would make, without writing
+    fn preview_reader<R: BufRead>(&self, reader: R) -> Result<Vec<LineEdit>, CheckError> {
+        let (disabled, reader) = read_header(reader)?;
+        let mut edits = Vec::new();
+        if disabled {
+            return Ok(edits);
+        }
+
+        let (mut unchecked, mut reader) = self.read_unchecked(reader)?;
+        let mut line = String::new();
+        let mut fixed = String::new();
+        let mut suppressions = Suppressions::default();
+        let mut line_no = 0;
+        let mut offset = 0;
+
+        loop {
+            line.clear();
+            let read = reader
+                .read_line(&mut line)
+                .map_err(|source| CheckError::ReadLine { source })?;
+            if read == 0 {
+                break;
+            }
+            line_no += 1;
+
+            let allowed = suppressions
+                .line(&line)
+                .with_spans(unchecked.line(offset, read));
+            let (changed, _) =
+                self.fix_line(&line, line_no, offset, &allowed, &mut fixed, &mut |_| {});
+            offset += read;
+            if changed {
+                let eol: &[char] = &['\r', '\n'];
+                edits.push(LineEdit {
+                    line: line_no,
+                    original: line.trim_end_matches(eol).to_owned(),
+                    fixed: fixed.trim_end_matches(eol).to_owned(),
+                });
+            }
+        }
+
+        Ok(edits)
+    }
+}
+
+/// Adapt a plain violation callback to one that never stops early
+fn continuing<F: FnMut(&Violation)>(
+    mut on_violation: F,
+) -> impl FnMut(&Violation) -> ControlFlow<()> {
+    move |v| {
+        on_violation(v);
+        ControlFlow::Continue(())
+    }
+}
+
+/// Iterator over the violations in a reader, returned by
+/// [`Checker::violations`]
+#[derive(Debug)]
+pub struct Violations<'a, R> {
+    /// Checker deciding which characters are violations
+    checker: &'a Checker,
+    /// Progress through the input
+    state: ReaderState<R>,
+    /// Directives seen so far
+    suppressions: Suppressions,
+    /// Spans left unchecked under `--lang`
+    unchecked: Unchecked,
+    /// Buffer holding the line most recently read
+    line: String,
+    /// Number of the line most recently read, starting at 1
+    line_no: usize,
+    /// Byte offset of the start of the next line
+    offset: usize,
+    /// Violations found on the current line and not yet yielded
+    pending: VecDeque<Violation>,
+}
+
+/// A reader with the header bytes consumed by [`read_header`] put back
+type Rewound<R> = Chain<Cursor<Vec<u8>>, R>;
+
+/// Progress of a [`Violations`] iterator through its input
+#[derive(Debug)]
+enum ReaderState<R> {
+    /// The header has not been read yet
+    Start(R),
+    /// Reading lines after the header
+    Lines(Rewound<R>),
+    /// The input is exhausted, disabled, or failed to read
+    Done,
+}
+
+impl<R: BufRead> Violations<'_, R> {
+    /// Read the header, moving from [`ReaderState::Start`] to
+    /// [`ReaderState::Lines`], or to [`ReaderState::Done`] if the input is
+    /// disabled or cannot be read
+    fn start(&mut self) -> Result<(), CheckError> {
+        if let ReaderState::Start(reader) = mem::replace(&mut self.state, ReaderState::Done) {
+            let (disabled, reader) = read_header(reader)?;
+            if !disabled {
+                let (unchecked, reader) = self.checker.read_unchecked(reader)?;
+                self.unchecked = unchecked;
+                self.state = ReaderState::Lines(reader);
+            }
+        }
+        Ok(())
+    }
+
+    /// Read and check the next line, queueing its violations
+    ///
+    /// Returns `Ok(false)` once the input is exhausted.
+    fn read_line(&mut self) -> Result<bool, CheckError> {
+        if matches!(self.state, ReaderState::Start(_)) {
+            self.start()?;
+        }
+        let ReaderState::Lines(reader) = &mut self.state else {
+            return Ok(false);
+        };
+
+        self.line.clear();
+        let read = reader
+            .read_line(&mut self.line)
+            .map_err(|source| CheckError::ReadLine { source })?;
+        if read == 0 {
+            self.state = ReaderState::Done;
+            return Ok(false);
+        }
+        self.line_no += 1;
+
+        let allowed = self
+            .suppressions
+            .line(&self.line)
+            .with_spans(self.unchecked.line(self.offset, read));
+        let pending = &mut self.pending;
+        let _ = self.checker.check_line(
+            &self.line,
+            self.line_no,
+            self.offset,
+            &allowed,
+            &mut continuing(|v| pending.push_back(v.clone())),
+        );
+        self.offset += read;
+        Ok(true)
+    }
+}
+
+impl<R: BufRead> Iterator for Violations<'_, R> {
+    type Item = Result<Violation, CheckError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(v) = self.pending.pop_front() {
+                return Some(Ok(v));
+            }
+            match self.read_line() {
+                Ok(true) => {}
+                Ok(false) => return None,
+                Err(err) => {
+                    self.state = ReaderState::Done;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+/// Open `path` for reading
+///
+/// Directories can be opened on some platforms but not read, so they are
+/// rejected up front with [`CheckError::IsDirectory`].
+fn open_file(path: &Path) -> Result<File, CheckError> {
+    let file = File::open(path).map_err(|source| CheckError::OpenFile {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    if file.metadata().is_ok_and(|metadata| metadata.is_dir()) {
+        return Err(CheckError::IsDirectory {
+            path: path.to_path_buf(),
+        });
+    }
+    Ok(file)
+}
+
+/// Read the header of `reader` and check it for a `disable-file` directive
+///
+/// Returns whether the input is disabled, along with a reader that yields the
+/// whole input again, header included.
+fn read_header<R: BufRead>(mut reader: R) -> Result<(bool, Rewound<R>), CheckError> {
+    let mut header = Vec::new();
+    for _ in 0..directive::HEADER_LINES {
+        let read = reader
+            .read_until(b'\n', &mut header)
+            .map_err(|source| CheckError::ReadLine { source })?;
+        if read == 0 {
+            break;
+        }
+    }
+    let disabled = directive::is_file_disabled(&String::from_utf8_lossy(&header));
+    Ok((disabled, Cursor::new(header).chain(reader)))
+}
+
+/// Unicode byte order mark, as it appears at the start of some UTF-8 files
+const BOM: char = '\u{FEFF}';
+
+/// Split a leading byte order mark off `line` if it starts the input
+///
+/// Returns whether a BOM was removed, along with the rest of the line. A BOM
+/// anywhere other than offset 0 is ordinary content.
+fn split_bom(line: &str, line_offset: usize) -> (bool, &str) {
+    match line.strip_prefix(BOM) {
+        Some(rest) if line_offset == 0 => (true, rest),
+        _ => (false, line),
+    }
+}
+
+/// Strip a trailing `\n` or `\r\n` line ending, as [`BufRead::lines`] does
+fn strip_eol(line: &str) -> &str {
+    match line.strip_suffix('\n') {
+        Some(line) => line.strip_suffix('\r').unwrap_or(line),
+        None => line,
+    }
+}
+
+/// Write `contents` to `path` by writing a sibling temporary file and renaming
+/// it over the original, so the file is never observed partially written.
+fn write_atomically(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "not a file path"))?;
+    let mut tmp_name = std::ffi::OsString::from(".");
+    tmp_name.push(file_name);
+    tmp_name.push(".noemoji-tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+
+    let result = (|| {
+        fs::write(&tmp_path, contents)?;
+        fs::set_permissions(&tmp_path, fs::metadata(path)?.permissions())?;
+        fs::rename(&tmp_path, path)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+    result
+}
+
+/// Check an in-memory string for prohibited characters, streaming results.
+///
+/// Calls `on_violation` for each prohibited character found, with the same
+/// line and column numbering as [`Checker::check_input`]. Returns true if any
+/// violations were found. All categories are checked; use
+/// [`Checker::check_str`] to choose.
+///
+/// # Examples
+///
+/// ```
+/// use noemoji::check::check_str;
+///
+/// let mut columns = Vec::new();
+/// assert!(check_str("a → b", |v| columns.push(v.column)));
+/// assert_eq!(columns, vec![3]);
+/// ```
+pub fn check_str(input: &str, on_violation: impl FnMut(&Violation)) -> bool {
+    Checker::default().check_str(input, on_violation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn check_reader<R, F>(reader: R, on_violation: F) -> Result<bool, CheckError>
+    where
+        R: BufRead,
+        F: FnMut(&Violation),
+    {
+        Checker::default().check_reader(reader, on_violation)
+    }
+
+    fn fix_reader<R, W, F>(
+        reader: R,
+        writer: &mut W,
+        on_violation: F,
+    ) -> Result<(bool, bool), CheckError>
+    where
+        R: BufRead,
+        W: Write,
+        F: FnMut(&Violation),
+    {
+        Checker::default().fix_reader(reader, writer, on_violation)
+    }
+
+    fn preview_reader<R: BufRead>(reader: R) -> Result<Vec<LineEdit>, CheckError> {
+        Checker::default().preview_reader(reader)
+    }
+
+    #[test]
+    fn input_source_name_for_file_is_path() {
+        let input = InputSource::File(PathBuf::from("docs/README.md"));
+        assert_eq!(input.name(), "docs/README.md");
+    }
+
+    #[test]
+    fn input_source_name_for_stdin() {
+        assert_eq!(InputSource::Stdin.name(), "stdin");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn input_source_name_with_non_utf8_path_is_lossy() {
+        use std::{ffi::OsString, os::unix::ffi::OsStringExt};
+        let path = PathBuf::from(OsString::from_vec(vec![b'a', 0xFF, b'b']));
+        let input = InputSource::File(path);
+        assert_eq!(input.name(), "a\u{FFFD}b");
+    }
+
+    #[test]
+    fn violations_yields_first_violation_lazily() {
+        let checker = Checker::new();
+        let mut violations = checker.violations(Cursor::new("clean\n→ ✓\n"));
+
+        let first = violations.next().unwrap().unwrap();
+        assert_eq!((first.line, first.column, first.ch), (2, 1, '→'));
+        let second = violations.next().unwrap().unwrap();
+        assert_eq!(second.ch, '✓');
+        assert!(violations.next().is_none());
+    }
+
+    #[test]
+    fn violations_yields_read_errors_mid_iteration() {
+        let checker = Checker::new();
+        let input: &[u8] = b"a \xE2\x86\x92 b\n\xFF\n\xE2\x86\x92\n";
+        let mut violations = checker.violations(input);
+
+        assert_eq!(violations.next().unwrap().unwrap().ch, '→');
+        assert!(matches!(
+            violations.next(),
+            Some(Err(CheckError::ReadLine { .. }))
+        ));
+        assert!(violations.next().is_none());
+    }
+
+    #[test]
+    fn violations_of_disabled_input_is_empty() {
+        let checker = Checker::new();
+        let input = "<!-- noemoji: disable-file -->\n→\n";
+        assert_eq!(checker.violations(Cursor::new(input)).count(), 0);
+    }
+
+    #[test]
+    fn check_reader_finds_single_violation() {
+        let input = Cursor::new("text → more");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(1, 6, '→')]);
+    }
+
+    #[test]
+    fn check_reader_finds_multiple_violations_same_line() {
+        let input = Cursor::new("a → b ← c");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(1, 3, '→'), (1, 7, '←')]);
+    }
+
+    #[test]
+    fn check_reader_finds_violations_across_lines() {
+        let input = Cursor::new("line one →\nline two ←\nline three");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(1, 10, '→'), (2, 10, '←')]);
+    }
+
+    #[test]
+    fn check_reader_clean_input() {
+        let input = Cursor::new("clean text with no violations");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(!result.unwrap());
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn check_reader_empty_input() {
+        let input = Cursor::new("");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(!result.unwrap());
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn check_reader_all_prohibited_chars() {
+        let input = Cursor::new("→←↑↓");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(
+            violations,
+            vec![(1, 1, '→'), (1, 2, '←'), (1, 3, '↑'), (1, 4, '↓')]
+        );
+    }
+
+    #[test]
     fn check_reader_violation_at_line_start() {
         let input = Cursor::new("→ starts with arrow");
         let mut violations = Vec::new();
 
-        let result = check_reader(input, |line, col, ch| {
-            violations.push((line, col, ch));
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(1, 1, '→')]);
+    }
+
+    #[test]
+    fn check_reader_violation_at_line_end() {
+        let input = Cursor::new("ends with arrow →");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(1, 17, '→')]);
+    }
+
+    #[test]
+    fn check_reader_reports_double_arrow_column() {
+        let input = Cursor::new("if a ⇒ b");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(1, 6, '⇒')]);
+    }
+
+    #[test]
+    fn check_reader_ascii_arrows_are_clean() {
+        let input = Cursor::new("a -> b <- c => d <=> e");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(!result.unwrap());
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn classify_table() {
+        let cases = [
+            ('→', Some(CharCategory::Arrow)),
+            ('✓', Some(CharCategory::Checkmark)),
+            ('─', Some(CharCategory::BoxDrawing)),
+            ('≤', Some(CharCategory::Math)),
+            ('★', Some(CharCategory::Decorative)),
+            ('—', Some(CharCategory::Punctuation)),
+            ('\u{00A0}', Some(CharCategory::Whitespace)),
+            ('🚀', Some(CharCategory::Emoji)),
+            ('a', None),
+            ('€', None),
+            ('©', None),
+            ('é', None),
+            ('世', None),
+        ];
+        for (ch, expected) in cases {
+            assert_eq!(CharCategory::classify(ch), expected, "{ch:?}");
+        }
+    }
+
+    #[test]
+    fn classify_covers_arrow_blocks() {
+        for ch in ['\u{2190}', '\u{21FF}', '⟶', '⟵', '⟹', '↔', '↕', '⤴'] {
+            assert_eq!(CharCategory::classify(ch), Some(CharCategory::Arrow));
+        }
+        for ch in ['-', '>', '<', '=', 'é', '\u{218F}', '\u{2200}'] {
+            assert_eq!(CharCategory::classify(ch), None, "{ch:?} should be allowed");
+        }
+    }
+
+    #[test]
+    fn check_reader_reports_bare_emoji() {
+        let input = Cursor::new("ship it 🚀");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(1, 9, '🚀')]);
+    }
+
+    #[test]
+    fn check_reader_reports_skin_tone_modified_emoji() {
+        // U+1F44D THUMBS UP SIGN followed by U+1F3FD skin tone modifier
+        let input = Cursor::new("ok \u{1F44D}\u{1F3FD}");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations[0], (1, 4, '\u{1F44D}'));
+        assert_eq!(violations.len(), 2);
+    }
+
+    #[test]
+    fn check_reader_reports_flag_sequence() {
+        // Regional indicators U+1F1FA U+1F1F8 form the US flag
+        let input = Cursor::new("flag: \u{1F1FA}\u{1F1F8}");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(1, 7, '\u{1F1FA}'), (1, 8, '\u{1F1F8}')]);
+    }
+
+    #[test]
+    fn check_reader_flags_only_checkmark_in_checklist() {
+        let input = Cursor::new("- [x] done\n- ✓ tests pass");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(2, 3, '✓')]);
+    }
+
+    #[test]
+    fn check_reader_reports_every_box_drawing_character() {
+        let input = Cursor::new("┌─┬─┐\n│a│b│\n└─┴─┘");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations.len(), 13);
+        for corner in [(1, 1, '┌'), (1, 3, '┬'), (1, 5, '┐')] {
+            assert!(violations.contains(&corner), "missing {corner:?}");
+        }
+        for corner in [(3, 1, '└'), (3, 3, '┴'), (3, 5, '┘')] {
+            assert!(violations.contains(&corner), "missing {corner:?}");
+        }
+        assert_eq!(
+            violations
+                .iter()
+                .filter(|&&(line, _, _)| line == 2)
+                .map(|&(_, col, _)| col)
+                .collect::<Vec<_>>(),
+            vec![1, 3, 5]
+        );
+    }
+
+    #[test]
+    fn check_reader_reports_math_comparisons() {
+        let input = Cursor::new("a ≤ b ≠ c");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(1, 3, '≤'), (1, 7, '≠')]);
+    }
+
+    #[test]
+    fn check_reader_ascii_math_is_clean() {
+        let input = Cursor::new("a <= b != c - d");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(!result.unwrap());
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn classify_allows_technical_symbols() {
+        assert_eq!(CharCategory::classify('∞'), None);
+        assert_eq!(CharCategory::classify('°'), None);
+        assert_eq!(CharCategory::classify('−'), Some(CharCategory::Math));
+        assert_eq!(CharCategory::classify('-'), None);
+    }
+
+    #[test]
+    fn check_reader_reports_each_bullet_in_list() {
+        let input = Cursor::new("Features:\n• fast\n• small\n  • nested");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(2, 1, '•'), (3, 1, '•'), (4, 3, '•')]);
+    }
+
+    #[test]
+    fn classify_decorative_keeps_exceptions_allowed() {
+        assert_eq!(CharCategory::classify('°'), None);
+        assert_eq!(CharCategory::classify('€'), None);
+        assert_eq!(CharCategory::classify('£'), None);
+    }
+
+    #[test]
+    fn non_ascii_falls_back_after_other_rules() {
+        let checker = Checker::new().with_non_ascii(true);
+        let mut violations = Vec::new();
+        checker
+            .check_reader(Cursor::new("é → € ok\n"), |v| {
+                violations.push((v.ch, v.category))
+            })
+            .unwrap();
+        assert_eq!(
+            violations,
+            [
+                ('é', CharCategory::NonAscii),
+                ('→', CharCategory::Arrow),
+                ('€', CharCategory::NonAscii),
+            ]
+        );
+
+        // A disabled rule leaves its characters to strict mode
+        let checker = checker.with_arrows(false).with_allowed(['é']);
+        let mut violations = Vec::new();
+        checker
+            .check_reader(Cursor::new("é → ok\n"), |v| {
+                violations.push((v.ch, v.category))
+            })
+            .unwrap();
+        assert_eq!(violations, [('→', CharCategory::NonAscii)]);
+    }
+
+    #[test]
+    fn normalization_reports_and_fixes_each_cluster_once() {
+        let checker = Checker::new().with_normalization(NormalizationForm::Nfc);
+        let input = "e\u{301}\u{301} o\u{308}\n";
+        let mut violations = Vec::new();
+        checker
+            .check_reader(Cursor::new(input), |v| {
+                violations.push((v.column, v.ch, v.suggestion.clone()))
+            })
+            .unwrap();
+        assert_eq!(
+            violations,
+            [
+                (1, 'e', Some("\u{e9}\u{301}".to_owned())),
+                (5, 'o', Some("\u{f6}".to_owned())),
+            ]
+        );
+
+        let mut output = Vec::new();
+        let (changed, remaining) = checker
+            .fix_reader(Cursor::new(input), &mut output, |_| {})
+            .unwrap();
+        assert!(changed && !remaining);
+        assert_eq!(String::from_utf8(output).unwrap(), "\u{e9}\u{301} \u{f6}\n");
+    }
+
+    #[test]
+    fn classify_ligatures_before_letters() {
+        for ch in ['ﬀ', 'ﬁ', 'ﬂ', 'ﬃ', 'ﬄ', 'ﬅ', 'ﬆ', '‼', '‽', '⁇', '⁈', '⁉']
+        {
+            assert_eq!(CharCategory::classify(ch), Some(CharCategory::Ligature));
+            assert!(suggest(ch).is_some());
+        }
+        // Armenian and Hebrew presentation forms are genuine text
+        assert_eq!(CharCategory::classify('\u{FB13}'), None);
+        assert_eq!(CharCategory::classify('\u{FB2A}'), None);
+        assert_eq!(CharCategory::classify('æ'), None);
+    }
+
+    #[test]
+    fn classify_supersub_and_fractions() {
+        for ch in ['¹', '²', '³', '⁰', 'ⁱ', 'ⁿ', '⁺', '₁', '₊', 'ₐ', 'ₜ'] {
+            assert_eq!(CharCategory::classify(ch), Some(CharCategory::Supersub));
+            assert!(suggest(ch).is_some());
+        }
+        for ch in ['¼', '½', '¾', '⅓', '⅞', '↉'] {
+            assert_eq!(CharCategory::classify(ch), Some(CharCategory::Fractions));
+            assert!(suggest(ch).is_some());
+        }
+        assert_eq!(suggest('²').as_deref(), Some("^2"));
+        assert_eq!(suggest('₁').as_deref(), Some("_1"));
+        // Superscript letters from other scripts are genuine text
+        assert_eq!(CharCategory::classify('ª'), None);
+        assert_eq!(CharCategory::classify('ʰ'), None);
+    }
+
+    #[test]
+    fn check_reader_reports_curly_quotes() {
+        let input = Cursor::new("say “hello”");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(1, 5, '“'), (1, 11, '”')]);
+    }
+
+    #[test]
+    fn check_reader_reports_ellipsis() {
+        let input = Cursor::new("wait…");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(1, 5, '…')]);
+    }
+
+    #[test]
+    fn check_reader_reports_no_break_space() {
+        let input = Cursor::new("hello\u{00A0}world");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(1, 6, '\u{00A0}')]);
+    }
+
+    #[test]
+    fn check_reader_reports_zero_width_characters() {
+        let input = Cursor::new("a\u{200B}b\u{200D}c");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| {
+            violations.push((v.line, v.column, v.ch));
+        });
+
+        assert!(result.unwrap());
+        assert_eq!(violations, vec![(1, 2, '\u{200B}'), (1, 4, '\u{200D}')]);
+    }
+
+    #[test]
+    fn display_char_names_invisible_characters() {
+        assert_eq!(DisplayChar('\u{00A0}').to_string(), "U+00A0 NO-BREAK SPACE");
+        assert_eq!(
+            DisplayChar('\u{202F}').to_string(),
+            "U+202F NARROW NO-BREAK SPACE"
+        );
+        assert_eq!(
+            DisplayChar('\u{200B}').to_string(),
+            "U+200B ZERO WIDTH SPACE"
+        );
+        assert_eq!(
+            DisplayChar('\u{3000}').to_string(),
+            "U+3000 IDEOGRAPHIC SPACE"
+        );
+    }
+
+    #[test]
+    fn display_char_quotes_visible_characters() {
+        assert_eq!(DisplayChar('→').to_string(), "'→' U+2192 RIGHTWARDS ARROW");
+        assert_eq!(DisplayChar('🚀').to_string(), "'🚀' U+1F680 ROCKET");
+    }
+
+    #[test]
+    fn display_char_omits_missing_names() {
+        assert_eq!(DisplayChar('\u{0007}').to_string(), "U+0007");
+        assert_eq!(DisplayChar('\u{E000}').to_string(), "'\u{E000}' U+E000");
+    }
+
+    #[test]
+    fn byte_offset_spans_lines_and_multibyte_chars() {
+        // "é\n" is 3 bytes and "a€ " is 5 bytes, so → starts at byte 8
+        let input = "é\na€ →\r\n→";
+        let mut from_reader = Vec::new();
+        let mut from_str = Vec::new();
+
+        check_reader(Cursor::new(input), |v| {
+            from_reader.push((v.line, v.column, v.byte_offset))
+        })
+        .unwrap();
+        check_str(input, |v| from_str.push((v.line, v.column, v.byte_offset)));
+
+        assert_eq!(from_reader, vec![(2, 4, 8), (3, 1, 13)]);
+        assert_eq!(from_str, from_reader);
+        assert_eq!(&input[8..11], "→");
+        assert_eq!(&input[13..], "→");
+    }
+
+    #[test]
+    fn crlf_line_endings_are_stripped_before_checking() {
+        let input = "a→\r\nb\u{A0}\r\nc→";
+        let mut from_reader = Vec::new();
+        let mut from_str = Vec::new();
+
+        check_reader(Cursor::new(input), |v| {
+            from_reader.push((v.line, v.column, v.byte_offset, v.ch))
+        })
+        .unwrap();
+        check_str(input, |v| {
+            from_str.push((v.line, v.column, v.byte_offset, v.ch))
+        });
+
+        // "a→\r\n" is 6 bytes and "b\u{A0}\r\n" is 5, so the terminators
+        // count 2 bytes each and the \r is never reported as whitespace
+        assert_eq!(
+            from_reader,
+            vec![(1, 2, 1, '→'), (2, 2, 7, '\u{A0}'), (3, 2, 12, '→')]
+        );
+        assert_eq!(from_str, from_reader);
+    }
+
+    #[test]
+    fn strip_eol_removes_one_terminator() {
+        assert_eq!(strip_eol("a\r\n"), "a");
+        assert_eq!(strip_eol("a\n"), "a");
+        assert_eq!(strip_eol("a\n\n"), "a\n");
+        assert_eq!(strip_eol("a\r"), "a\r");
+        assert_eq!(strip_eol("a"), "a");
+    }
+
+    #[test]
+    fn allow_directive_suppresses_only_its_line() {
+        let input = "a → b // noemoji: allow\nc → d\n";
+        let mut lines = Vec::new();
+
+        check_reader(Cursor::new(input), |v| lines.push(v.line)).unwrap();
+
+        assert_eq!(lines, vec![2]);
+    }
+
+    #[test]
+    fn allow_next_line_directive_suppresses_following_line() {
+        let input = "# noemoji: allow-next-line U+2192\na → b ≤ c\nd → e\n";
+        let mut from_reader = Vec::new();
+        let mut from_str = Vec::new();
+
+        check_reader(Cursor::new(input), |v| from_reader.push((v.line, v.ch))).unwrap();
+        check_str(input, |v| from_str.push((v.line, v.ch)));
+
+        assert_eq!(from_reader, vec![(2, '≤'), (3, '→')]);
+        assert_eq!(from_str, from_reader);
+    }
+
+    #[test]
+    fn disable_file_directive_skips_input() {
+        let input = "// noemoji: disable-file\n\n→ ╔═╗ 🚀\n";
+
+        assert!(!check_reader(Cursor::new(input), |_| panic!("unexpected violation")).unwrap());
+        assert!(!check_str(input, |_| panic!("unexpected violation")));
+
+        let mut output = Vec::new();
+        let result = fix_reader(Cursor::new(input), &mut output, |_| {});
+        assert_eq!(result.unwrap(), (false, false));
+        assert_eq!(String::from_utf8(output).unwrap(), input);
+        assert!(preview_reader(Cursor::new(input)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn header_is_replayed_when_not_disabled() {
+        let input = "1 →\n2\n3\n4\n5\n6 →\n";
+        let mut lines = Vec::new();
+
+        check_reader(Cursor::new(input), |v| lines.push(v.line)).unwrap();
+
+        assert_eq!(lines, vec![1, 6]);
+    }
+
+    #[test]
+    fn ascii_fast_path_matches_full_scan() {
+        let input = "plain ascii\n\tindented\nmixed → here\nmore ascii\ncafé ≤ 🚀\n";
+        let mut violations = Vec::new();
+
+        check_str(input, |v| {
+            violations.push((v.line, v.column, v.byte_offset, v.ch))
+        });
+
+        assert_eq!(
+            violations,
+            vec![(3, 7, 28, '→'), (5, 6, 54, '≤'), (5, 8, 58, '🚀')]
+        );
+        let checker = Checker::new();
+        assert!(checker.is_trivially_clean("plain ascii\n"));
+        assert!(!checker.is_trivially_clean("mixed → here\n"));
+    }
+
+    #[test]
+    fn ascii_fast_path_respects_ascii_deny_list() {
+        let checker = Checker::new().with_denied(['`']);
+        let mut violations = Vec::new();
+
+        checker.check_str("use `code`\n", |v| violations.push(v.column));
+
+        assert!(!checker.is_trivially_clean("use `code`"));
+        assert_eq!(violations, vec![5, 10]);
+    }
+
+    #[test]
+    fn check_input_maps_large_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("large.txt");
+        let line = "the quick brown fox jumps over the lazy dog\n";
+        let lines = 3 * MMAP_THRESHOLD as usize / line.len();
+        let mut contents = line.repeat(lines);
+        contents.push_str("near the end → here\n");
+        contents.push_str(line);
+        fs::write(&path, &contents).unwrap();
+        assert!(fs::metadata(&path).unwrap().len() >= MMAP_THRESHOLD);
+
+        let mut violations = Vec::new();
+        let found = Checker::new()
+            .check_input(&InputSource::File(path), |v| {
+                violations.push((v.line, v.column, v.byte_offset))
+            })
+            .unwrap();
+
+        assert!(found);
+        assert_eq!(violations, vec![(lines + 1, 14, lines * line.len() + 13)]);
+    }
+
+    #[test]
+    fn check_input_rejects_invalid_utf8_in_large_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("binary.dat");
+        let mut contents = vec![b'a'; MMAP_THRESHOLD as usize];
+        contents.extend_from_slice(b"\xff\xfe\n");
+        fs::write(&path, &contents).unwrap();
+
+        let result = Checker::new().check_input(&InputSource::File(path), |_| {});
+
+        assert!(matches!(result, Err(CheckError::ReadLine { .. })));
+    }
+
+    #[test]
+    fn try_check_stops_when_callback_breaks() {
+        let input = "a → b\nc ≤ d → e\n";
+        let mut from_reader = Vec::new();
+        let mut from_str = Vec::new();
+
+        let found = Checker::new()
+            .try_check_reader(Cursor::new(input), |v| {
+                from_reader.push(v.ch);
+                if from_reader.len() == 2 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .unwrap();
+        let found_str = Checker::new().try_check_str(input, |v| {
+            from_str.push(v.ch);
+            ControlFlow::Break(())
+        });
+
+        assert!(found);
+        assert!(found_str);
+        assert_eq!(from_reader, vec!['→', '≤']);
+        assert_eq!(from_str, vec!['→']);
+    }
+
+    #[test]
+    fn fix_leaves_suppressed_chars() {
+        let mut output = Vec::new();
+
+        let result = fix_reader(
+            Cursor::new("a → b # noemoji: allow\nc → d\n"),
+            &mut output,
+            |_| {},
+        );
+
+        assert_eq!(result.unwrap(), (true, false));
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "a → b # noemoji: allow\nc -> d\n"
+        );
+    }
+
+    #[test]
+    fn fix_reports_byte_offset() {
+        let mut offsets = Vec::new();
+
+        fix_reader(Cursor::new("→\n🚀"), &mut Vec::new(), |v| {
+            offsets.push(v.byte_offset)
+        })
+        .unwrap();
+
+        // Offset in the fixed output "->\n🚀"
+        assert_eq!(offsets, vec![3]);
+    }
+
+    #[test]
+    fn column_modes_count_combining_sequences() {
+        let columns = |mode, input: &str| {
+            let mut columns = Vec::new();
+            Checker::new()
+                .with_column_mode(mode)
+                .check_str(input, |v| columns.push(v.column));
+            columns
+        };
+        let precomposed = "caf\u{E9} → x";
+        let combining = "cafe\u{301} → x";
+
+        assert_eq!(columns(ColumnMode::Char, precomposed), vec![6]);
+        assert_eq!(columns(ColumnMode::Char, combining), vec![7]);
+        assert_eq!(columns(ColumnMode::Grapheme, precomposed), vec![6]);
+        assert_eq!(columns(ColumnMode::Grapheme, combining), vec![6]);
+        assert_eq!(columns(ColumnMode::Byte, precomposed), vec![7]);
+        assert_eq!(columns(ColumnMode::Byte, combining), vec![8]);
+    }
+
+    #[test]
+    fn grapheme_columns_treat_zwj_sequence_as_one() {
+        let mut columns = Vec::new();
+
+        Checker::new()
+            .with_column_mode(ColumnMode::Grapheme)
+            .check_str("\u{1F468}\u{200D}\u{1F469} →", |v| columns.push(v.column));
+
+        // Every part of the family emoji shares column 1; the arrow is at 3
+        assert_eq!(columns, vec![1, 1, 1, 3]);
+    }
+
+    #[test]
+    fn violations_in_zwj_sequence_carry_whole_cluster() {
+        let family = "\u{1F469}\u{200D}\u{1F467}";
+        let mut graphemes = Vec::new();
+
+        Checker::new().check_str(&format!("{family} → 🚀"), |v| {
+            graphemes.push((v.ch, v.grapheme.clone()))
         });
 
+        assert_eq!(
+            graphemes,
+            vec![
+                ('\u{1F469}', Some(family.to_owned())),
+                ('\u{200D}', Some(family.to_owned())),
+                ('\u{1F467}', Some(family.to_owned())),
+                ('→', None),
+                ('🚀', None),
+            ]
+        );
+    }
+
+    #[test]
+    fn tab_width_expands_tabs_in_columns() {
+        let columns = |checker: Checker, input: &str| {
+            let mut columns = Vec::new();
+            checker.check_str(input, |v| columns.push((v.column, v.byte_offset)));
+            columns
+        };
+
+        assert_eq!(
+            columns(Checker::new().with_tab_width(4), "\t→"),
+            vec![(5, 1)]
+        );
+        assert_eq!(columns(Checker::new(), "\t→"), vec![(9, 1)]);
+        assert_eq!(
+            columns(Checker::new().with_tab_width(1), "\t→"),
+            vec![(2, 1)]
+        );
+        assert_eq!(
+            columns(Checker::new().with_tab_width(4), "ab\t→\t→"),
+            vec![(5, 3), (9, 7)]
+        );
+        assert_eq!(
+            columns(
+                Checker::new()
+                    .with_tab_width(4)
+                    .with_column_mode(ColumnMode::Grapheme),
+                "e\u{301}\t→"
+            ),
+            vec![(5, 4)]
+        );
+        assert_eq!(
+            columns(
+                Checker::new()
+                    .with_tab_width(4)
+                    .with_column_mode(ColumnMode::Byte),
+                "\t→"
+            ),
+            vec![(2, 1)]
+        );
+    }
+
+    #[test]
+    fn column_mode_from_str() {
+        assert_eq!("grapheme".parse(), Ok(ColumnMode::Grapheme));
+        assert_eq!("byte".parse(), Ok(ColumnMode::Byte));
+        assert_eq!("char".parse(), Ok(ColumnMode::Char));
+        assert!("chars".parse::<ColumnMode>().is_err());
+    }
+
+    #[test]
+    fn check_reader_populates_category_and_suggestion() {
+        let input = Cursor::new("go → 🚀");
+        let mut violations = Vec::new();
+
+        let result = check_reader(input, |v| violations.push(v.clone()));
+
         assert!(result.unwrap());
-        assert_eq!(violations, vec![(1, 1, '→')]);
+        assert_eq!(
+            violations,
+            vec![
+                Violation {
+                    line: 1,
+                    column: 4,
+                    byte_offset: 3,
+                    ch: '→',
+                    category: CharCategory::Arrow,
+                    suggestion: Some("->".to_owned()),
+                    grapheme: None,
+                },
+                Violation {
+                    line: 1,
+                    column: 6,
+                    byte_offset: 7,
+                    ch: '🚀',
+                    category: CharCategory::Emoji,
+                    suggestion: None,
+                    grapheme: None,
+                },
+            ]
+        );
     }
 
     #[test]
-    fn check_reader_violation_at_line_end() {
-        let input = Cursor::new("ends with arrow →");
+    fn fix_reader_replaces_suggested_characters() {
+        let input = Cursor::new("a → b\r\nsay “hi”\n");
+        let mut output = Vec::new();
+        let mut violations = Vec::new();
+
+        let result = fix_reader(input, &mut output, |v| violations.push(v.clone()));
+
+        assert_eq!(result.unwrap(), (true, false));
+        assert_eq!(String::from_utf8(output).unwrap(), "a -> b\r\nsay \"hi\"\n");
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn fix_reader_leaves_unfixable_characters_and_reports_them() {
+        let input = Cursor::new("ship → 🚀");
+        let mut output = Vec::new();
         let mut violations = Vec::new();
 
-        let result = check_reader(input, |line, col, ch| {
-            violations.push((line, col, ch));
+        let result = fix_reader(input, &mut output, |v| {
+            violations.push((v.line, v.column, v.ch));
         });
 
+        assert_eq!(result.unwrap(), (true, true));
+        assert_eq!(String::from_utf8(output).unwrap(), "ship -> 🚀");
+        // Column in the fixed line, after "->" widened the arrow
+        assert_eq!(violations, vec![(1, 9, '🚀')]);
+    }
+
+    #[test]
+    fn fix_reader_clean_input_is_unchanged() {
+        let input = Cursor::new("clean\ntext\n");
+        let mut output = Vec::new();
+
+        let result = fix_reader(input, &mut output, |_| {});
+
+        assert_eq!(result.unwrap(), (false, false));
+        assert_eq!(String::from_utf8(output).unwrap(), "clean\ntext\n");
+    }
+
+    #[test]
+    fn preview_reader_collects_changed_lines() {
+        let input = Cursor::new("clean\na → b\r\nemoji 🚀\n");
+
+        let edits = preview_reader(input).unwrap();
+
+        assert_eq!(
+            edits,
+            vec![LineEdit {
+                line: 2,
+                original: "a → b".to_owned(),
+                fixed: "a -> b".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn check_str_matches_check_reader() {
+        let text = "plain\r\nx → y ≤ z\n\n🚀 end";
+        let mut from_str = Vec::new();
+        let mut from_reader = Vec::new();
+
+        let found = check_str(text, |v| from_str.push(v.clone()));
+        let result = check_reader(Cursor::new(text), |v| from_reader.push(v.clone()));
+
+        assert!(found);
         assert!(result.unwrap());
-        assert_eq!(violations, vec![(1, 17, '→')]);
+        assert_eq!(from_str, from_reader);
+        assert_eq!(
+            from_str
+                .iter()
+                .map(|v| (v.line, v.column))
+                .collect::<Vec<_>>(),
+            vec![(2, 3), (2, 7), (4, 1)]
+        );
+    }
+
+    #[test]
+    fn check_str_clean_input() {
+        assert!(!check_str("nothing here\n", |_| panic!(
+            "unexpected violation"
+        )));
+        assert!(!check_str("", |_| panic!("unexpected violation")));
+    }
+
+    #[test]
+    fn checker_with_only_arrows_ignores_emoji() {
+        let checker = CharCategory::ALL
+            .iter()
+            .fold(Checker::new(), |c, &category| {
+                c.with_category(category, false)
+            })
+            .with_arrows(true);
+        let mut violations = Vec::new();
+
+        let found = checker.check_str("rocket 🚀 then →", |v| violations.push(v.clone()));
+
+        assert!(found);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].ch, '→');
+        assert_eq!(violations[0].category, CharCategory::Arrow);
+    }
+
+    #[test]
+    fn checker_builder_toggles_categories() {
+        let checker = Checker::new().with_emoji(false).with_math(false);
+
+        assert!(!checker.is_enabled(CharCategory::Emoji));
+        assert!(!checker.is_enabled(CharCategory::Math));
+        assert!(checker.is_enabled(CharCategory::Arrow));
+        assert!(checker.with_emoji(true).is_enabled(CharCategory::Emoji));
+    }
+
+    #[test]
+    fn checker_settings_each_change_behavior() {
+        let report = |checker: &Checker, input: &str| {
+            let mut found = Vec::new();
+            checker.check_str(input, |v| found.push((v.column, v.ch, v.category)));
+            found
+        };
+        let default = Checker::default();
+        assert_eq!(default, Checker::new());
+
+        // Enabled categories
+        assert_eq!(report(&default.clone().with_arrows(false), "→"), vec![]);
+        // Allow and deny lists
+        assert_eq!(report(&default.clone().with_allowed(['→']), "→"), vec![]);
+        assert_eq!(
+            report(&default.clone().with_denied(['x']), "x"),
+            vec![(1, 'x', CharCategory::Custom)]
+        );
+        // Column mode and tab width
+        assert_eq!(report(&default, "é→")[0].0, 2);
+        assert_eq!(
+            report(&default.clone().with_column_mode(ColumnMode::Byte), "é→")[0].0,
+            3
+        );
+        assert_eq!(report(&default, "\t→")[0].0, 9);
+        assert_eq!(report(&default.clone().with_tab_width(4), "\t→")[0].0, 5);
+        // Byte order mark handling
+        assert_eq!(report(&default, "\u{FEFF}x"), vec![]);
+        assert_eq!(
+            report(&default.clone().with_byte_order_mark(true), "\u{FEFF}x"),
+            vec![(1, '\u{FEFF}', CharCategory::ByteOrderMark)]
+        );
+        // Source language
+        assert_eq!(report(&default, "\"→\"").len(), 1);
+        assert_eq!(
+            report(&default.clone().with_language(Language::Rust), "\"→\""),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn checker_with_language_skips_literals_in_every_path() {
+        let checker = Checker::new().with_language(Language::Rust);
+        let input = "\u{FEFF}let s = \"→\"; // ←\nlet t = '→'; /* ≤\n≥ */ \"…\n\" 🚀\n";
+        let expected = vec![(2, '→'), (4, '🚀')];
+
+        let mut found = Vec::new();
+        checker.check_str(input, |v| found.push((v.line, v.ch)));
+        assert_eq!(found, expected);
+
+        let found: Vec<_> = checker
+            .violations(input.as_bytes())
+            .map(|v| v.map(|v| (v.line, v.ch)).unwrap())
+            .collect();
+        assert_eq!(found, expected);
+
+        let mut output = Vec::new();
+        let mut found = Vec::new();
+        checker
+            .fix_reader(input.as_bytes(), &mut output, |v| {
+                found.push((v.line, v.ch))
+            })
+            .unwrap();
+        assert_eq!(found, vec![(4, '🚀')]);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            input.replace("'→'", "'->'")
+        );
+        assert_eq!(checker.preview_reader(input.as_bytes()).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn checker_with_language_checks_unlexable_input_in_full() {
+        let checker = Checker::new().with_language(Language::Rust);
+        let mut found = Vec::new();
+        checker.check_str("let s = \"→;\n", |v| found.push(v.ch));
+        assert_eq!(found, vec!['→']);
+    }
+
+    #[test]
+    fn checker_with_confusables_reports_only_mixed_words() {
+        let checker = Checker::new().with_confusables(true);
+        let mut found = Vec::new();
+        checker.check_str("Ａpple\nМосква\n", |v| {
+            found.push((v.line, v.ch, v.category))
+        });
+        assert_eq!(found, vec![(1, 'Ａ', CharCategory::Confusable)]);
+        assert!(!Checker::new().check_str("Ａpple", |_| {}));
+        let allowed = Checker::new().with_confusables(true).with_allowed(['Ａ']);
+        assert!(!allowed.check_str("Ａpple", |_| {}));
+    }
+
+    #[test]
+    fn checker_with_lines_reports_only_that_range() {
+        let checker = Checker::new().with_lines(2..=3);
+        let mut found = Vec::new();
+        checker.check_str("→\na →\n→\n→\n", |v| {
+            found.push((v.line, v.column, v.byte_offset))
+        });
+        assert_eq!(found, vec![(2, 3, 6), (3, 1, 10)]);
+    }
+
+    #[test]
+    fn checker_severity_defaults_to_error() {
+        let checker = Checker::new().with_severity(CharCategory::Punctuation, Severity::Warning);
+
+        assert_eq!(
+            checker.severity(CharCategory::Punctuation),
+            Severity::Warning
+        );
+        assert_eq!(checker.severity(CharCategory::Emoji), Severity::Error);
+        let checker = checker.with_severity(CharCategory::Punctuation, Severity::Error);
+        assert_eq!(checker.severity(CharCategory::Punctuation), Severity::Error);
+    }
+
+    #[test]
+    fn checker_default_enables_every_category() {
+        let checker = Checker::default();
+        for &category in CharCategory::ALL {
+            assert_eq!(
+                checker.is_enabled(category),
+                !category.is_opt_in(),
+                "{category}"
+            );
+        }
+        assert!(!checker.is_enabled(CharCategory::MissingFinalNewline));
+    }
+
+    #[test]
+    fn final_newline_rule_reports_unterminated_last_line() {
+        let checker = Checker::new().with_final_newline(true);
+        let mut violations = Vec::new();
+
+        let found = checker
+            .check_reader(Cursor::new("ok\nlast"), |v| violations.push(v.clone()))
+            .unwrap();
+
+        assert!(found);
+        assert_eq!(violations.len(), 1);
+        let v = &violations[0];
+        assert_eq!((v.line, v.column, v.byte_offset), (2, 5, 7));
+        assert_eq!(v.category, CharCategory::MissingFinalNewline);
+        assert!(checker.check_str("ok\nlast", |_| {}));
+    }
+
+    #[test]
+    fn final_newline_rule_accepts_terminated_and_empty_input() {
+        let checker = Checker::new().with_final_newline(true);
+        for input in ["ok\n", "ok\r\n", "", "\n"] {
+            assert!(
+                !checker
+                    .check_reader(Cursor::new(input), |_| panic!("flagged {input:?}"))
+                    .unwrap()
+            );
+            assert!(!checker.check_str(input, |_| panic!("flagged {input:?}")));
+        }
+        // Off by default
+        assert!(!check_str("no newline", |_| panic!("unexpected violation")));
+    }
+
+    #[test]
+    fn final_newline_rule_fix_appends_newline() {
+        let checker = Checker::new().with_final_newline(true);
+        let mut output = Vec::new();
+
+        let result = checker.fix_reader(Cursor::new("a → b"), &mut output, |_| {});
+
+        assert_eq!(result.unwrap(), (true, false));
+        assert_eq!(String::from_utf8(output).unwrap(), "a -> b\n");
+    }
+
+    #[test]
+    fn checker_fix_skips_disabled_categories() {
+        let checker = Checker::new().with_punctuation(false);
+        let mut output = Vec::new();
+
+        let result = checker.fix_reader(Cursor::new("“a” → b"), &mut output, |_| {});
+
+        assert_eq!(result.unwrap(), (true, false));
+        assert_eq!(String::from_utf8(output).unwrap(), "“a” -> b");
+    }
+
+    #[test]
+    fn checker_skips_allowed_chars() {
+        let checker = Checker::new().with_allowed(['≤']);
+        let mut violations = Vec::new();
+
+        let found = checker.check_str("a ≤ b ≥ c", |v| violations.push(v.ch));
+
+        assert!(found);
+        assert_eq!(violations, vec!['≥']);
+        assert!(checker.is_allowed('≤'));
+        assert!(!checker.is_allowed('≥'));
+    }
+
+    #[test]
+    fn checker_reports_denied_chars_as_custom() {
+        let checker = Checker::new().with_denied(['·']).with_allowed(['·', '≤']);
+        let mut violations = Vec::new();
+
+        let found = checker.check_str("a·b ≤ c", |v| violations.push((v.ch, v.category)));
+
+        assert!(found);
+        assert_eq!(violations, vec![('·', CharCategory::Custom)]);
+        assert!(!checker.with_custom(false).check_str("a·b", |_| {}));
+    }
+
+    #[test]
+    fn international_text_is_never_flagged() {
+        for line in [
+            "日本語のテキストです。",
+            "café naïve résumé",
+            "Привет, Москва",
+            "世界",
+            "e\u{0301}",
+            "١٢٣ ४५६",
+        ] {
+            assert!(
+                !check_str(line, |v| panic!("unexpected violation {v:?} in {line}")),
+                "{line}"
+            );
+        }
+        assert!(!is_international_text('\u{FE0F}'));
+        assert!(!is_international_text('½'));
+        assert!(!is_international_text('★'));
+    }
+
+    #[test]
+    fn allowed_exceptions_are_never_flagged() {
+        for ch in ['$', '¢', '£', '¥', '€', '₹', '₽', '₩'] {
+            assert!(is_allowed_exception(ch), "{ch} not allowed");
+        }
+        for &ch in ALLOWED_SYMBOLS {
+            assert!(is_allowed_exception(ch), "{ch} not allowed");
+            assert_eq!(CharCategory::classify(ch), None);
+        }
+        assert!(!check_str("€5 § 3 © 2024", |_| panic!(
+            "unexpected violation"
+        )));
+        assert!(!is_allowed_exception('★'));
+        assert_eq!(CharCategory::classify('★'), Some(CharCategory::Decorative));
+    }
+
+    #[test]
+    fn classify_assigns_categories() {
+        assert_eq!(CharCategory::classify('→'), Some(CharCategory::Arrow));
+        assert_eq!(CharCategory::classify('✔'), Some(CharCategory::Checkmark));
+        assert_eq!(CharCategory::classify('❌'), Some(CharCategory::Checkmark));
+        assert_eq!(CharCategory::classify('╔'), Some(CharCategory::BoxDrawing));
+        assert_eq!(CharCategory::classify('█'), Some(CharCategory::BoxDrawing));
+        assert_eq!(CharCategory::classify('≥'), Some(CharCategory::Math));
+        assert_eq!(CharCategory::classify('×'), Some(CharCategory::Math));
+        for ch in ['★', '●', '♦', '•', '‣', '▪'] {
+            assert_eq!(CharCategory::classify(ch), Some(CharCategory::Decorative));
+        }
+        for ch in ['‘', '’', '“', '”', '—', '–', '…'] {
+            assert_eq!(CharCategory::classify(ch), Some(CharCategory::Punctuation));
+        }
+        for ch in ['\u{00A0}', '\u{2003}', '\u{200B}', '\u{202F}', '\u{3000}'] {
+            assert_eq!(CharCategory::classify(ch), Some(CharCategory::Whitespace));
+        }
+        assert_eq!(CharCategory::classify(' '), None);
+        assert_eq!(CharCategory::classify('\t'), None);
+        assert_eq!(CharCategory::classify('😀'), Some(CharCategory::Emoji));
+        assert_eq!(
+            CharCategory::classify('\u{FE0F}'),
+            Some(CharCategory::Emoji)
+        );
+        assert_eq!(CharCategory::classify('a'), None);
+        assert_eq!(CharCategory::classify('世'), None);
+    }
+
+    #[test]
+    fn suggest_maps_arrows_to_ascii() {
+        assert_eq!(suggest('→').as_deref(), Some("->"));
+        assert_eq!(suggest('⟶').as_deref(), Some("->"));
+        assert_eq!(suggest('←').as_deref(), Some("<-"));
+        assert_eq!(suggest('⇒').as_deref(), Some("=>"));
+        assert_eq!(suggest('⟹').as_deref(), Some("=>"));
+        assert_eq!(suggest('↔').as_deref(), Some("<->"));
+        assert_eq!(suggest('↕'), None);
+    }
+
+    #[test]
+    fn suggest_maps_checkmarks_to_brackets() {
+        for ch in ['✓', '✔', '☑', '✅'] {
+            assert_eq!(suggest(ch).as_deref(), Some("[x]"));
+        }
+        for ch in ['✗', '✘', '☒', '❌'] {
+            assert_eq!(suggest(ch).as_deref(), Some("[ ]"));
+        }
+    }
+
+    #[test]
+    fn suggest_maps_punctuation_to_ascii() {
+        assert_eq!(suggest('‘').as_deref(), Some("'"));
+        assert_eq!(suggest('’').as_deref(), Some("'"));
+        assert_eq!(suggest('“').as_deref(), Some("\""));
+        assert_eq!(suggest('”').as_deref(), Some("\""));
+        assert_eq!(suggest('—').as_deref(), Some("--"));
+        assert_eq!(suggest('–').as_deref(), Some("-"));
+        assert_eq!(suggest('…').as_deref(), Some("..."));
+    }
+
+    #[test]
+    fn suggest_maps_bullet_to_hyphen() {
+        assert_eq!(suggest('•').as_deref(), Some("-"));
+        assert_eq!(suggest('★'), None);
+    }
+
+    #[test]
+    fn suggest_maps_math_whitespace_and_fractions() {
+        assert_eq!(suggest('≤').as_deref(), Some("<="));
+        assert_eq!(suggest('×').as_deref(), Some("*"));
+        assert_eq!(suggest('\u{00A0}').as_deref(), Some(" "));
+        assert_eq!(suggest('½').as_deref(), Some("1/2"));
+        assert_eq!(suggest('⅞').as_deref(), Some("7/8"));
+        assert_eq!(suggest('─'), None);
+        assert_eq!(suggest('🚀'), None);
+    }
+
+    #[test]
+    fn suggestions_are_sorted_and_unique() {
+        assert!(SUGGESTIONS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(NOTES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn is_binary_detects_nul_bytes() {
+        assert!(is_binary(b"PNG\0\0\x01"));
+        assert!(!is_binary(b""));
+        assert!(!is_binary(
+            "plain text → arrows\r\n\tindented\x0c\x1b[0m".as_bytes()
+        ));
+    }
+
+    #[test]
+    fn is_binary_detects_dense_control_bytes() {
+        assert!(is_binary(b"ab\x01\x02\x03\x04cd"));
+        assert!(!is_binary(
+            b"a single \x07 bell in a longer line of ordinary text"
+        ));
+    }
+
+    #[test]
+    fn binary_threshold_parses_ratios() {
+        assert_eq!("0.1".parse(), Ok(BinaryThreshold::default()));
+        assert_eq!("1".parse::<BinaryThreshold>().unwrap().to_string(), "1.0");
+        assert_eq!(BinaryThreshold::default().to_string(), "0.1");
+        for bad in ["-0.1", "1.01", "NaN", "half", ""] {
+            assert!(bad.parse::<BinaryThreshold>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn binary_threshold_sets_control_byte_ratio() {
+        let sample = b"ab\x01cdefgh\x02j";
+        let threshold = |ratio| BinaryThreshold::new(ratio).unwrap();
+
+        // 2 of 11 bytes are control characters, just over 18%
+        assert!(threshold(0.18).is_binary(sample));
+        assert!(!threshold(0.19).is_binary(sample));
+        assert!(threshold(0.0).is_binary(b"a\x01"));
+        assert!(!threshold(0.0).is_binary(b"plain"));
+        assert!(!threshold(1.0).is_binary(b"\0\0\0"));
+    }
+
+    #[test]
+    fn check_input_skips_binary_files_unless_disabled() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("image.bin");
+        std::fs::write(&path, b"\x89PNG\0\0\xff\xfe \xe2\x86\x92\n").unwrap();
+        let input = InputSource::File(path);
+
+        assert!(!Checker::new().check_input(&input, |_| {}).unwrap());
+        assert!(Checker::new().preview_input(&input).unwrap().is_empty());
+        assert!(
+            Checker::new()
+                .with_binary_skip(false)
+                .check_input(&input, |_| {})
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn leading_bom_is_skipped_and_not_counted_in_columns() {
+        let mut violations = Vec::new();
+        let found = Checker::new().check_str("\u{FEFF}→ a\n→\n", |v| {
+            violations.push((v.line, v.column, v.byte_offset, v.category));
+        });
+        assert!(found);
+        assert_eq!(
+            violations,
+            vec![
+                (1, 1, 3, CharCategory::Arrow),
+                (2, 1, 9, CharCategory::Arrow)
+            ]
+        );
+    }
+
+    #[test]
+    fn bom_rule_reports_leading_bom_only() {
+        let checker = Checker::new().with_byte_order_mark(true);
+        let mut violations = Vec::new();
+        checker.check_str("\u{FEFF}a\u{FEFF}\n", |v| {
+            violations.push((v.column, v.byte_offset, v.ch, v.category));
+        });
+        assert_eq!(
+            violations,
+            vec![(1, 0, '\u{FEFF}', CharCategory::ByteOrderMark)]
+        );
+        assert!(!Checker::new().check_str("\u{FEFF}a\n", |_| {}));
+    }
+
+    #[test]
+    fn fix_keeps_bom_unless_rule_enabled() {
+        let input = "\u{FEFF}a → b\n";
+        let mut output = Vec::new();
+        fix_reader(Cursor::new(input), &mut output, |_| {}).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "\u{FEFF}a -> b\n");
+
+        let mut output = Vec::new();
+        let result = Checker::new().with_byte_order_mark(true).fix_reader(
+            Cursor::new(input),
+            &mut output,
+            |_| {},
+        );
+        assert_eq!(result.unwrap(), (true, false));
+        assert_eq!(String::from_utf8(output).unwrap(), "a -> b\n");
     }
 }
 
@@ -5,14 +5,31 @@
 //! Command-line interface parsing and help/version display
 
 use std::{
+    borrow::Cow,
+    collections::HashSet,
+    env,
     ffi::OsString,
-    path::{Path, PathBuf},
+    fs,
+    io::{self, IsTerminal, Read},
+    ops::RangeInclusive,
+    path::{self, Component, Path, PathBuf},
     process::{ExitCode, Termination},
+    sync::LazyLock,
 };
 
 use thiserror::Error;
 
-use crate::check::InputSource;
+use crate::{
+    check::{
+        BinaryThreshold, CharCategory, Checker, ColumnMode, DisplayChar, InputSource,
+        NormalizationForm, note, suggest,
+    },
+    config::{ConfigSource, parse_char},
+    confusables::confusable,
+    lang::Language,
+};
+
+pub use crate::output::OutputFormat;
 
 /// Error type for command line argument parsing
 #[derive(Debug, Error)]
@@ -38,6 +55,104 @@ pub enum CliError {
         value: OsString,
     },
 
+    /// Option is only meaningful together with another option
+    #[error("option '{option}' requires '{required}'")]
+    MissingRequiredOption {
+        /// The option that was provided
+        option: String,
+        /// The option it depends on
+        required: String,
+    },
+
+    /// Two options that cannot be combined were both given
+    #[error("options '{option}' and '{other}' cannot be used together")]
+    ConflictingOptions {
+        /// The option that was provided
+        option: String,
+        /// The option it conflicts with
+        other: String,
+    },
+
+    /// Option value is not one of the accepted choices
+    #[error("invalid value '{value}' for '{option}', expected one of: {expected}")]
+    InvalidValue {
+        /// The option that received the value
+        option: String,
+        /// The rejected value
+        value: String,
+        /// Human-readable list of accepted values
+        expected: &'static str,
+    },
+
+    /// Category list names a category that does not exist
+    #[error(
+        "unknown category '{name}' for '{option}', expected one of: {}",
+        category_keys()
+    )]
+    UnknownCategory {
+        /// The option that received the list
+        option: String,
+        /// The unrecognized entry
+        name: String,
+    },
+
+    /// Glob pattern could not be parsed
+    #[error("invalid glob pattern '{pattern}': {source}")]
+    InvalidGlob {
+        /// The pattern as given on the command line
+        pattern: String,
+        /// The underlying pattern error
+        #[source]
+        source: glob::PatternError,
+    },
+
+    /// Glob pattern matched no files and no file has that literal name
+    #[error("no files matched pattern '{0}'")]
+    NoMatchingFiles(String),
+
+    /// File list given to `--files-from` could not be read
+    #[error("cannot read file list '{path}': {source}")]
+    FileList {
+        /// The list path as given on the command line
+        path: String,
+        /// The underlying I/O error
+        #[source]
+        source: io::Error,
+    },
+
+    /// Response file given as `@FILE` could not be read
+    #[error("cannot read response file '{path}': {source}")]
+    ResponseFile {
+        /// The response file path, without its leading `@`
+        path: String,
+        /// The underlying I/O error
+        #[source]
+        source: io::Error,
+    },
+
+    /// Response file names another response file
+    #[error("response file '{path}' cannot include another response file '{nested}'")]
+    NestedResponseFile {
+        /// The response file containing the reference
+        path: String,
+        /// The nested reference, including its leading `@`
+        nested: String,
+    },
+
+    /// Option that applies to one input was given several
+    #[error("option '{0}' requires a single input")]
+    SingleInputRequired(String),
+
+    /// Directory given with `-r` could not be walked
+    #[error("cannot read directory '{}': {source}", path.display())]
+    ReadDir {
+        /// The directory that could not be read
+        path: PathBuf,
+        /// The underlying I/O error
+        #[source]
+        source: io::Error,
+    },
+
     /// Invalid UTF-8 in argument value
     #[error("invalid UTF-8 in argument: {}", .0.to_string_lossy())]
     InvalidUtf8Value(OsString),
@@ -71,26 +186,452 @@ impl From<lexopt::Error> for CliError {
     }
 }
 
+/// When to style text output with ANSI colors
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color only when stdout is a terminal and `NO_COLOR` is unset
+    #[default]
+    Auto,
+    /// Always emit color codes
+    Always,
+    /// Never emit color codes
+    Never,
+}
+
+impl ColorChoice {
+    /// Accepted values, for error messages
+    const EXPECTED: &'static str = "auto, always, never";
+
+    /// Parse a `--color` value
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "auto" => Some(Self::Auto),
+            "always" => Some(Self::Always),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+
+    /// Resolve whether to emit color codes on stdout
+    pub fn enabled(self) -> bool {
+        match self {
+            Self::Auto => {
+                env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+                    && io::stdout().is_terminal()
+            }
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+/// Subject of an `--explain` request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplainTopic {
+    /// A rule, named by its configuration key or category name
+    Category(CharCategory),
+    /// A specific character, given literally or as `U+XXXX`
+    Char(char),
+}
+
+impl ExplainTopic {
+    /// Accepted values, for error messages
+    fn expected() -> &'static str {
+        static EXPECTED: LazyLock<String> =
+            LazyLock::new(|| format!("{}, or a single character", category_keys()));
+        &EXPECTED
+    }
+
+    /// Parse an `--explain` value
+    fn parse(value: &str) -> Option<Self> {
+        match CharCategory::parse(value) {
+            Some(category) => Some(Self::Category(category)),
+            None => parse_char(value).ok().map(Self::Char),
+        }
+    }
+}
+
+/// How reported file paths are written
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum PathStyle {
+    /// As given on the command line
+    #[default]
+    AsGiven,
+    /// Relative to a root directory, from `--relative`, or absolute for
+    /// paths outside it
+    Relative(PathBuf),
+    /// Absolute, from `--absolute`
+    Absolute,
+}
+
+/// Order in which violations are reported
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Report violations as inputs are checked, in input order
+    #[default]
+    None,
+    /// Sort by input name, then line and column
+    Path,
+    /// Sort by category severity, emoji first, then by position
+    Severity,
+}
+
+impl SortOrder {
+    /// Accepted values, for error messages
+    const EXPECTED: &'static str = "path, severity, none";
+
+    /// Parse a `--sort` value
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "path" => Some(Self::Path),
+            "severity" => Some(Self::Severity),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+
+/// Options controlling how inputs are processed and reported
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Options {
+    /// Where configuration is loaded from
+    pub config: ConfigSource,
+    /// How violations are reported, overriding configuration if set
+    pub format: Option<OutputFormat>,
+    /// When to color text output
+    pub color: ColorChoice,
+    /// Inputs whose path matches any of these patterns are skipped
+    pub exclude: Vec<glob::Pattern>,
+    /// Suppress violation output, reporting only through the exit code
+    pub quiet: bool,
+    /// Log inputs that cannot be opened or read at debug level instead of
+    /// printing them, still exiting with an error
+    pub quiet_errors: bool,
+    /// Print a summary of violation counts to stderr after processing
+    pub summary: bool,
+    /// Print scan totals, time, and throughput to stderr after processing
+    pub profile: bool,
+    /// Include additional detail, such as per-category summary counts
+    pub verbose: bool,
+    /// Print text violations under a header per input instead of one
+    /// `name:line:col` line each
+    pub group: bool,
+    /// Also print a line for each input checked without violations
+    pub show_clean: bool,
+    /// Prefix each text violation with its input name, as chosen by
+    /// `--with-filename` and `--no-filename` or by the number of inputs
+    pub with_filename: bool,
+    /// Order in which violations are reported
+    pub sort: SortOrder,
+    /// How reported file paths are written
+    pub path_style: PathStyle,
+    /// Report file locations as `file://` URLs, from `--link`
+    pub link: bool,
+    /// Check only these categories, from `--only`
+    pub only: Option<Vec<CharCategory>>,
+    /// Categories disabled by `--ignore`
+    pub ignore: Vec<CharCategory>,
+    /// Print only the number of violations, per input under `--group`
+    pub count: bool,
+    /// Print a table of violation counts per character instead of violations
+    pub stats: bool,
+    /// Report a run of the same character on one line as a single violation
+    pub dedupe: bool,
+    /// End each reported record with a NUL byte instead of a newline
+    pub null: bool,
+    /// Keep running after the first pass, rechecking inputs as they change
+    pub watch: bool,
+    /// Check the files within directory inputs, recursively
+    pub recursive: bool,
+    /// Follow symbolic links found while walking directories under `-r`
+    pub follow_symlinks: bool,
+    /// Report only violations on these 1-based lines, from `--lines`
+    pub lines: Option<RangeInclusive<usize>>,
+    /// Unit for reported columns, overriding configuration if set
+    pub column: Option<ColumnMode>,
+    /// Tab stop width for reported columns, if set
+    pub tab_width: Option<usize>,
+    /// Language whose string literals and comments are left unchecked
+    pub lang: Option<Language>,
+    /// Report inputs whose last line does not end in a newline
+    pub require_final_newline: bool,
+    /// Report a leading UTF-8 byte order mark
+    pub check_bom: bool,
+    /// Report letters that look like ASCII mixed into ASCII text
+    pub check_confusables: bool,
+    /// Report every non-ASCII character, ignoring the usual exceptions
+    pub ascii_only: bool,
+    /// Stop at the first violation instead of processing every input
+    pub fail_fast: bool,
+    /// Stop at the first input that cannot be opened or read instead of
+    /// reporting it and moving on
+    pub stop_on_error: bool,
+    /// Exit successfully even when violations are found
+    pub exit_zero: bool,
+    /// Fail on warning-severity violations as well as errors
+    pub warnings_as_errors: bool,
+    /// Fail when there is nothing to check, rather than only warning
+    pub error_on_empty: bool,
+    /// Run as a pre-commit hook, from `--hook`: check only the files named,
+    /// always name them, and exit 1 on violations whatever the configuration
+    pub hook: bool,
+    /// Baseline file of known violations, which are not reported
+    pub baseline: Option<PathBuf>,
+    /// Record every violation found into the baseline file instead of checking
+    pub write_baseline: bool,
+    /// Drop baseline entries that no longer occur after checking
+    pub prune_baseline: bool,
+    /// Git revision range; only lines it adds are reported
+    pub diff: Option<String>,
+    /// Check the tracked files with uncommitted changes instead of the inputs
+    /// given, from `--changed-only`
+    pub changed_only: bool,
+    /// Maximum number of violations to print across the whole run
+    pub max_violations: Option<usize>,
+    /// Maximum number of violations to print for each input
+    pub max_violations_per_file: Option<usize>,
+    /// Name to report for stdin in place of `stdin`
+    pub stdin_filename: Option<String>,
+    /// Scan inputs that look binary instead of skipping them
+    pub no_binary_skip: bool,
+    /// Share of non-text bytes above which an input looks binary (None = use
+    /// configuration)
+    pub binary_threshold: Option<BinaryThreshold>,
+    /// Normalization form text must be in (None = use configuration)
+    pub normalize: Option<NormalizationForm>,
+}
+
+impl Options {
+    /// Returns true if `input` matches an `--exclude` pattern
+    ///
+    /// Stdin is never excluded.
+    pub fn is_excluded(&self, input: &InputSource) -> bool {
+        match input {
+            InputSource::File(path) => self.exclude.iter().any(|p| p.matches_path(path)),
+            InputSource::Stdin => false,
+        }
+    }
+
+    /// Returns the name to report for `input`, honouring `--stdin-filename`
+    pub fn display_name<'a>(&'a self, input: &InputSource) -> Cow<'a, str> {
+        match (input, &self.stdin_filename) {
+            (InputSource::Stdin, Some(name)) => Cow::Borrowed(name),
+            _ => input.name(),
+        }
+    }
+
+    /// Returns the name to print for `input`, as for [`Options::display_name`]
+    /// but with file paths rewritten by `--relative` or `--absolute`, or
+    /// written as `file://` URLs under `--link`
+    ///
+    /// Diff and baseline entries are matched against the display name, so
+    /// that they do not depend on how paths are printed.
+    pub fn report_name<'a>(&'a self, input: &InputSource) -> Cow<'a, str> {
+        let InputSource::File(path) = input else {
+            return self.display_name(input);
+        };
+        if self.link {
+            return Cow::Owned(file_url(&absolute(path)));
+        }
+        let path = match &self.path_style {
+            PathStyle::AsGiven => return self.display_name(input),
+            PathStyle::Absolute => absolute(path),
+            PathStyle::Relative(root) => {
+                let path = absolute(path);
+                match path.strip_prefix(absolute(root)) {
+                    Ok(relative) if relative.as_os_str().is_empty() => path,
+                    Ok(relative) => relative.to_path_buf(),
+                    Err(_) => path,
+                }
+            }
+        };
+        Cow::Owned(path.display().to_string())
+    }
+
+    /// Returns the character ending each reported record: NUL under `--null`,
+    /// otherwise a newline
+    pub fn terminator(&self) -> char {
+        if self.null { '\0' } else { '\n' }
+    }
+
+    /// Apply command-line overrides to a checker built from configuration
+    pub fn apply(&self, mut checker: Checker) -> Checker {
+        if let Some(mode) = self.column {
+            checker = checker.with_column_mode(mode);
+        }
+        if let Some(width) = self.tab_width {
+            checker = checker.with_tab_width(width);
+        }
+        if let Some(language) = self.lang {
+            checker = checker.with_language(language);
+        }
+        if self.require_final_newline {
+            checker = checker.with_final_newline(true);
+        }
+        if self.check_bom {
+            checker = checker.with_byte_order_mark(true);
+        }
+        if self.check_confusables {
+            checker = checker.with_confusables(true);
+        }
+        if self.ascii_only {
+            checker = checker.with_non_ascii(true);
+        }
+        if let Some(form) = self.normalize {
+            checker = checker.with_normalization(form);
+        }
+        if self.no_binary_skip {
+            checker = checker.with_binary_skip(false);
+        }
+        if let Some(threshold) = self.binary_threshold {
+            checker = checker.with_binary_threshold(threshold);
+        }
+        if let Some(lines) = &self.lines {
+            checker = checker.with_lines(lines.clone());
+        }
+        if let Some(only) = &self.only {
+            for &category in CharCategory::ALL {
+                checker = checker.with_category(category, only.contains(&category));
+            }
+        }
+        for &category in &self.ignore {
+            checker = checker.with_category(category, false);
+        }
+        checker
+    }
+}
+
 /// CLI command structure
 #[derive(Debug, PartialEq, Clone)]
 pub enum CliCommand {
     /// Show help information
     Help,
     /// Show version information
-    Version,
+    Version {
+        /// Include the commit, build date, and compiler
+        verbose: bool,
+    },
+    /// Explain a rule, or why a character is prohibited
+    Explain(ExplainTopic),
+    /// Validate configuration files and print the effective configuration
+    CheckConfig(ConfigSource),
+    /// Write a commented `.noemoji.toml` into the current directory
+    Init {
+        /// Overwrite an existing file
+        force: bool,
+    },
     /// Process inputs for Unicode compliance checking
     Check {
         /// Input sources to check, in order of processing
         inputs: Vec<InputSource>,
+        /// Processing and reporting options
+        options: Options,
+    },
+    /// Replace prohibited characters with ASCII equivalents
+    Fix {
+        /// Input sources to fix, in order of processing
+        inputs: Vec<InputSource>,
+        /// Print a diff of proposed changes instead of writing them
+        dry_run: bool,
+        /// Processing and reporting options
+        options: Options,
     },
 }
 
+impl CliCommand {
+    /// Replace directory inputs with the files beneath them under `-r`
+    ///
+    /// This is separate from [`parse_args`] so that it runs once logging is
+    /// set up, since skipped entries are logged.
+    pub fn expand_directories(self) -> Result<Self, CliError> {
+        // A directory is a single input until it is walked
+        let expand = |inputs, options: &Options| -> Result<Vec<InputSource>, CliError> {
+            let inputs = expand_directories(inputs, options.follow_symlinks)?;
+            check_single_input(&inputs, options)?;
+            Ok(inputs)
+        };
+        match self {
+            CliCommand::Check { inputs, options } if options.recursive => Ok(CliCommand::Check {
+                inputs: expand(inputs, &options)?,
+                options,
+            }),
+            CliCommand::Fix {
+                inputs,
+                dry_run,
+                options,
+            } if options.recursive => Ok(CliCommand::Fix {
+                inputs: expand(inputs, &options)?,
+                dry_run,
+                options,
+            }),
+            command => Ok(command),
+        }
+    }
+}
+
+/// Reject options that apply to one input when given several
+fn check_single_input(inputs: &[InputSource], options: &Options) -> Result<(), CliError> {
+    if options.lines.is_some() && inputs.len() > 1 {
+        return Err(CliError::SingleInputRequired("--lines".to_owned()));
+    }
+    Ok(())
+}
+
+/// Expand `@FILE` arguments into the arguments listed in FILE, one per line
+///
+/// Response files work around command line length limits when passing many
+/// files. Each non-empty line becomes a single argument taken literally, so
+/// paths may contain spaces; a trailing carriage return is dropped. A
+/// response file may not name another, which rules out loops. Arguments
+/// after `--` and a bare `@` are passed through unchanged.
+pub fn expand_response_files(args: &[String]) -> Result<Vec<String>, CliError> {
+    let mut expanded = Vec::with_capacity(args.len());
+    let mut options_done = false;
+
+    for arg in args {
+        let path = match arg.strip_prefix('@') {
+            Some(path) if !options_done && !path.is_empty() => path,
+            _ => {
+                options_done |= arg == "--";
+                expanded.push(arg.clone());
+                continue;
+            }
+        };
+        let contents = fs::read_to_string(path).map_err(|source| CliError::ResponseFile {
+            path: path.to_owned(),
+            source,
+        })?;
+        for line in contents.lines() {
+            if line.is_empty() {
+                continue;
+            }
+            if line.len() > 1 && line.starts_with('@') {
+                return Err(CliError::NestedResponseFile {
+                    path: path.to_owned(),
+                    nested: line.to_owned(),
+                });
+            }
+            expanded.push(line.to_owned());
+        }
+    }
+    Ok(expanded)
+}
+
 /// Parse command line arguments using lexopt
 pub fn parse_args(args: &[String]) -> Result<CliCommand, CliError> {
     use lexopt::prelude::*;
 
     let mut parser = lexopt::Parser::from_args(args.iter().map(|s| s.as_str()));
     let mut inputs = Vec::with_capacity(args.len());
+    let mut fix = false;
+    let mut dry_run = false;
+    let mut options = Options::default();
+    let mut has_file_list = false;
+    let mut check_config = false;
+    let mut version = false;
+    let mut init = false;
+    let mut force = false;
+    let mut with_filename = None;
 
     loop {
         let arg = match parser.next() {
@@ -100,30 +641,718 @@ pub fn parse_args(args: &[String]) -> Result<CliCommand, CliError> {
         };
         match arg {
             Short('h') | Long("help") => return Ok(CliCommand::Help),
-            Short('V') | Long("version") => return Ok(CliCommand::Version),
+            Short('V') | Long("version") => version = true,
+            Long("check-config") => check_config = true,
+            Long("init") => init = true,
+            Long("force") => force = true,
+            Long("config") => {
+                let path = PathBuf::from(parser.value()?);
+                set_config_source(&mut options.config, ConfigSource::File(path))?;
+            }
+            Long("no-config") => set_config_source(&mut options.config, ConfigSource::Defaults)?,
+            Long("explain") => {
+                let value = string_value(&mut parser)?;
+                let topic = ExplainTopic::parse(&value).ok_or_else(|| CliError::InvalidValue {
+                    option: "--explain".to_owned(),
+                    value,
+                    expected: ExplainTopic::expected(),
+                })?;
+                return Ok(CliCommand::Explain(topic));
+            }
+            Long("fix") => fix = true,
+            Long("dry-run") => dry_run = true,
+            Short('q') | Long("quiet") => options.quiet = true,
+            Long("quiet-errors") => options.quiet_errors = true,
+            Long("summary") => options.summary = true,
+            Long("profile") => options.profile = true,
+            Long("group") => options.group = true,
+            Long("show-clean") => options.show_clean = true,
+            Short('H') | Long("with-filename") => with_filename = Some(true),
+            Long("no-filename") => with_filename = Some(false),
+            Long("count") => options.count = true,
+            Long("stats") => options.stats = true,
+            Long("dedupe") => options.dedupe = true,
+            Long("link") => options.link = true,
+            Short('0') | Long("null") => options.null = true,
+            Short('w') | Long("watch") => options.watch = true,
+            Short('r') | Long("recursive") => options.recursive = true,
+            Long("follow-symlinks") => options.follow_symlinks = true,
+            Long("no-follow-symlinks") => options.follow_symlinks = false,
+            Long("fail-fast") => options.fail_fast = true,
+            Long("keep-going") => options.stop_on_error = false,
+            Long("no-keep-going") => options.stop_on_error = true,
+            Long("exit-zero") => options.exit_zero = true,
+            Long("warnings-as-errors") => options.warnings_as_errors = true,
+            Long("error-on-empty") => options.error_on_empty = true,
+            Long("hook") => options.hook = true,
+            Long("baseline") => options.baseline = Some(PathBuf::from(parser.value()?)),
+            Long("write-baseline") => options.write_baseline = true,
+            Long("prune-baseline") => options.prune_baseline = true,
+            Long("diff") => options.diff = Some(string_value(&mut parser)?),
+            Long("changed-only") => options.changed_only = true,
+            Long("no-binary-skip") => options.no_binary_skip = true,
+            Long("require-final-newline") => options.require_final_newline = true,
+            Long("check-bom") => options.check_bom = true,
+            Long("check-confusables") => options.check_confusables = true,
+            Long("ascii-only") => options.ascii_only = true,
+            Short('v') | Long("verbose") => options.verbose = true,
+            Long("format") => {
+                let value = string_value(&mut parser)?;
+                options.format =
+                    Some(
+                        OutputFormat::parse(&value).ok_or_else(|| CliError::InvalidValue {
+                            option: "--format".to_owned(),
+                            value,
+                            expected: OutputFormat::EXPECTED,
+                        })?,
+                    );
+            }
+            Long("color") => {
+                let value = string_value(&mut parser)?;
+                options.color =
+                    ColorChoice::parse(&value).ok_or_else(|| CliError::InvalidValue {
+                        option: "--color".to_owned(),
+                        value,
+                        expected: ColorChoice::EXPECTED,
+                    })?;
+            }
+            Long("only") => {
+                let list = category_list("--only", &string_value(&mut parser)?)?;
+                options.only.get_or_insert_default().extend(list);
+            }
+            Long("ignore") => {
+                let list = category_list("--ignore", &string_value(&mut parser)?)?;
+                options.ignore.extend(list);
+            }
+            Long("relative") => {
+                let root = PathBuf::from(string_value(&mut parser)?);
+                if options.path_style == PathStyle::Absolute {
+                    return Err(CliError::ConflictingOptions {
+                        option: "--relative".to_owned(),
+                        other: "--absolute".to_owned(),
+                    });
+                }
+                options.path_style = PathStyle::Relative(root);
+            }
+            Long("absolute") => {
+                if let PathStyle::Relative(_) = options.path_style {
+                    return Err(CliError::ConflictingOptions {
+                        option: "--absolute".to_owned(),
+                        other: "--relative".to_owned(),
+                    });
+                }
+                options.path_style = PathStyle::Absolute;
+            }
+            Long("sort") => {
+                let value = string_value(&mut parser)?;
+                options.sort = SortOrder::parse(&value).ok_or_else(|| CliError::InvalidValue {
+                    option: "--sort".to_owned(),
+                    value,
+                    expected: SortOrder::EXPECTED,
+                })?;
+            }
+            Long("lines") => {
+                let value = string_value(&mut parser)?;
+                options.lines =
+                    Some(
+                        parse_line_range(&value).ok_or_else(|| CliError::InvalidValue {
+                            option: "--lines".to_owned(),
+                            value,
+                            expected: "START:END, with 1 <= START <= END",
+                        })?,
+                    );
+            }
+            Long("column") => {
+                let value = string_value(&mut parser)?;
+                options.column = Some(value.parse().map_err(|_| CliError::InvalidValue {
+                    option: "--column".to_owned(),
+                    value,
+                    expected: ColumnMode::EXPECTED,
+                })?);
+            }
+            Long("normalize") => {
+                let value = string_value(&mut parser)?;
+                options.normalize = Some(value.parse().map_err(|_| CliError::InvalidValue {
+                    option: "--normalize".to_owned(),
+                    value,
+                    expected: NormalizationForm::EXPECTED,
+                })?);
+            }
+            Long("binary-threshold") => {
+                let value = string_value(&mut parser)?;
+                options.binary_threshold =
+                    Some(value.parse().map_err(|_| CliError::InvalidValue {
+                        option: "--binary-threshold".to_owned(),
+                        value,
+                        expected: BinaryThreshold::EXPECTED,
+                    })?);
+            }
+            Long("lang") => {
+                let value = string_value(&mut parser)?;
+                options.lang = Some(value.parse().map_err(|_| CliError::InvalidValue {
+                    option: "--lang".to_owned(),
+                    value,
+                    expected: Language::EXPECTED,
+                })?);
+            }
+            Long("tab-width") => {
+                options.tab_width = Some(positive_value(&mut parser, "--tab-width")?);
+            }
+            Long("jobs") => {
+                // Inputs are checked one at a time; see the help text
+                let value = string_value(&mut parser)?;
+                if value != "1" {
+                    return Err(CliError::InvalidValue {
+                        option: "--jobs".to_owned(),
+                        value,
+                        expected: "1",
+                    });
+                }
+            }
+            Long("max-violations") => {
+                options.max_violations = Some(positive_value(&mut parser, "--max-violations")?);
+            }
+            Long("max-violations-per-file") => {
+                options.max_violations_per_file =
+                    Some(positive_value(&mut parser, "--max-violations-per-file")?);
+            }
+            Long("stdin-filename") => {
+                options.stdin_filename = Some(string_value(&mut parser)?);
+            }
+            Long("files-from") => {
+                let path = string_value(&mut parser)?;
+                inputs.extend(read_file_list(&path, b'\n')?);
+                has_file_list = true;
+            }
+            Long("files-from0") => {
+                let path = string_value(&mut parser)?;
+                inputs.extend(read_file_list(&path, b'\0')?);
+                has_file_list = true;
+            }
+            Long("exclude") => {
+                let pattern = string_value(&mut parser)?;
+                let compiled =
+                    glob::Pattern::new(&pattern).map_err(|source| CliError::InvalidGlob {
+                        pattern: pattern.clone(),
+                        source,
+                    })?;
+                options.exclude.push(compiled);
+            }
             Value(val) => {
                 if val == "-" {
                     // Explicit stdin request, preserving position in input list
                     inputs.push(InputSource::Stdin);
                 } else {
-                    inputs.push(InputSource::File(PathBuf::from(val)));
+                    inputs.extend(expand_glob(PathBuf::from(val))?);
                 }
             }
             _ => return Err(arg.unexpected().into()),
         }
     }
 
-    // If no inputs specified, default to reading from stdin
-    if inputs.is_empty() {
+    if version {
+        return Ok(CliCommand::Version {
+            verbose: options.verbose,
+        });
+    }
+    if force && !init {
+        return Err(CliError::MissingRequiredOption {
+            option: "--force".to_owned(),
+            required: "--init".to_owned(),
+        });
+    }
+    if init {
+        return Ok(CliCommand::Init { force });
+    }
+
+    // --changed-only asks git which files to check, whatever was named
+    if options.changed_only {
+        inputs.clear();
+    }
+
+    // If no inputs specified, default to reading from stdin. An empty file list
+    // means there is nothing to check, not that content follows on stdin, and
+    // --diff and --changed-only check the files git reports instead. A hook is
+    // passed the files to check, and none means nothing was staged that
+    // matches.
+    if inputs.is_empty()
+        && !has_file_list
+        && options.diff.is_none()
+        && !options.changed_only
+        && !options.hook
+    {
         inputs.push(InputSource::Stdin);
     }
 
-    Ok(CliCommand::Check { inputs })
+    // Like grep, name the input only when there may be several; inputs from
+    // --diff are not known yet. Naming stdin asks for the name to be shown.
+    options.with_filename = with_filename.unwrap_or(
+        inputs.len() != 1 || options.recursive || options.stdin_filename.is_some() || options.hook,
+    );
+
+    if check_config {
+        return Ok(CliCommand::CheckConfig(options.config));
+    }
+
+    for (flag, set) in [
+        ("--write-baseline", options.write_baseline),
+        ("--prune-baseline", options.prune_baseline),
+    ] {
+        if set && options.baseline.is_none() {
+            return Err(CliError::MissingRequiredOption {
+                option: flag.to_owned(),
+                required: "--baseline".to_owned(),
+            });
+        }
+    }
+    for (flag, set) in [
+        ("--count", options.count),
+        ("--stats", options.stats),
+        ("--dedupe", options.dedupe),
+        ("--null", options.null),
+    ] {
+        if let Some(format @ (OutputFormat::Json | OutputFormat::Csv)) = options.format
+            && set
+        {
+            return Err(CliError::ConflictingOptions {
+                option: flag.to_owned(),
+                other: format!("--format {}", format.name()),
+            });
+        }
+    }
+
+    // Links are printed only by the editor format, which --link implies.
+    // A link must be absolute, so it cannot honour --relative.
+    if options.link {
+        match options.format {
+            None => options.format = Some(OutputFormat::Editor),
+            Some(OutputFormat::Editor) => {}
+            Some(format) => {
+                return Err(CliError::ConflictingOptions {
+                    option: "--link".to_owned(),
+                    other: format!("--format {}", format.name()),
+                });
+            }
+        }
+        if let PathStyle::Relative(_) = options.path_style {
+            return Err(CliError::ConflictingOptions {
+                option: "--link".to_owned(),
+                other: "--relative".to_owned(),
+            });
+        }
+    }
+
+    // Clean inputs are reported as they are checked, which has no place in
+    // a sorted report
+    if options.show_clean && options.sort != SortOrder::None {
+        return Err(CliError::ConflictingOptions {
+            option: "--show-clean".to_owned(),
+            other: "--sort".to_owned(),
+        });
+    }
+
+    check_single_input(&inputs, &options)?;
+
+    // A recheck sees only the changed inputs, which would shrink the baseline
+    for (flag, set) in [
+        ("--write-baseline", options.write_baseline),
+        ("--prune-baseline", options.prune_baseline),
+    ] {
+        if set && options.watch {
+            return Err(CliError::ConflictingOptions {
+                option: flag.to_owned(),
+                other: "--watch".to_owned(),
+            });
+        }
+    }
+    // The changed files are listed once, so a watch would miss new changes,
+    // and they are too few to write a baseline from
+    for (flag, set) in [
+        ("--diff", options.diff.is_some()),
+        ("--watch", options.watch),
+        ("--write-baseline", options.write_baseline),
+    ] {
+        if set && options.changed_only {
+            return Err(CliError::ConflictingOptions {
+                option: "--changed-only".to_owned(),
+                other: flag.to_owned(),
+            });
+        }
+    }
+    if options.hook && options.exit_zero {
+        return Err(CliError::ConflictingOptions {
+            option: "--exit-zero".to_owned(),
+            other: "--hook".to_owned(),
+        });
+    }
+    if options.stats && options.count {
+        return Err(CliError::ConflictingOptions {
+            option: "--stats".to_owned(),
+            other: "--count".to_owned(),
+        });
+    }
+    if options.null && options.group && !options.count {
+        return Err(CliError::ConflictingOptions {
+            option: "--null".to_owned(),
+            other: "--group".to_owned(),
+        });
+    }
+    for (flag, set) in [
+        ("--baseline", options.baseline.is_some()),
+        ("--diff", options.diff.is_some()),
+        ("--changed-only", options.changed_only),
+        ("--count", options.count),
+        ("--stats", options.stats),
+        ("--show-clean", options.show_clean),
+        ("--dedupe", options.dedupe),
+        ("--link", options.link),
+        ("--profile", options.profile),
+        ("--watch", options.watch),
+        ("--lines", options.lines.is_some()),
+    ] {
+        if fix && set {
+            return Err(CliError::ConflictingOptions {
+                option: flag.to_owned(),
+                other: "--fix".to_owned(),
+            });
+        }
+    }
+
+    if dry_run && !fix {
+        return Err(CliError::MissingRequiredOption {
+            option: "--dry-run".to_owned(),
+            required: "--fix".to_owned(),
+        });
+    }
+
+    if fix {
+        Ok(CliCommand::Fix {
+            inputs,
+            dry_run,
+            options,
+        })
+    } else {
+        Ok(CliCommand::Check { inputs, options })
+    }
+}
+
+/// Record a `--config` or `--no-config` choice
+///
+/// The last `--config` wins, but `--config` and `--no-config` conflict.
+fn set_config_source(current: &mut ConfigSource, source: ConfigSource) -> Result<(), CliError> {
+    let name = |source: &ConfigSource| match source {
+        ConfigSource::File(_) => "--config",
+        _ => "--no-config",
+    };
+    if *current != ConfigSource::Discover && name(current) != name(&source) {
+        return Err(CliError::ConflictingOptions {
+            option: name(&source).to_owned(),
+            other: name(current).to_owned(),
+        });
+    }
+    *current = source;
+    Ok(())
+}
+
+/// Fetch the value of the current option as a UTF-8 string
+fn string_value(parser: &mut lexopt::Parser) -> Result<String, CliError> {
+    parser
+        .value()?
+        .into_string()
+        .map_err(CliError::InvalidUtf8Value)
+}
+
+/// Parse an inclusive `START:END` line range, rejecting line 0 and ranges
+/// that end before they start
+fn parse_line_range(value: &str) -> Option<RangeInclusive<usize>> {
+    let (start, end) = value.split_once(':')?;
+    let (start, end): (usize, usize) = (start.parse().ok()?, end.parse().ok()?);
+    (start >= 1 && start <= end).then_some(start..=end)
+}
+
+/// Fetch the value of the current option as a positive integer
+fn positive_value(parser: &mut lexopt::Parser, option: &str) -> Result<usize, CliError> {
+    let value = string_value(parser)?;
+    value
+        .parse()
+        .ok()
+        .filter(|&n| n > 0)
+        .ok_or_else(|| CliError::InvalidValue {
+            option: option.to_owned(),
+            value,
+            expected: "a positive integer",
+        })
+}
+
+/// Parse a comma-separated list of category keys or names for `option`
+fn category_list(option: &str, value: &str) -> Result<Vec<CharCategory>, CliError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            CharCategory::parse(name).ok_or_else(|| CliError::UnknownCategory {
+                option: option.to_owned(),
+                name: name.to_owned(),
+            })
+        })
+        .collect()
 }
 
-/// Print version information
-pub fn print_version() {
+/// Returns the configuration keys of every category, for error messages
+fn category_keys() -> String {
+    CharCategory::ALL
+        .iter()
+        .map(|category| category.key())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Read the paths listed in `path` (or stdin for `-`), separated by `delimiter`
+///
+/// Empty entries are skipped, and a trailing carriage return is dropped from
+/// newline-separated entries. Listed paths are taken literally, not as globs.
+fn read_file_list(path: &str, delimiter: u8) -> Result<Vec<InputSource>, CliError> {
+    let read = || -> io::Result<String> {
+        let mut list = String::new();
+        if path == "-" {
+            io::stdin().lock().read_to_string(&mut list)?;
+        } else {
+            list = fs::read_to_string(path)?;
+        }
+        Ok(list)
+    };
+    let list = read().map_err(|source| CliError::FileList {
+        path: path.to_owned(),
+        source,
+    })?;
+
+    Ok(list
+        .split(char::from(delimiter))
+        .map(|entry| {
+            if delimiter == b'\n' {
+                entry.strip_suffix('\r').unwrap_or(entry)
+            } else {
+                entry
+            }
+        })
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| InputSource::File(PathBuf::from(entry)))
+        .collect())
+}
+
+/// Returns `path` made absolute against the current directory, with `.` and
+/// `..` components resolved lexically
+fn absolute(path: &Path) -> PathBuf {
+    let path = path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let mut resolved = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            other => resolved.push(other),
+        }
+    }
+    resolved
+}
+
+/// Returns a `file://` URL for the absolute `path`, percent-encoding bytes
+/// that may not appear in a URL path
+fn file_url(path: &Path) -> String {
+    let path = path.to_string_lossy().replace(path::MAIN_SEPARATOR, "/");
+    let mut url = String::from("file://");
+    // Windows paths start with a drive letter rather than a separator
+    if !path.starts_with('/') {
+        url.push('/');
+    }
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                url.push(char::from(byte));
+            }
+            _ => url.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    url
+}
+
+/// Returns true if `arg` contains glob metacharacters
+fn is_glob(arg: &str) -> bool {
+    arg.contains(['*', '?', '['])
+}
+
+/// Expand a positional argument containing glob metacharacters
+///
+/// Shells on Unix expand globs before we see them, but on Windows and in some
+/// CI environments patterns like `src/*.rs` arrive literally. Arguments without
+/// metacharacters, or that name an existing file literally, are returned
+/// unchanged. Matches are returned in sorted order.
+fn expand_glob(path: PathBuf) -> Result<Vec<InputSource>, CliError> {
+    let Some(pattern) = path.to_str().filter(|p| is_glob(p)) else {
+        return Ok(vec![InputSource::File(path)]);
+    };
+
+    let paths = glob::glob(pattern).map_err(|source| CliError::InvalidGlob {
+        pattern: pattern.to_owned(),
+        source,
+    })?;
+    let mut matches: Vec<InputSource> = paths
+        .filter_map(Result::ok)
+        .filter(|p| !p.is_dir())
+        .map(InputSource::File)
+        .collect();
+
+    if matches.is_empty() {
+        if path.exists() {
+            matches.push(InputSource::File(path));
+        } else {
+            return Err(CliError::NoMatchingFiles(pattern.to_owned()));
+        }
+    }
+    Ok(matches)
+}
+
+/// Replace each directory in `inputs` with the files beneath it
+///
+/// Entries are visited in name order. Like `grep -r`, symbolic links found
+/// while walking are skipped unless `follow_symlinks` is set, though a
+/// directory named on the command line is walked even if it is a link.
+fn expand_directories(
+    inputs: Vec<InputSource>,
+    follow_symlinks: bool,
+) -> Result<Vec<InputSource>, CliError> {
+    let mut walk = Walk {
+        follow_symlinks,
+        visited: HashSet::new(),
+        files: Vec::with_capacity(inputs.len()),
+    };
+    for input in inputs {
+        match input {
+            InputSource::File(path) if path.is_dir() => {
+                if walk.first_visit(&path) {
+                    walk.directory(&path)?;
+                }
+            }
+            input => walk.files.push(input),
+        }
+    }
+    Ok(walk.files)
+}
+
+/// State of a directory walk for `-r`
+struct Walk {
+    /// Follow symbolic links to files and directories
+    follow_symlinks: bool,
+    /// Canonical paths of the directories and files reached so far when
+    /// following links, so that a link back into the tree is neither walked
+    /// forever nor checked twice
+    visited: HashSet<PathBuf>,
+    /// Files found so far, in walk order
+    files: Vec<InputSource>,
+}
+
+impl Walk {
+    /// Append the files beneath `dir`, recursively
+    fn directory(&mut self, dir: &Path) -> Result<(), CliError> {
+        let read_dir_error = |source| CliError::ReadDir {
+            path: dir.to_path_buf(),
+            source,
+        };
+        let mut entries = fs::read_dir(dir)
+            .and_then(|entries| entries.collect::<io::Result<Vec<_>>>())
+            .map_err(read_dir_error)?;
+        entries.sort_by_key(|entry| entry.file_name());
+
+        for entry in entries {
+            let path = entry.path();
+            let mut file_type = entry.file_type().map_err(read_dir_error)?;
+            if file_type.is_symlink() {
+                if !self.follow_symlinks {
+                    log::debug!("skipping symbolic link {}", path.display());
+                    continue;
+                }
+                match fs::metadata(&path) {
+                    Ok(metadata) => file_type = metadata.file_type(),
+                    Err(err) => {
+                        log::debug!("skipping broken symbolic link {}: {}", path.display(), err);
+                        continue;
+                    }
+                }
+            }
+            if !(file_type.is_dir() || file_type.is_file()) || !self.first_visit(&path) {
+                continue;
+            }
+            if file_type.is_dir() {
+                self.directory(&path)?;
+            } else {
+                self.files.push(InputSource::File(path));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns true unless `path` was already reached through another link
+    ///
+    /// Without links to follow, every path is reached only once.
+    fn first_visit(&mut self, path: &Path) -> bool {
+        if !self.follow_symlinks {
+            return true;
+        }
+        match fs::canonicalize(path) {
+            Ok(canonical) => self.visited.insert(canonical),
+            Err(_) => true,
+        }
+    }
+}
+
+/// Print version information, with build metadata if `verbose`
+pub fn print_version(verbose: bool) {
     println!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+    if verbose {
+        println!("commit: {}", env!("NOEMOJI_COMMIT"));
+        println!("build date: {}", env!("NOEMOJI_BUILD_DATE"));
+        println!("rustc: {}", env!("NOEMOJI_RUSTC"));
+    }
+}
+
+/// Print the rationale and ASCII alternatives for an `--explain` topic
+pub fn print_explanation(topic: ExplainTopic) {
+    let category = match topic {
+        ExplainTopic::Category(category) => category,
+        ExplainTopic::Char(ch) => match CharCategory::classify(ch) {
+            Some(category) => {
+                let advice = match suggest(ch) {
+                    Some(replacement) => format!("'{}'", replacement),
+                    None => "remove".to_owned(),
+                };
+                println!(
+                    "{} is a prohibited {} (suggest: {})\n",
+                    DisplayChar(ch),
+                    category,
+                    advice
+                );
+                if let Some(note) = note(ch) {
+                    println!("{}\n", note);
+                }
+                category
+            }
+            None => {
+                println!("{} is permitted", DisplayChar(ch));
+                if let Some(ascii) = confusable(ch) {
+                    println!(
+                        "\nIt looks like '{}', so the {} rule reports it in ASCII text.",
+                        ascii,
+                        CharCategory::Confusable.key()
+                    );
+                }
+                return;
+            }
+        },
+    };
+    println!("{} (rule: {})\n", category, category.key());
+    println!("{}", category.explanation());
 }
 
 /// Extract program name from `argv[0]`, falling back to package name
@@ -157,10 +1386,172 @@ ARGS:
     [FILE]...    Files to check for Unicode compliance
                  If no files are specified, reads from stdin
                  Use '-' to explicitly read from stdin
+                 Glob patterns (e.g. 'src/*.rs') are expanded if the
+                 shell did not already expand them
+                 Directories are walked only with -r
+                 Files are checked one at a time and reported in the
+                 order given, so output is identical from run to run
+    @FILE        Read further arguments from FILE, one per line
 
 OPTIONS:
+        --fix        Replace prohibited characters with ASCII equivalents
+                     in place (stdin is fixed to stdout)
+        --dry-run    With --fix, print a diff of proposed changes
+                     instead of writing them
+        --format <FORMAT>
+                     Output format: text (default), json, csv, or
+                     editor (always 'FILE:LINE:COL', never grouped);
+                     json also prints a one-line summary to stderr
+        --link       Report locations as file:// URLs with a #LlineCcol
+                     fragment, for editors that open them (implies
+                     --format editor)
+        --group      Print text violations grouped under a header for
+                     each file
+        --show-clean Also print 'FILE: clean' for each file without
+                     violations
+    -H, --with-filename
+                     Prefix each violation with its file name (default
+                     when checking more than one input)
+        --no-filename
+                     Omit the file name prefix (default for one input)
+    -0, --null       End each violation record with a NUL byte instead of a
+                     newline, for 'xargs -0' and similar tools
+        --lines <START:END>
+                     Report only violations on lines START through END
+                     of a single input
+        --count      Print only the total number of violations, or with
+                     --group a tab-separated count for each file
+        --stats      Print only a table of violation counts for each
+                     character, most frequent first, with a total
+        --dedupe     Report consecutive violations of the same character
+                     on one line once, with a count
+        --relative <ROOT>
+                     Report file paths relative to ROOT, or as absolute
+                     paths if outside it
+        --absolute   Report absolute file paths
+        --sort <ORDER>
+                     Report order: none (default, input order), path,
+                     or severity
+        --color <WHEN>
+                     Color output: auto (default), always, or never
+        --files-from <FILE>
+                     Also check the newline-separated paths listed in FILE
+                     ('-' reads the list from stdin)
+        --files-from0 <FILE>
+                     Like --files-from, but paths are NUL-separated
+        --stdin-filename <NAME>
+                     Report stdin input as NAME
+        --exclude <GLOB>
+                     Skip inputs whose path matches GLOB (repeatable)
+        --column <UNIT>
+                     Count columns by char (default), grapheme, or byte
+        --tab-width <N>
+                     Tab stop width for reported columns (default: 8)
+        --lang <LANG>
+                     Skip string literals and comments in 'rust', or code
+                     blocks and code spans in 'markdown'; input that does
+                     not lex is checked in full
+        --only <LIST>
+                     Check only the comma-separated categories in LIST,
+                     such as 'emoji,arrows'
+        --ignore <LIST>
+                     Do not check the comma-separated categories in LIST
+        --require-final-newline
+                     Report inputs that do not end with a newline
+        --check-bom  Report a leading UTF-8 byte order mark
+        --check-confusables
+                     Report letters that look like ASCII, such as
+                     full-width or Cyrillic forms, in ASCII words
+        --ascii-only Report every non-ASCII character, even the accented
+                     letters, other scripts, currency, and legal symbols
+                     allowed by default
+        --normalize <FORM>
+                     Report text not in Unicode normalization FORM (nfc
+                     or nfd); --fix rewrites it into that form
+        --no-binary-skip
+                     Scan inputs that look binary instead of skipping them
+        --binary-threshold <RATIO>
+                     Treat an input as binary when more than RATIO of its
+                     first 8 KiB are control bytes (default: 0.1; 1.0
+                     never skips)
+    -q, --quiet      Print no violations; report only via exit code
+        --quiet-errors
+                     Log unreadable inputs at debug level instead of
+                     printing them; the exit code is still 2
+    -r, --recursive  Check every file within directory arguments
+        --follow-symlinks
+                     Follow symbolic links while walking directories,
+                     checking each file once
+        --no-follow-symlinks
+                     Skip symbolic links while walking directories
+                     (default)
+    -w, --watch      After checking, watch the input files and recheck each
+                     one when it changes, until interrupted; the exit
+                     status reflects only the first pass
+        --fail-fast  Stop at the first violation (with --fix, after the
+                     first input with unfixable violations)
+        --keep-going Report inputs that cannot be read and check the
+                     rest (default)
+        --no-keep-going
+                     Stop at the first input that cannot be read
+        --exit-zero  Exit with status 0 even if violations are found
+                     (errors still exit with status 2)
+        --warnings-as-errors
+                     Exit with status 1 for warning-severity violations
+                     too, not only errors
+        --error-on-empty
+                     Exit with status 2 if no input files are left to
+                     check, such as from an empty --files-from list,
+                     instead of only warning
+        --hook       Preset for pre-commit hooks: check only the files
+                     given (nothing when none are), always name them,
+                     show no progress or empty-input warning, and exit
+                     with status 1 on violations even if configured
+                     otherwise
+        --baseline <FILE>
+                     Report only violations not recorded in FILE
+        --write-baseline
+                     With --baseline, record all current violations to
+                     FILE instead of reporting them
+        --prune-baseline
+                     With --baseline, drop entries from FILE that no
+                     longer occur
+        --diff <RANGE>
+                     Report only violations on lines added by
+                     'git diff RANGE'; with no FILE, checks the files
+                     the diff touches
+        --changed-only
+                     Check only tracked files with uncommitted changes,
+                     staged or not, as listed by git; FILE arguments are
+                     ignored
+        --max-violations <N>
+                     Print at most N violations in total
+        --max-violations-per-file <N>
+                     Print at most N violations for each input
+        --jobs <N>   Number of inputs to check at once; only 1 is
+                     supported, which keeps output in argument order
+        --summary    Print total violation and file counts to stderr
+        --profile    Print bytes and files scanned, violations found,
+                     elapsed time, and throughput to stderr
+    -v, --verbose    Show more detail (per-category counts in --summary,
+                     build metadata in --version)
+        --config <FILE>
+                     Load configuration from FILE only, instead of
+                     searching for .noemoji.toml files (and the file
+                     named by NOEMOJI_CONFIG, which overrides them)
+        --no-config  Ignore all configuration files and use defaults
+        --check-config
+                     Validate configuration files, print the effective
+                     configuration, and exit
+        --init       Write a commented .noemoji.toml to the current
+                     directory and exit
+        --force      With --init, overwrite an existing .noemoji.toml
+        --explain <RULE|CHAR>
+                     Explain a rule, or why a character is prohibited,
+                     and exit
     -h, --help       Show this help message and exit
-    -V, --version    Show version information and exit
+    -V, --version    Show version information and exit; with --verbose, also
+                     show the commit, build date, and compiler
 
 EXAMPLES:
     {program} README.md
@@ -169,6 +1560,19 @@ EXAMPLES:
     echo 'text' | {program}
     {program} file1.txt - file2.txt
     {program} < file.txt
+    {program} --fix README.md
+    {program} --fix --dry-run README.md
+    {program} --format json src/*.rs
+    {program} --exclude '*.min.js' web/*.js
+    {program} --column grapheme README.md
+    {program} --explain arrows
+    {program} --only emoji,arrows README.md
+    {program} -r docs
+    {program} --config ci/noemoji.toml src/*.rs
+    {program} --baseline .noemoji-baseline.json --write-baseline src/*.rs
+    git diff --name-only | {program} --files-from -
+    {program} @args.txt
+    {program} --diff origin/main...HEAD
 
 EXIT CODES:
     0    All files are compliant (success)
@@ -237,6 +1641,681 @@ mod tests {
         assert_eq!(code, ExitCode::from(2));
     }
 
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_args_defaults_to_check() {
+        let cmd = parse_args(&args(&["a.txt"])).unwrap();
+        assert_eq!(
+            cmd,
+            CliCommand::Check {
+                inputs: vec![InputSource::File(PathBuf::from("a.txt"))],
+                options: Options::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_format_json() {
+        for argv in [&["--format", "json"][..], &["--format=json"][..]] {
+            let cmd = parse_args(&args(argv)).unwrap();
+            assert_eq!(
+                cmd,
+                CliCommand::Check {
+                    inputs: vec![InputSource::Stdin],
+                    options: Options {
+                        format: Some(OutputFormat::Json),
+                        ..Options::default()
+                    },
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn parse_args_column() {
+        match parse_args(&args(&["--column=grapheme", "file.txt"])).unwrap() {
+            CliCommand::Check { options, .. } => {
+                assert_eq!(options.column, Some(ColumnMode::Grapheme));
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        let err = parse_args(&args(&["--column", "glyph"])).unwrap_err();
+        assert!(matches!(err, CliError::InvalidValue { .. }));
+    }
+
+    #[test]
+    fn parse_args_lang() {
+        match parse_args(&args(&["--lang", "rust", "main.rs"])).unwrap() {
+            CliCommand::Check { options, .. } => assert_eq!(options.lang, Some(Language::Rust)),
+            other => panic!("unexpected command {other:?}"),
+        }
+        let err = parse_args(&args(&["--lang", "cobol"])).unwrap_err();
+        assert!(matches!(err, CliError::InvalidValue { .. }));
+    }
+
+    #[test]
+    fn parse_args_tab_width() {
+        match parse_args(&args(&["--tab-width", "4"])).unwrap() {
+            CliCommand::Check { options, .. } => assert_eq!(options.tab_width, Some(4)),
+            other => panic!("unexpected command {other:?}"),
+        }
+        for bad in ["0", "-1", "four"] {
+            let err = parse_args(&args(&["--tab-width", bad])).unwrap_err();
+            assert!(matches!(err, CliError::InvalidValue { .. }), "{bad}");
+        }
+    }
+
+    #[test]
+    fn parse_args_max_violations() {
+        let argv = args(&["--max-violations", "3", "--max-violations-per-file", "2"]);
+        match parse_args(&argv).unwrap() {
+            CliCommand::Check { options, .. } => {
+                assert_eq!(options.max_violations, Some(3));
+                assert_eq!(options.max_violations_per_file, Some(2));
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        for bad in ["0", "-1", "three"] {
+            let err = parse_args(&args(&["--max-violations", bad])).unwrap_err();
+            assert!(matches!(err, CliError::InvalidValue { .. }), "{bad}");
+        }
+    }
+
+    #[test]
+    fn parse_args_jobs_accepts_only_one() {
+        assert!(parse_args(&args(&["--jobs", "1", "a.txt"])).is_ok());
+        for bad in ["0", "2", "one"] {
+            let err = parse_args(&args(&["--jobs", bad])).unwrap_err();
+            assert!(matches!(err, CliError::InvalidValue { .. }), "{bad}");
+        }
+    }
+
+    #[test]
+    fn parse_args_color() {
+        match parse_args(&args(&["--color", "always"])).unwrap() {
+            CliCommand::Check { options, .. } => assert_eq!(options.color, ColorChoice::Always),
+            other => panic!("unexpected command {other:?}"),
+        }
+        let err = parse_args(&args(&["--color", "sometimes"])).unwrap_err();
+        assert!(matches!(err, CliError::InvalidValue { .. }));
+    }
+
+    #[test]
+    fn expand_response_files_passes_through_after_double_dash() {
+        let argv = args(&["--", "@literal.txt", "@"]);
+        assert_eq!(expand_response_files(&argv).unwrap(), argv);
+        let argv = args(&["@"]);
+        assert_eq!(expand_response_files(&argv).unwrap(), argv);
+    }
+
+    #[test]
+    fn parse_args_only_and_ignore() {
+        match parse_args(&args(&[
+            "--only",
+            "emoji, arrows",
+            "--only=math",
+            "--ignore=math",
+        ]))
+        .unwrap()
+        {
+            CliCommand::Check { options, .. } => {
+                assert_eq!(
+                    options.only,
+                    Some(vec![
+                        CharCategory::Emoji,
+                        CharCategory::Arrow,
+                        CharCategory::Math
+                    ])
+                );
+                assert_eq!(options.ignore, vec![CharCategory::Math]);
+                let checker = options.apply(Checker::new());
+                assert!(checker.is_enabled(CharCategory::Emoji));
+                assert!(!checker.is_enabled(CharCategory::Math));
+                assert!(!checker.is_enabled(CharCategory::Punctuation));
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        let err = parse_args(&args(&["--ignore", "sparkles"])).unwrap_err();
+        assert!(matches!(err, CliError::UnknownCategory { .. }));
+    }
+
+    #[test]
+    fn parse_args_lines() {
+        match parse_args(&args(&["--lines", "5:20", "a.txt"])).unwrap() {
+            CliCommand::Check { options, .. } => assert_eq!(options.lines, Some(5..=20)),
+            other => panic!("unexpected command {other:?}"),
+        }
+        for value in ["0:3", "9:2", "5", "a:b", "3:"] {
+            let err = parse_args(&args(&["--lines", value])).unwrap_err();
+            assert!(matches!(err, CliError::InvalidValue { .. }), "{value}");
+        }
+        let err = parse_args(&args(&["--lines", "1:2", "a.txt", "b.txt"])).unwrap_err();
+        assert!(matches!(err, CliError::SingleInputRequired(_)));
+    }
+
+    #[test]
+    fn parse_args_sort() {
+        for (value, order) in [
+            ("path", SortOrder::Path),
+            ("severity", SortOrder::Severity),
+            ("none", SortOrder::None),
+        ] {
+            match parse_args(&args(&["--sort", value])).unwrap() {
+                CliCommand::Check { options, .. } => assert_eq!(options.sort, order),
+                other => panic!("unexpected command {other:?}"),
+            }
+        }
+        let err = parse_args(&args(&["--sort=name"])).unwrap_err();
+        assert!(matches!(err, CliError::InvalidValue { .. }));
+    }
+
+    #[test]
+    fn color_choice_forced_values_ignore_environment() {
+        assert!(ColorChoice::Always.enabled());
+        assert!(!ColorChoice::Never.enabled());
+    }
+
+    #[test]
+    fn display_name_overrides_only_stdin() {
+        let options = Options {
+            stdin_filename: Some("src/foo.rs".to_owned()),
+            ..Options::default()
+        };
+        assert_eq!(options.display_name(&InputSource::Stdin), "src/foo.rs");
+        let file = InputSource::File(PathBuf::from("a.txt"));
+        assert_eq!(options.display_name(&file), "a.txt");
+        assert_eq!(
+            Options::default().display_name(&InputSource::Stdin),
+            "stdin"
+        );
+    }
+
+    #[test]
+    fn parse_args_explain() {
+        assert_eq!(
+            parse_args(&args(&["--explain", "box-drawing"])).unwrap(),
+            CliCommand::Explain(ExplainTopic::Category(CharCategory::BoxDrawing))
+        );
+        assert_eq!(
+            parse_args(&args(&["--explain", "→"])).unwrap(),
+            CliCommand::Explain(ExplainTopic::Char('→'))
+        );
+        assert_eq!(
+            parse_args(&args(&["--explain", "U+00A0"])).unwrap(),
+            CliCommand::Explain(ExplainTopic::Char('\u{00A0}'))
+        );
+        let err = parse_args(&args(&["--explain", "sparkles"])).unwrap_err();
+        assert!(matches!(err, CliError::InvalidValue { .. }));
+    }
+
+    #[test]
+    fn parse_args_config_source() {
+        match parse_args(&args(&["--config", "ci.toml"])).unwrap() {
+            CliCommand::Check { options, .. } => {
+                assert_eq!(options.config, ConfigSource::File(PathBuf::from("ci.toml")))
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        assert_eq!(
+            parse_args(&args(&["--no-config", "--check-config"])).unwrap(),
+            CliCommand::CheckConfig(ConfigSource::Defaults)
+        );
+        let err = parse_args(&args(&["--config", "ci.toml", "--no-config"])).unwrap_err();
+        assert!(matches!(err, CliError::ConflictingOptions { .. }));
+        // The last --config wins, and a repeated --no-config is harmless
+        match parse_args(&args(&["--config", "a.toml", "--config", "b.toml"])).unwrap() {
+            CliCommand::Check { options, .. } => {
+                assert_eq!(options.config, ConfigSource::File(PathBuf::from("b.toml")))
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        assert!(parse_args(&args(&["--no-config", "--no-config"])).is_ok());
+    }
+
+    #[test]
+    fn parse_args_relative_conflicts_with_absolute() {
+        match parse_args(&args(&["--relative", "src"])).unwrap() {
+            CliCommand::Check { options, .. } => {
+                assert_eq!(options.path_style, PathStyle::Relative("src".into()));
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        for argv in [
+            &["--relative", "src", "--absolute"][..],
+            &["--absolute", "--relative", "src"][..],
+        ] {
+            let err = parse_args(&args(argv)).unwrap_err();
+            assert!(matches!(err, CliError::ConflictingOptions { .. }));
+        }
+    }
+
+    #[test]
+    fn report_name_rewrites_only_files() {
+        let file = InputSource::File(Path::new("src").join("dir").join("a.txt"));
+        let options = Options {
+            path_style: PathStyle::Relative("src".into()),
+            stdin_filename: Some("stdin.txt".to_owned()),
+            ..Options::default()
+        };
+        assert_eq!(
+            options.report_name(&file),
+            Path::new("dir").join("a.txt").display().to_string()
+        );
+        assert_eq!(options.report_name(&InputSource::Stdin), "stdin.txt");
+
+        let options = Options {
+            path_style: PathStyle::Relative(Path::new("src").join("..").join("src")),
+            ..Options::default()
+        };
+        assert_eq!(
+            options.report_name(&file),
+            Path::new("dir").join("a.txt").display().to_string()
+        );
+
+        let options = Options {
+            path_style: PathStyle::Relative("other".into()),
+            ..Options::default()
+        };
+        let name = options.report_name(&file);
+        assert!(Path::new(&*name).is_absolute());
+        assert!(name.ends_with(&file.name().into_owned()));
+    }
+
+    #[test]
+    fn parse_args_version() {
+        assert_eq!(
+            parse_args(&args(&["--version"])).unwrap(),
+            CliCommand::Version { verbose: false }
+        );
+        assert_eq!(
+            parse_args(&args(&["--version", "--verbose"])).unwrap(),
+            CliCommand::Version { verbose: true }
+        );
+        assert_eq!(
+            parse_args(&args(&["-v", "-V"])).unwrap(),
+            CliCommand::Version { verbose: true }
+        );
+    }
+
+    #[test]
+    fn parse_args_dedupe() {
+        match parse_args(&args(&["--dedupe"])).unwrap() {
+            CliCommand::Check { options, .. } => assert!(options.dedupe),
+            other => panic!("unexpected command {other:?}"),
+        }
+        let err = parse_args(&args(&["--dedupe", "--fix", "file.txt"])).unwrap_err();
+        assert!(matches!(err, CliError::ConflictingOptions { .. }));
+    }
+
+    #[test]
+    fn parse_args_init() {
+        assert_eq!(
+            parse_args(&args(&["--init"])).unwrap(),
+            CliCommand::Init { force: false }
+        );
+        assert_eq!(
+            parse_args(&args(&["--force", "--init"])).unwrap(),
+            CliCommand::Init { force: true }
+        );
+        let err = parse_args(&args(&["--force"])).unwrap_err();
+        assert!(matches!(err, CliError::MissingRequiredOption { .. }));
+    }
+
+    #[test]
+    fn parse_args_baseline() {
+        match parse_args(&args(&["--baseline", "b.json", "--prune-baseline"])).unwrap() {
+            CliCommand::Check { options, .. } => {
+                assert_eq!(options.baseline, Some(PathBuf::from("b.json")));
+                assert!(options.prune_baseline);
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        let err = parse_args(&args(&["--prune-baseline"])).unwrap_err();
+        assert!(matches!(err, CliError::MissingRequiredOption { .. }));
+        let err = parse_args(&args(&["--baseline", "b.json", "--fix"])).unwrap_err();
+        assert!(matches!(err, CliError::ConflictingOptions { .. }));
+    }
+
+    #[test]
+    fn parse_args_keep_going_last_flag_wins() {
+        match parse_args(&args(&["--no-keep-going"])).unwrap() {
+            CliCommand::Check { options, .. } => assert!(options.stop_on_error),
+            other => panic!("unexpected command {other:?}"),
+        }
+        match parse_args(&args(&["--no-keep-going", "--keep-going"])).unwrap() {
+            CliCommand::Check { options, .. } => assert!(!options.stop_on_error),
+            other => panic!("unexpected command {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_args_fail_fast() {
+        match parse_args(&args(&["--fail-fast", "-q"])).unwrap() {
+            CliCommand::Check { options, .. } => {
+                assert!(options.fail_fast);
+                assert!(options.quiet);
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_args_link_implies_editor_format() {
+        match parse_args(&args(&["--link"])).unwrap() {
+            CliCommand::Check { options, .. } => {
+                assert!(options.link);
+                assert_eq!(options.format, Some(OutputFormat::Editor));
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        assert!(parse_args(&args(&["--link", "--format", "editor"])).is_ok());
+        for argv in [
+            &["--link", "--format", "json"][..],
+            &["--link", "--relative", "src"][..],
+            &["--link", "--fix"][..],
+        ] {
+            let err = parse_args(&args(argv)).unwrap_err();
+            assert!(matches!(err, CliError::ConflictingOptions { .. }));
+        }
+    }
+
+    #[test]
+    fn file_url_percent_encodes_path() {
+        assert_eq!(
+            file_url(Path::new("/tmp/a b#1.txt")),
+            "file:///tmp/a%20b%231.txt"
+        );
+        assert_eq!(file_url(Path::new("/tmp/é.rs")), "file:///tmp/%C3%A9.rs");
+    }
+
+    #[test]
+    fn parse_args_csv_format_conflicts_with_count() {
+        match parse_args(&args(&["--format", "csv"])).unwrap() {
+            CliCommand::Check { options, .. } => {
+                assert_eq!(options.format, Some(OutputFormat::Csv));
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        let err = parse_args(&args(&["--format", "csv", "--count"])).unwrap_err();
+        assert!(
+            matches!(err, CliError::ConflictingOptions { ref other, .. } if other == "--format csv")
+        );
+    }
+
+    #[test]
+    fn parse_args_rejects_unknown_format() {
+        let err = parse_args(&args(&["--format", "xml"])).unwrap_err();
+        assert!(matches!(err, CliError::InvalidValue { ref value, .. } if value == "xml"));
+    }
+
+    #[test]
+    fn parse_args_fix_flag_selects_fix() {
+        let cmd = parse_args(&args(&["--fix", "a.txt", "-"])).unwrap();
+        assert_eq!(
+            cmd,
+            CliCommand::Fix {
+                inputs: vec![
+                    InputSource::File(PathBuf::from("a.txt")),
+                    InputSource::Stdin
+                ],
+                dry_run: false,
+                options: Options {
+                    with_filename: true,
+                    ..Options::default()
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_expands_glob_patterns() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::write(dir.join("a.toml"), "").unwrap();
+        std::fs::write(dir.join("b.toml"), "").unwrap();
+        std::fs::write(dir.join("c.txt"), "").unwrap();
+
+        let pattern = dir.join("*.toml").to_str().unwrap().to_owned();
+        let cmd = parse_args(&args(&[&pattern])).unwrap();
+
+        assert_eq!(
+            cmd,
+            CliCommand::Check {
+                inputs: vec![
+                    InputSource::File(dir.join("a.toml")),
+                    InputSource::File(dir.join("b.toml")),
+                ],
+                options: Options {
+                    with_filename: true,
+                    ..Options::default()
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_glob_falls_back_to_literal_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let literal = temp_dir.path().join("[draft].md");
+        std::fs::write(&literal, "").unwrap();
+
+        let cmd = parse_args(&args(&[literal.to_str().unwrap()])).unwrap();
+
+        assert_eq!(
+            cmd,
+            CliCommand::Check {
+                inputs: vec![InputSource::File(literal)],
+                options: Options::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_files_from_keeps_position() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let list = temp_dir.path().join("list.txt");
+        std::fs::write(&list, "b.txt\r\n\nc.txt\n").unwrap();
+
+        let cmd = parse_args(&args(&["a.txt", "--files-from", list.to_str().unwrap()])).unwrap();
+
+        assert_eq!(
+            cmd,
+            CliCommand::Check {
+                inputs: vec![
+                    InputSource::File(PathBuf::from("a.txt")),
+                    InputSource::File(PathBuf::from("b.txt")),
+                    InputSource::File(PathBuf::from("c.txt")),
+                ],
+                options: Options {
+                    with_filename: true,
+                    ..Options::default()
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_files_from0_splits_on_nul() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let list = temp_dir.path().join("list");
+        std::fs::write(&list, "with\nnewline.txt\0*.rs\0").unwrap();
+
+        let cmd = parse_args(&args(&["--files-from0", list.to_str().unwrap()])).unwrap();
+
+        assert_eq!(
+            cmd,
+            CliCommand::Check {
+                inputs: vec![
+                    InputSource::File(PathBuf::from("with\nnewline.txt")),
+                    InputSource::File(PathBuf::from("*.rs")),
+                ],
+                options: Options {
+                    with_filename: true,
+                    ..Options::default()
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_empty_file_list_does_not_read_stdin() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let list = temp_dir.path().join("empty");
+        std::fs::write(&list, "").unwrap();
+
+        let cmd = parse_args(&args(&["--files-from", list.to_str().unwrap()])).unwrap();
+
+        assert_eq!(
+            cmd,
+            CliCommand::Check {
+                inputs: vec![],
+                options: Options {
+                    with_filename: true,
+                    ..Options::default()
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_missing_file_list_is_an_error() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let list = temp_dir.path().join("missing");
+
+        let err = parse_args(&args(&["--files-from", list.to_str().unwrap()])).unwrap_err();
+
+        assert!(matches!(err, CliError::FileList { .. }));
+    }
+
+    #[test]
+    fn parse_args_unmatched_glob_is_an_error() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let pattern = temp_dir.path().join("*.nothing");
+
+        let err = parse_args(&args(&[pattern.to_str().unwrap()])).unwrap_err();
+
+        assert!(matches!(err, CliError::NoMatchingFiles(_)));
+        assert!(err.to_string().contains("no files matched pattern"));
+    }
+
+    #[test]
+    fn parse_args_invalid_glob_is_an_error() {
+        let err = parse_args(&args(&["src/[*.rs"])).unwrap_err();
+        assert!(matches!(err, CliError::InvalidGlob { .. }));
+    }
+
+    #[test]
+    fn parse_args_collects_repeated_excludes() {
+        let cmd = parse_args(&args(&[
+            "--exclude",
+            "*.min.js",
+            "--exclude=docs/**",
+            "a.txt",
+        ]))
+        .unwrap();
+        let CliCommand::Check { options, .. } = cmd else {
+            panic!("expected check command");
+        };
+        assert_eq!(
+            options.exclude,
+            vec![
+                glob::Pattern::new("*.min.js").unwrap(),
+                glob::Pattern::new("docs/**").unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_args_changed_only_ignores_inputs() {
+        match parse_args(&args(&["--changed-only", "a.txt"])).unwrap() {
+            CliCommand::Check { inputs, options } => {
+                assert!(inputs.is_empty());
+                assert!(options.changed_only);
+                assert!(options.with_filename);
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        for argv in [
+            &["--changed-only", "--diff", "HEAD"][..],
+            &["--changed-only", "--watch"][..],
+            &["--changed-only", "--fix"][..],
+        ] {
+            let err = parse_args(&args(argv)).unwrap_err();
+            assert!(matches!(err, CliError::ConflictingOptions { .. }));
+        }
+    }
+
+    #[test]
+    fn parse_args_hook_preset() {
+        match parse_args(&args(&["--hook"])).unwrap() {
+            CliCommand::Check { inputs, options } => {
+                assert!(inputs.is_empty());
+                assert!(options.hook);
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        match parse_args(&args(&["--hook", "a.txt"])).unwrap() {
+            CliCommand::Check { options, .. } => assert!(options.with_filename),
+            other => panic!("unexpected command {other:?}"),
+        }
+        match parse_args(&args(&["--hook", "--no-filename", "a.txt"])).unwrap() {
+            CliCommand::Check { options, .. } => assert!(!options.with_filename),
+            other => panic!("unexpected command {other:?}"),
+        }
+        let err = parse_args(&args(&["--hook", "--exit-zero"])).unwrap_err();
+        assert!(matches!(err, CliError::ConflictingOptions { .. }));
+    }
+
+    #[test]
+    fn parse_args_error_on_empty() {
+        match parse_args(&args(&["--error-on-empty"])).unwrap() {
+            CliCommand::Check { options, .. } => assert!(options.error_on_empty),
+            other => panic!("unexpected command {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_args_quiet_flag() {
+        for flag in ["-q", "--quiet"] {
+            let cmd = parse_args(&args(&[flag])).unwrap();
+            let CliCommand::Check { options, .. } = cmd else {
+                panic!("expected check command");
+            };
+            assert!(options.quiet);
+        }
+    }
+
+    #[test]
+    fn parse_args_summary_and_verbose_flags() {
+        let cmd = parse_args(&args(&["--summary", "-v"])).unwrap();
+        let CliCommand::Check { options, .. } = cmd else {
+            panic!("expected check command");
+        };
+        assert!(options.summary);
+        assert!(options.verbose);
+    }
+
+    #[test]
+    fn options_is_excluded_matches_paths() {
+        let options = Options {
+            exclude: vec![
+                glob::Pattern::new("*.min.js").unwrap(),
+                glob::Pattern::new("docs/generated/**").unwrap(),
+            ],
+            ..Options::default()
+        };
+        let file = |p: &str| InputSource::File(PathBuf::from(p));
+
+        assert!(options.is_excluded(&file("web/app.min.js")));
+        assert!(options.is_excluded(&file("docs/generated/api/index.md")));
+        assert!(!options.is_excluded(&file("web/app.js")));
+        assert!(!options.is_excluded(&file("docs/guide.md")));
+        assert!(!options.is_excluded(&InputSource::Stdin));
+    }
+
     #[test]
     fn from_lexopt_unexpected_option() {
         let lexopt_err = lexopt::Error::UnexpectedOption("--bad".to_owned());
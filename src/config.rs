@@ -8,14 +8,29 @@
 //! Configuration files (`.noemoji.toml`) are searched from the current directory
 //! up through parent directories, with child configurations overriding parent values.
 //! The search stops when a configuration file sets `inherit = false` or when the
-//! filesystem root is reached.
+//! filesystem root is reached. In the latter case, a global configuration file,
+//! if one is passed to [`Config::load_from_with_global`], is merged in last,
+//! below every project configuration. The command line passes the user's
+//! (see [`global_config_path`]); loading from a directory alone never reads it.
 
-use std::{env, fs, io};
+use std::{
+    collections::{HashMap, HashSet},
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
 
-use serde::Deserialize;
+use directories::ProjectDirs;
+use serde::{Deserialize, Deserializer, de};
 use thiserror::Error;
 
-use crate::logging::LogLevel;
+use crate::{
+    check::{
+        BinaryThreshold, CharCategory, Checker, ColumnMode, DisplayChar, NormalizationForm,
+        Severity,
+    },
+    logging::LogLevel,
+    output::OutputFormat,
+};
 
 /// Configuration parsing and validation errors
 #[derive(Debug, Error)]
@@ -42,6 +57,281 @@ pub struct LogConfig {
     pub level: Option<LogLevel>,
 }
 
+/// Output configuration for noemoji.
+///
+/// Corresponds to the `[output]` section in .noemoji.toml:
+/// ```toml
+/// [output]
+/// format = "json"  # One of: text, json, csv, editor
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Deserialize)]
+pub struct OutputConfig {
+    /// Report format used when `--format` is not given (None = use default)
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+}
+
+/// Exit status configuration for noemoji.
+///
+/// Corresponds to the `[behavior]` section in .noemoji.toml. Setting
+/// `violations_exit_code = 0` reports violations without failing, which helps
+/// adopt the linter gradually; errors still exit with status 2:
+/// ```toml
+/// [behavior]
+/// violations_exit_code = 0  # One of: 0, 1 (default)
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Deserialize)]
+pub struct BehaviorConfig {
+    /// Exit status when violations are found (None = use default of 1)
+    #[serde(default, deserialize_with = "deserialize_exit_code")]
+    pub violations_exit_code: Option<u8>,
+}
+
+/// Per-category rule toggles for noemoji.
+///
+/// Corresponds to the `[rules]` section in .noemoji.toml. Each field enables
+/// (`true`) or disables (`false`) a detection category; unset fields inherit
+/// from parent configs and default to enabled:
+/// ```toml
+/// [rules]
+/// punctuation = false
+/// emoji = true
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Deserialize)]
+pub struct RulesConfig {
+    /// Emoji, pictographs, and flags
+    #[serde(default)]
+    pub emoji: Option<bool>,
+    /// Arrows such as → and ⇒
+    #[serde(default)]
+    pub arrows: Option<bool>,
+    /// Checkmarks and cross marks such as ✓ and ✗
+    #[serde(default)]
+    pub checkmarks: Option<bool>,
+    /// Box drawing and block elements
+    #[serde(default)]
+    pub box_drawing: Option<bool>,
+    /// Math symbols with ASCII equivalents such as ≤ and ≠
+    #[serde(default)]
+    pub math: Option<bool>,
+    /// Superscripts and subscripts such as ² and ₁
+    #[serde(default)]
+    pub supersub: Option<bool>,
+    /// Vulgar fractions such as ½ and ¾
+    #[serde(default)]
+    pub fractions: Option<bool>,
+    /// Decorative stars, bullets, and shapes
+    #[serde(default)]
+    pub decorative: Option<bool>,
+    /// Curly quotes, dashes, and ellipses
+    #[serde(default)]
+    pub punctuation: Option<bool>,
+    /// Typographic ligatures such as ﬁ and marks such as ‽
+    #[serde(default)]
+    pub ligatures: Option<bool>,
+    /// Non-ASCII and zero-width whitespace
+    #[serde(default)]
+    pub whitespace: Option<bool>,
+    /// Require the last line to end in a newline (off unless enabled)
+    #[serde(default)]
+    pub final_newline: Option<bool>,
+    /// Report a leading UTF-8 byte order mark (off unless enabled)
+    #[serde(default)]
+    pub byte_order_mark: Option<bool>,
+    /// Letters that look like ASCII mixed into ASCII text (off unless enabled)
+    #[serde(default)]
+    pub confusables: Option<bool>,
+    /// Every other non-ASCII character, ignoring the usual exceptions (off
+    /// unless enabled)
+    #[serde(default)]
+    pub non_ascii: Option<bool>,
+}
+
+impl RulesConfig {
+    /// Merge two rule sets field by field, preferring `self`'s values
+    pub fn or(self, other: Self) -> Self {
+        RulesConfig {
+            emoji: self.emoji.or(other.emoji),
+            arrows: self.arrows.or(other.arrows),
+            checkmarks: self.checkmarks.or(other.checkmarks),
+            box_drawing: self.box_drawing.or(other.box_drawing),
+            math: self.math.or(other.math),
+            supersub: self.supersub.or(other.supersub),
+            fractions: self.fractions.or(other.fractions),
+            decorative: self.decorative.or(other.decorative),
+            punctuation: self.punctuation.or(other.punctuation),
+            ligatures: self.ligatures.or(other.ligatures),
+            whitespace: self.whitespace.or(other.whitespace),
+            final_newline: self.final_newline.or(other.final_newline),
+            byte_order_mark: self.byte_order_mark.or(other.byte_order_mark),
+            confusables: self.confusables.or(other.confusables),
+            non_ascii: self.non_ascii.or(other.non_ascii),
+        }
+    }
+
+    /// Returns the toggle for `category`, or `None` if unset
+    pub fn get(&self, category: CharCategory) -> Option<bool> {
+        match category {
+            CharCategory::Arrow => self.arrows,
+            CharCategory::Checkmark => self.checkmarks,
+            CharCategory::BoxDrawing => self.box_drawing,
+            CharCategory::Math => self.math,
+            CharCategory::Supersub => self.supersub,
+            CharCategory::Fractions => self.fractions,
+            CharCategory::Decorative => self.decorative,
+            CharCategory::Punctuation => self.punctuation,
+            CharCategory::Ligature => self.ligatures,
+            CharCategory::Whitespace => self.whitespace,
+            CharCategory::Emoji => self.emoji,
+            // Controlled by the `deny` list rather than a toggle
+            CharCategory::Custom => None,
+            CharCategory::MissingFinalNewline => self.final_newline,
+            CharCategory::ByteOrderMark => self.byte_order_mark,
+            CharCategory::Confusable => self.confusables,
+            // Controlled by the `normalize` setting rather than a toggle
+            CharCategory::Normalization => None,
+            CharCategory::NonAscii => self.non_ascii,
+        }
+    }
+
+    /// Build a [`Checker`] with these toggles applied; unset categories keep
+    /// their defaults
+    pub fn checker(&self) -> Checker {
+        CharCategory::ALL
+            .iter()
+            .fold(Checker::new(), |checker, &category| {
+                match self.get(category) {
+                    Some(enabled) => checker.with_category(category, enabled),
+                    None => checker,
+                }
+            })
+    }
+}
+
+/// Per-category severities for noemoji.
+///
+/// Corresponds to the `[severity]` section in .noemoji.toml. Each field sets
+/// whether a category's violations are errors, which fail the run, or
+/// warnings, which are only reported; unset fields inherit from parent configs
+/// and default to errors:
+/// ```toml
+/// [severity]
+/// punctuation = "warning"
+/// emoji = "error"
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Deserialize)]
+pub struct SeverityConfig {
+    /// Emoji, pictographs, and flags
+    #[serde(default)]
+    pub emoji: Option<Severity>,
+    /// Arrows such as → and ⇒
+    #[serde(default)]
+    pub arrows: Option<Severity>,
+    /// Checkmarks and cross marks such as ✓ and ✗
+    #[serde(default)]
+    pub checkmarks: Option<Severity>,
+    /// Box drawing and block elements
+    #[serde(default)]
+    pub box_drawing: Option<Severity>,
+    /// Math symbols with ASCII equivalents such as ≤ and ≠
+    #[serde(default)]
+    pub math: Option<Severity>,
+    /// Superscripts and subscripts such as ² and ₁
+    #[serde(default)]
+    pub supersub: Option<Severity>,
+    /// Vulgar fractions such as ½ and ¾
+    #[serde(default)]
+    pub fractions: Option<Severity>,
+    /// Decorative stars, bullets, and shapes
+    #[serde(default)]
+    pub decorative: Option<Severity>,
+    /// Curly quotes, dashes, and ellipses
+    #[serde(default)]
+    pub punctuation: Option<Severity>,
+    /// Typographic ligatures such as ﬁ and marks such as ‽
+    #[serde(default)]
+    pub ligatures: Option<Severity>,
+    /// Non-ASCII and zero-width whitespace
+    #[serde(default)]
+    pub whitespace: Option<Severity>,
+    /// Characters on the `deny` list
+    #[serde(default)]
+    pub deny: Option<Severity>,
+    /// A last line that does not end in a newline
+    #[serde(default)]
+    pub final_newline: Option<Severity>,
+    /// A leading UTF-8 byte order mark
+    #[serde(default)]
+    pub byte_order_mark: Option<Severity>,
+    /// Letters that look like ASCII mixed into ASCII text
+    #[serde(default)]
+    pub confusables: Option<Severity>,
+    /// Text not in the form set by `normalize`
+    #[serde(default)]
+    pub normalization: Option<Severity>,
+    /// Other non-ASCII characters, reported in strict mode
+    #[serde(default)]
+    pub non_ascii: Option<Severity>,
+}
+
+impl SeverityConfig {
+    /// Merge two severity sets field by field, preferring `self`'s values
+    pub fn or(self, other: Self) -> Self {
+        SeverityConfig {
+            emoji: self.emoji.or(other.emoji),
+            arrows: self.arrows.or(other.arrows),
+            checkmarks: self.checkmarks.or(other.checkmarks),
+            box_drawing: self.box_drawing.or(other.box_drawing),
+            math: self.math.or(other.math),
+            supersub: self.supersub.or(other.supersub),
+            fractions: self.fractions.or(other.fractions),
+            decorative: self.decorative.or(other.decorative),
+            punctuation: self.punctuation.or(other.punctuation),
+            ligatures: self.ligatures.or(other.ligatures),
+            whitespace: self.whitespace.or(other.whitespace),
+            deny: self.deny.or(other.deny),
+            final_newline: self.final_newline.or(other.final_newline),
+            byte_order_mark: self.byte_order_mark.or(other.byte_order_mark),
+            confusables: self.confusables.or(other.confusables),
+            normalization: self.normalization.or(other.normalization),
+            non_ascii: self.non_ascii.or(other.non_ascii),
+        }
+    }
+
+    /// Returns the severity for `category`, or `None` if unset
+    pub fn get(&self, category: CharCategory) -> Option<Severity> {
+        match category {
+            CharCategory::Arrow => self.arrows,
+            CharCategory::Checkmark => self.checkmarks,
+            CharCategory::BoxDrawing => self.box_drawing,
+            CharCategory::Math => self.math,
+            CharCategory::Supersub => self.supersub,
+            CharCategory::Fractions => self.fractions,
+            CharCategory::Decorative => self.decorative,
+            CharCategory::Punctuation => self.punctuation,
+            CharCategory::Ligature => self.ligatures,
+            CharCategory::Whitespace => self.whitespace,
+            CharCategory::Emoji => self.emoji,
+            CharCategory::Custom => self.deny,
+            CharCategory::MissingFinalNewline => self.final_newline,
+            CharCategory::ByteOrderMark => self.byte_order_mark,
+            CharCategory::Confusable => self.confusables,
+            CharCategory::Normalization => self.normalization,
+            CharCategory::NonAscii => self.non_ascii,
+        }
+    }
+
+    /// Apply these severities to `checker`; unset categories are left alone
+    pub fn apply(&self, checker: Checker) -> Checker {
+        CharCategory::ALL
+            .iter()
+            .fold(checker, |checker, &category| match self.get(category) {
+                Some(severity) => checker.with_severity(category, severity),
+                None => checker,
+            })
+    }
+}
+
 /// Configuration settings for noemoji
 ///
 /// Example `.noemoji.toml` file:
@@ -51,12 +341,82 @@ pub struct LogConfig {
 ///
 /// [log]
 /// level = "debug"  # One of: disabled, error, warn, info, debug, trace
+///
+/// [output]
+/// format = "json"  # Report format when --format is not given
+///
+/// [rules]
+/// punctuation = false  # Allow curly quotes, dashes, and ellipses
+/// final_newline = true  # Require files to end with a newline
+///
+/// [severity]
+/// whitespace = "warning"  # Report without failing; one of: error, warning
+/// ```
+///
+/// Individual characters can be allowed or denied as literals or in `U+XXXX`
+/// notation:
+/// ```toml
+/// allow = ["≤", "U+2265"]
+/// deny = ["·"]
+/// ```
+///
+/// Built-in suggestions can be replaced, keyed the same way:
+/// ```toml
+/// [suggestions]
+/// "↑" = "up"
+/// ```
+///
+/// Reported columns can count graphemes or bytes instead of characters:
+/// ```toml
+/// column = "grapheme"  # One of: char, grapheme, byte
+/// ```
+///
+/// Inputs are skipped as binary when more than this share of their first
+/// bytes are control characters:
+/// ```toml
+/// binary_threshold = 0.25  # From 0.0 to 1.0; 1.0 never skips
 /// ```
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize)]
+///
+/// Text can be required to be in one Unicode normalization form:
+/// ```toml
+/// normalize = "nfc"  # One of: nfc, nfd
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize)]
 pub struct Config {
     /// Log configuration section
     #[serde(default)]
     pub log: LogConfig,
+    /// Rule toggles section
+    #[serde(default)]
+    pub rules: RulesConfig,
+    /// Rule severities section
+    #[serde(default)]
+    pub severity: SeverityConfig,
+    /// Output section
+    #[serde(default)]
+    pub output: OutputConfig,
+    /// Exit status section
+    #[serde(default)]
+    pub behavior: BehaviorConfig,
+    /// Characters that are never reported
+    #[serde(default, deserialize_with = "deserialize_char_set")]
+    pub allow: HashSet<char>,
+    /// Additional characters to report
+    #[serde(default, deserialize_with = "deserialize_char_set")]
+    pub deny: HashSet<char>,
+    /// Replacements that take precedence over the built-in suggestions
+    #[serde(default, deserialize_with = "deserialize_suggestions")]
+    pub suggestions: HashMap<char, String>,
+    /// Unit in which reported columns are counted (None = use default)
+    #[serde(default)]
+    pub column: Option<ColumnMode>,
+    /// Share of non-text bytes above which an input is skipped as binary
+    /// (None = use default)
+    #[serde(default)]
+    pub binary_threshold: Option<BinaryThreshold>,
+    /// Normalization form text must be in (None = not checked)
+    #[serde(default)]
+    pub normalize: Option<NormalizationForm>,
     /// When false, stops the config file search at this file
     #[serde(default = "default_inherit")]
     pub inherit: bool,
@@ -70,6 +430,16 @@ impl Default for Config {
     fn default() -> Self {
         Config {
             log: LogConfig::default(),
+            rules: RulesConfig::default(),
+            severity: SeverityConfig::default(),
+            output: OutputConfig::default(),
+            behavior: BehaviorConfig::default(),
+            allow: HashSet::new(),
+            deny: HashSet::new(),
+            suggestions: HashMap::new(),
+            column: None,
+            binary_threshold: None,
+            normalize: None,
             inherit: true,
         }
     }
@@ -79,6 +449,9 @@ impl Config {
     /// Merge two configurations with field-level precedence
     ///
     /// For Option fields, `self` takes precedence if it's Some, otherwise `other`.
+    /// The `allow` and `deny` sets are combined, so a child extends its parent's
+    /// lists, and a child's suggestions override its parent's for the same
+    /// character.
     ///
     /// # Arguments
     ///
@@ -92,18 +465,64 @@ impl Config {
             log: LogConfig {
                 level: self.log.level.or(other.log.level),
             },
+            rules: self.rules.or(other.rules),
+            severity: self.severity.or(other.severity),
+            output: OutputConfig {
+                format: self.output.format.or(other.output.format),
+            },
+            behavior: BehaviorConfig {
+                violations_exit_code: self
+                    .behavior
+                    .violations_exit_code
+                    .or(other.behavior.violations_exit_code),
+            },
+            allow: self.allow.union(&other.allow).copied().collect(),
+            deny: self.deny.union(&other.deny).copied().collect(),
+            suggestions: other
+                .suggestions
+                .into_iter()
+                .chain(self.suggestions)
+                .collect(),
+            column: self.column.or(other.column),
+            binary_threshold: self.binary_threshold.or(other.binary_threshold),
+            normalize: self.normalize.or(other.normalize),
             // inherit indicates whether search continued, so preserve it from fallback
             inherit: other.inherit,
         }
     }
 
+    /// Build a [`Checker`] with this configuration's rule toggles, severities,
+    /// allowlist, deny list, and suggestions applied
+    ///
+    /// A character that is both allowed and denied is denied, with a warning.
+    pub fn checker(&self) -> Checker {
+        for ch in self.allow.intersection(&self.deny) {
+            log::warn!(
+                "{} is both allowed and denied in configuration; denying it",
+                DisplayChar(*ch)
+            );
+        }
+        let mut checker = self.rules.checker();
+        if let Some(form) = self.normalize {
+            checker = checker.with_normalization(form);
+        }
+        self.severity
+            .apply(checker)
+            .with_allowed(self.allow.difference(&self.deny).copied())
+            .with_denied(self.deny.iter().copied())
+            .with_suggestions(self.suggestions.clone())
+            .with_column_mode(self.column.unwrap_or_default())
+            .with_binary_threshold(self.binary_threshold.unwrap_or_default())
+    }
+
     /// Load configuration from the current working directory
     ///
     /// Searches for .noemoji.toml files starting from the current directory and
     /// continuing up parent directories. Merges configurations from general to
     /// specific (parent to child), where child configurations override parent
     /// values. If any configuration sets inherit = false, stops scanning parent
-    /// directories.
+    /// directories. The user's global configuration and the file named by
+    /// `NOEMOJI_CONFIG` are not read; see [`ConfigSource`].
     ///
     /// # Returns
     ///
@@ -137,10 +556,14 @@ impl Config {
     /// Load configuration from a specific directory
     ///
     /// Searches for .noemoji.toml files starting from the given directory and
-    /// continuing up parent directories. Merges configurations from general to
-    /// specific (parent to child), where child configurations override parent
-    /// values. If any configuration sets inherit = false, stops scanning parent
-    /// directories.
+    /// continuing up parent directories. A directory without one may instead
+    /// configure noemoji in a `[tool.noemoji]` table of its `pyproject.toml`,
+    /// which is ignored whenever a `.noemoji.toml` sits beside it. Merges
+    /// configurations from general to specific (parent to child), where child
+    /// configurations override parent values. If any configuration sets
+    /// inherit = false, stops scanning parent directories. Only files in
+    /// `start_dir` and its parents are read, so the user's global
+    /// configuration has no effect; see [`Config::load_from_with_global`].
     ///
     /// # Arguments
     ///
@@ -180,48 +603,518 @@ impl Config {
     ///
     /// Calling `Config::load_from("/home/user/project")` returns a config with
     /// `log.level = Some(Debug)` because child configs override parent values.
-    pub fn load_from<P: AsRef<std::path::Path>>(start_dir: P) -> Result<Config, ConfigError> {
+    pub fn load_from<P: AsRef<Path>>(start_dir: P) -> Result<Config, ConfigError> {
+        Self::load_from_with(start_dir, |_| {})
+    }
+
+    /// Load configuration from exactly one file, without searching directories
+    ///
+    /// The file's `inherit` setting has no effect, since nothing else is
+    /// merged. Unlike discovery, a missing file is an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use noemoji::config::Config;
+    /// # use noemoji::logging::LogLevel;
+    /// # use tempfile::TempDir;
+    /// # use std::fs;
+    /// let temp_dir = TempDir::new().unwrap();
+    /// let path = temp_dir.path().join("ci.toml");
+    /// fs::write(&path, "[log]\nlevel = \"info\"\n").unwrap();
+    ///
+    /// let config = Config::load_file(&path).unwrap();
+    /// assert_eq!(config.log.level, Some(LogLevel::Info));
+    /// assert!(Config::load_file(temp_dir.path().join("missing.toml")).is_err());
+    /// ```
+    pub fn load_file<P: AsRef<Path>>(path: P) -> Result<Config, ConfigError> {
+        parse_config(&fs::read_to_string(path)?)
+    }
+
+    /// Load configuration from a specific directory, along with the files
+    /// that contributed to it
+    ///
+    /// Behaves like [`Config::load_from`], additionally returning the paths of
+    /// the merged configuration files, closest first. The list is empty when no
+    /// configuration file was found.
+    pub fn load_from_with_sources<P: AsRef<Path>>(
+        start_dir: P,
+    ) -> Result<(Config, Vec<PathBuf>), ConfigError> {
+        let mut sources = Vec::new();
+        let config = Self::load_from_with(start_dir, |path| sources.push(path.to_path_buf()))?;
+        Ok((config, sources))
+    }
+
+    /// Load configuration from a specific directory, reporting each file used
+    ///
+    /// Behaves like [`Config::load_from`], but calls `on_file` with the path
+    /// of each configuration file found, closest first, before it is read and
+    /// parsed. When an error is returned, the last path reported is the file
+    /// that caused it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use noemoji::config::Config;
+    /// # use tempfile::TempDir;
+    /// # use std::fs;
+    /// let temp_dir = TempDir::new().unwrap();
+    /// fs::write(temp_dir.path().join(".noemoji.toml"), "inherit = false\n").unwrap();
+    ///
+    /// let mut paths = Vec::new();
+    /// Config::load_from_with(temp_dir.path(), |path| paths.push(path.to_path_buf())).unwrap();
+    /// assert_eq!(paths, vec![temp_dir.path().join(".noemoji.toml")]);
+    /// ```
+    pub fn load_from_with<P, F>(start_dir: P, on_file: F) -> Result<Config, ConfigError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path),
+    {
+        Self::load_from_with_global(start_dir, None, on_file)
+    }
+
+    /// Load configuration from a specific directory, then merge in a global
+    /// configuration file below every discovered one
+    ///
+    /// Behaves like [`Config::load_from_with`], but when no configuration
+    /// sets `inherit = false`, the file at `global` is merged in last, if it
+    /// exists. The command line passes [`global_config_path`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use noemoji::config::Config;
+    /// # use noemoji::logging::LogLevel;
+    /// # use tempfile::TempDir;
+    /// # use std::fs;
+    /// let project_dir = TempDir::new().unwrap();
+    /// let global_dir = TempDir::new().unwrap();
+    /// let global = global_dir.path().join("config.toml");
+    /// fs::write(&global, "[log]\nlevel = \"info\"\n").unwrap();
+    ///
+    /// let config = Config::load_from_with_global(project_dir.path(), Some(&global), |_| {});
+    /// assert_eq!(config.unwrap().log.level, Some(LogLevel::Info));
+    /// ```
+    pub fn load_from_with_global<P, F>(
+        start_dir: P,
+        global: Option<&Path>,
+        mut on_file: F,
+    ) -> Result<Config, ConfigError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(&Path),
+    {
         let mut current_dir = Some(start_dir.as_ref().to_path_buf());
         let mut result = Config::default();
 
         while let Some(dir) = current_dir {
-            let config_path = dir.join(".noemoji.toml");
+            let config = match read_config_file(&dir.join(CONFIG_FILE), &mut on_file)? {
+                Some(config) => Some(config),
+                None => read_shared_config_file(&dir.join(SHARED_CONFIG_FILE), &mut on_file)?,
+            };
 
-            // Attempt to read the file directly, handling NotFound gracefully
-            match fs::read_to_string(&config_path) {
-                Ok(content) => {
-                    let config = parse_config(&content)?;
+            if let Some(config) = config {
+                let inherit = config.inherit;
 
-                    // Merge: child configs override parent configs
-                    // result.or(config) means result (child) takes precedence, config (parent) is fallback
-                    result = result.or(config);
+                // Merge: child configs override parent configs
+                // result.or(config) means result (child) takes precedence, config (parent) is fallback
+                result = result.or(config);
 
-                    // If this config has inherit = false, stop scanning for parent configs
-                    if !config.inherit {
-                        break;
-                    }
-                }
-                Err(e) if e.kind() == io::ErrorKind::NotFound => {
-                    // File doesn't exist, continue to parent directory
-                }
-                Err(e) => {
-                    // Other I/O error (permission denied, etc.)
-                    return Err(ConfigError::IoError(e));
+                // If this config has inherit = false, stop scanning for parent
+                // configs, including the global one
+                if !inherit {
+                    return Ok(result);
                 }
             }
 
             current_dir = dir.parent().map(|p| p.to_path_buf());
         }
 
+        // The user's global config sits below every project config
+        if let Some(global_path) = global
+            && let Some(config) = read_config_file(global_path, &mut on_file)?
+        {
+            result = result.or(config);
+        }
+
         Ok(result)
     }
 }
 
+/// Where configuration is loaded from
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// Discover `.noemoji.toml` files from the working directory upward, then
+    /// the global configuration file
+    #[default]
+    Discover,
+    /// Load exactly this file, skipping discovery
+    File(PathBuf),
+    /// Load this file above every discovered one, as the command line does
+    /// for the file named by `NOEMOJI_CONFIG`
+    ///
+    /// This serves environments such as containers where no `.noemoji.toml`
+    /// can be added to the tree. Unlike discovered files, it must exist;
+    /// setting `inherit = false` in it skips discovery entirely.
+    Overlay(PathBuf),
+    /// Use built-in defaults, ignoring every configuration file
+    Defaults,
+}
+
+impl ConfigSource {
+    /// Load configuration from this source, with `global` as the global
+    /// configuration file for discovery
+    ///
+    /// Calls `on_file` with the path of each configuration file used, as
+    /// [`Config::load_from_with`] does.
+    pub fn load_with<F>(&self, global: Option<&Path>, mut on_file: F) -> Result<Config, ConfigError>
+    where
+        F: FnMut(&Path),
+    {
+        match self {
+            Self::Discover => Config::load_from_with_global(env::current_dir()?, global, on_file),
+            Self::File(path) => {
+                on_file(path);
+                Config::load_file(path)
+            }
+            Self::Overlay(path) => {
+                on_file(path);
+                let config = Config::load_file(path)?;
+                if !config.inherit {
+                    return Ok(config);
+                }
+                let discovered =
+                    Config::load_from_with_global(env::current_dir()?, global, on_file)?;
+                Ok(config.or(discovered))
+            }
+            Self::Defaults => Ok(Config::default()),
+        }
+    }
+}
+
+/// Name of the configuration file searched for in each directory
+pub const CONFIG_FILE: &str = ".noemoji.toml";
+
+/// Name of the shared file whose `[tool.noemoji]` table is used in a directory
+/// without a [`CONFIG_FILE`]
+pub const SHARED_CONFIG_FILE: &str = "pyproject.toml";
+
+/// Commented `.noemoji.toml` written by `--init`
+///
+/// Every setting is listed with its default but commented out, so the file
+/// changes nothing until edited and doubles as documentation.
+pub const TEMPLATE: &str = r#"# Configuration for noemoji, which reports Unicode characters that should
+# be written in ASCII. Settings are commented out at their defaults; remove
+# the leading '#' to change one. Files in parent directories are merged
+# below this one unless inherit is false.
+
+# Stop searching parent directories and the global config at this file
+# inherit = true
+
+# Characters never reported, as literals or in U+XXXX notation
+# allow = ["≤", "U+2265"]
+
+# Characters always reported, even where permitted by default
+# deny = ["·"]
+
+# Unit for reported columns: char, grapheme, or byte
+# column = "char"
+
+# Share of control bytes above which a file is skipped as binary, from 0.0
+# to 1.0; 1.0 never skips
+# binary_threshold = 0.1
+
+# Report text not in this Unicode normalization form, nfc or nfd; unset
+# by default
+# normalize = "nfc"
+
+[log]
+# One of: disabled, error, warn, info, debug, trace
+# level = "disabled"
+
+[output]
+# Report format when --format is not given: text, json, csv, or editor
+# format = "text"
+
+[behavior]
+# Exit status when violations are found: 0 or 1
+# violations_exit_code = 1
+
+[rules]
+# Set a rule to false to stop reporting it, or true to enable an opt-in rule
+# emoji = true
+# arrows = true
+# checkmarks = true
+# box_drawing = true
+# math = true
+# supersub = true
+# fractions = true
+# decorative = true
+# punctuation = true
+# ligatures = true
+# whitespace = true
+# final_newline = false
+# byte_order_mark = false
+# confusables = false
+# non_ascii = false
+
+[suggestions]
+# Replacements used in reports and by --fix instead of the built-in ones,
+# keyed by literal or U+XXXX
+# "↑" = "up"
+
+[severity]
+# Errors fail the run; warnings are only reported
+# emoji = "error"
+# arrows = "error"
+# checkmarks = "error"
+# box_drawing = "error"
+# math = "error"
+# supersub = "error"
+# fractions = "error"
+# decorative = "error"
+# punctuation = "error"
+# ligatures = "error"
+# whitespace = "error"
+# deny = "error"
+# final_newline = "error"
+# byte_order_mark = "error"
+# confusables = "error"
+# normalization = "error"
+# non_ascii = "error"
+"#;
+
+/// Environment variable naming a configuration file that takes precedence
+/// over discovered ones
+pub const CONFIG_ENV: &str = "NOEMOJI_CONFIG";
+
+/// Environment variable that overrides the directory holding the global
+/// configuration file
+pub const CONFIG_DIR_ENV: &str = "NOEMOJI_CONFIG_DIR";
+
+/// Returns the path of the user's global configuration file
+///
+/// This is `config.toml` in the directory named by `NOEMOJI_CONFIG_DIR` if
+/// set, or else in the platform's configuration directory: for example
+/// `$XDG_CONFIG_HOME/noemoji/config.toml` on Linux. Returns `None` if no
+/// home directory can be determined.
+pub fn global_config_path() -> Option<PathBuf> {
+    let dir = match env::var_os(CONFIG_DIR_ENV) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => ProjectDirs::from("", "", "noemoji")?
+            .config_dir()
+            .to_path_buf(),
+    };
+    Some(dir.join("config.toml"))
+}
+
+/// Read and parse the configuration file at `path`, if it exists
+///
+/// Calls `on_file` with `path` before parsing, or before returning an I/O
+/// error other than the file not existing.
+fn read_config_file<F>(path: &Path, on_file: &mut F) -> Result<Option<Config>, ConfigError>
+where
+    F: FnMut(&Path),
+{
+    // Attempt to read the file directly, handling NotFound gracefully
+    match fs::read_to_string(path) {
+        Ok(content) => {
+            on_file(path);
+            parse_config(&content).map(Some)
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => {
+            // Other I/O error (permission denied, etc.)
+            on_file(path);
+            Err(ConfigError::IoError(e))
+        }
+    }
+}
+
+/// Read the `[tool.noemoji]` table of the shared file at `path`, if both exist
+///
+/// A file without the table belongs to other tools and is skipped without
+/// calling `on_file`; otherwise behaves like [`read_config_file`].
+fn read_shared_config_file<F>(path: &Path, on_file: &mut F) -> Result<Option<Config>, ConfigError>
+where
+    F: FnMut(&Path),
+{
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            on_file(path);
+            return Err(ConfigError::IoError(e));
+        }
+    };
+    let Some(table) = parse_shared_table(&content) else {
+        return Ok(None);
+    };
+    on_file(path);
+    table.try_into().map(Some).map_err(ConfigError::InvalidToml)
+}
+
+/// Extract the `[tool.noemoji]` table from a shared configuration file
+///
+/// Returns `None` if the file is not valid TOML or has no such table, since
+/// the file is owned by other tools.
+fn parse_shared_table(toml_str: &str) -> Option<toml::Value> {
+    let mut document = toml_str.parse::<toml::Table>().ok()?;
+    match document.remove("tool")? {
+        toml::Value::Table(mut tool) => tool.remove("noemoji"),
+        _ => None,
+    }
+}
+
+/// Renders the effective settings as a `.noemoji.toml` file
+///
+/// Unset values are shown with their defaults, and every rule is listed, so
+/// the output describes exactly how inputs will be checked.
+impl fmt::Display for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let chars = |set: &HashSet<char>| {
+            let mut chars: Vec<char> = set.iter().copied().collect();
+            chars.sort_unstable();
+            chars
+                .iter()
+                .map(|&ch| format!("\"U+{:04X}\"", ch as u32))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        writeln!(f, "allow = [{}]", chars(&self.allow))?;
+        writeln!(f, "deny = [{}]", chars(&self.deny))?;
+        writeln!(f, "column = \"{}\"", self.column.unwrap_or_default().name())?;
+        writeln!(
+            f,
+            "binary_threshold = {}",
+            self.binary_threshold.unwrap_or_default()
+        )?;
+        if let Some(form) = self.normalize {
+            writeln!(f, "normalize = \"{}\"", form)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "[log]")?;
+        writeln!(
+            f,
+            "level = \"{}\"",
+            self.log.level.unwrap_or_default().name()
+        )?;
+        writeln!(f)?;
+        writeln!(f, "[output]")?;
+        writeln!(
+            f,
+            "format = \"{}\"",
+            self.output.format.unwrap_or_default().name()
+        )?;
+        writeln!(f)?;
+        writeln!(f, "[behavior]")?;
+        writeln!(
+            f,
+            "violations_exit_code = {}",
+            self.behavior.violations_exit_code.unwrap_or(1)
+        )?;
+        writeln!(f)?;
+        writeln!(f, "[rules]")?;
+        let checker = self.rules.checker();
+        for &category in CharCategory::ALL {
+            // Custom characters and normalization are configured by the
+            // `deny` list and `normalize` setting above
+            if !matches!(category, CharCategory::Custom | CharCategory::Normalization) {
+                writeln!(f, "{} = {}", category.key(), checker.is_enabled(category))?;
+            }
+        }
+        writeln!(f)?;
+        writeln!(f, "[severity]")?;
+        for &category in CharCategory::ALL {
+            let severity = self.severity.get(category).unwrap_or_default();
+            writeln!(f, "{} = \"{}\"", category.key(), severity)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "[suggestions]")?;
+        let mut suggestions: Vec<_> = self.suggestions.iter().collect();
+        suggestions.sort_unstable();
+        for (ch, replacement) in suggestions {
+            let replacement = toml::Value::String(replacement.clone());
+            writeln!(f, "\"U+{:04X}\" = {}", *ch as u32, replacement)?;
+        }
+        Ok(())
+    }
+}
+
 /// Parse a TOML configuration string into a Config struct
 fn parse_config(toml_str: &str) -> Result<Config, ConfigError> {
     toml::from_str::<Config>(toml_str).map_err(ConfigError::InvalidToml)
 }
 
+/// Parse a character given literally (`"≤"`) or in `U+XXXX` notation
+pub(crate) fn parse_char(entry: &str) -> Result<char, String> {
+    if let Some(hex) = entry
+        .strip_prefix("U+")
+        .or_else(|| entry.strip_prefix("u+"))
+    {
+        return u32::from_str_radix(hex, 16)
+            .ok()
+            .filter(|_| !hex.is_empty() && hex.len() <= 6)
+            .and_then(char::from_u32)
+            .ok_or_else(|| format!("invalid code point '{}'", entry));
+    }
+
+    let mut chars = entry.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => Ok(ch),
+        _ => Err(format!(
+            "invalid character '{}': expected a single character or U+XXXX",
+            entry
+        )),
+    }
+}
+
+/// Deserialize a list of [`parse_char`] entries into a set
+fn deserialize_char_set<'de, D>(deserializer: D) -> Result<HashSet<char>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|entry| parse_char(entry).map_err(de::Error::custom))
+        .collect()
+}
+
+/// Deserialize a table of suggestions keyed by [`parse_char`] entries,
+/// rejecting empty replacements
+fn deserialize_suggestions<'de, D>(deserializer: D) -> Result<HashMap<char, String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    HashMap::<String, String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(entry, replacement)| {
+            let ch = parse_char(&entry).map_err(de::Error::custom)?;
+            if replacement.is_empty() {
+                return Err(de::Error::custom(format!(
+                    "empty suggestion for '{}'",
+                    entry
+                )));
+            }
+            Ok((ch, replacement))
+        })
+        .collect()
+}
+
+/// Deserialize a `violations_exit_code`, which must be 0 or 1
+fn deserialize_exit_code<'de, D>(deserializer: D) -> Result<Option<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match u8::deserialize(deserializer)? {
+        code @ (0 | 1) => Ok(Some(code)),
+        code => Err(de::Error::custom(format!(
+            "invalid violations_exit_code {}, expected 0 or 1",
+            code
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -248,6 +1141,290 @@ mod tests {
         assert!(config.inherit);
     }
 
+    #[test]
+    fn rules_config_default_is_all_unset() {
+        let rules = RulesConfig::default();
+        for &category in CharCategory::ALL {
+            assert_eq!(rules.get(category), None);
+        }
+    }
+
+    #[test]
+    fn rules_config_checker_applies_toggles() {
+        let rules = RulesConfig {
+            punctuation: Some(false),
+            arrows: Some(true),
+            ..RulesConfig::default()
+        };
+        let checker = rules.checker();
+        assert!(!checker.is_enabled(CharCategory::Punctuation));
+        assert!(checker.is_enabled(CharCategory::Arrow));
+        assert!(checker.is_enabled(CharCategory::Emoji));
+        assert!(!checker.is_enabled(CharCategory::MissingFinalNewline));
+    }
+
+    #[test]
+    fn rules_config_enables_final_newline() {
+        let config = parse_config("[rules]\nfinal_newline = true\n").unwrap();
+        assert_eq!(config.rules.final_newline, Some(true));
+        assert!(
+            config
+                .checker()
+                .is_enabled(CharCategory::MissingFinalNewline)
+        );
+    }
+
+    #[test]
+    fn template_parses_to_defaults() {
+        assert_eq!(parse_config(TEMPLATE).unwrap(), Config::default());
+    }
+
+    #[test]
+    fn template_settings_are_valid_defaults() {
+        // Uncomment every setting, leaving prose comments alone
+        let uncommented: String = TEMPLATE
+            .lines()
+            .map(|line| match line.strip_prefix("# ") {
+                Some(setting) if setting.contains(" = ") => format!("{}\n", setting),
+                _ => format!("{}\n", line),
+            })
+            .collect();
+        let config = parse_config(&uncommented).unwrap();
+        assert_eq!(
+            config.checker(),
+            Config::default()
+                .checker()
+                .with_allowed(['≤', '≥'])
+                .with_denied(['·'])
+                .with_suggestions([('↑', "up".to_owned())])
+                .with_normalization(NormalizationForm::Nfc)
+        );
+        assert_eq!(config.log.level, Some(LogLevel::Disabled));
+        assert_eq!(config.output.format, Some(OutputFormat::Text));
+        assert_eq!(config.behavior.violations_exit_code, Some(1));
+    }
+
+    #[test]
+    fn rules_config_enables_byte_order_mark() {
+        let config = parse_config("[rules]\nbyte_order_mark = true\n").unwrap();
+        assert!(config.checker().is_enabled(CharCategory::ByteOrderMark));
+        assert!(
+            !Config::default()
+                .checker()
+                .is_enabled(CharCategory::ByteOrderMark)
+        );
+    }
+
+    #[test]
+    fn output_format_parses_and_merges() {
+        let child = parse_config("[output]\nformat = \"json\"\n").unwrap();
+        let parent = parse_config("[output]\nformat = \"text\"\n").unwrap();
+        assert_eq!(child.output.format, Some(OutputFormat::Json));
+        assert_eq!(
+            Config::default().or(parent.clone()).output.format,
+            Some(OutputFormat::Text)
+        );
+        assert_eq!(child.or(parent).output.format, Some(OutputFormat::Json));
+        assert!(parse_config("[output]\nformat = \"xml\"\n").is_err());
+    }
+
+    #[test]
+    fn violations_exit_code_accepts_only_zero_or_one() {
+        let config = parse_config("[behavior]\nviolations_exit_code = 0\n").unwrap();
+        assert_eq!(config.behavior.violations_exit_code, Some(0));
+        assert_eq!(
+            Config::default().or(config).behavior.violations_exit_code,
+            Some(0)
+        );
+        assert!(parse_config("[behavior]\nviolations_exit_code = 2\n").is_err());
+    }
+
+    #[test]
+    fn parse_shared_table_finds_tool_section() {
+        let table = parse_shared_table(
+            "[project]\nname = \"demo\"\n\n[tool.noemoji.log]\nlevel = \"debug\"\n",
+        )
+        .unwrap();
+        let config: Config = table.try_into().unwrap();
+        assert_eq!(config.log.level, Some(LogLevel::Debug));
+
+        assert_eq!(parse_shared_table("[tool.black]\nline-length = 88\n"), None);
+        assert_eq!(parse_shared_table("tool = 1\n"), None);
+        assert_eq!(parse_shared_table("not toml ["), None);
+    }
+
+    #[test]
+    fn parse_config_rules_section() {
+        let toml_str = r#"
+[rules]
+punctuation = false
+box_drawing = true
+"#;
+
+        let config = parse_config(toml_str).unwrap();
+        assert_eq!(config.rules.punctuation, Some(false));
+        assert_eq!(config.rules.box_drawing, Some(true));
+        assert_eq!(config.rules.emoji, None);
+    }
+
+    #[test]
+    fn parse_config_severity_section() {
+        let config =
+            parse_config("[severity]\npunctuation = \"warning\"\ndeny = \"error\"\n").unwrap();
+        assert_eq!(config.severity.punctuation, Some(Severity::Warning));
+        assert_eq!(config.severity.deny, Some(Severity::Error));
+        assert_eq!(config.severity.emoji, None);
+
+        let checker = config.checker();
+        assert_eq!(
+            checker.severity(CharCategory::Punctuation),
+            Severity::Warning
+        );
+        assert_eq!(checker.severity(CharCategory::Arrow), Severity::Error);
+
+        assert!(parse_config("[severity]\nemoji = \"fatal\"\n").is_err());
+    }
+
+    #[test]
+    fn severity_config_merges_child_first() {
+        let child = SeverityConfig {
+            emoji: Some(Severity::Warning),
+            ..SeverityConfig::default()
+        };
+        let parent = SeverityConfig {
+            emoji: Some(Severity::Error),
+            math: Some(Severity::Warning),
+            ..SeverityConfig::default()
+        };
+        let merged = child.or(parent);
+        assert_eq!(merged.emoji, Some(Severity::Warning));
+        assert_eq!(merged.math, Some(Severity::Warning));
+    }
+
+    #[test]
+    fn parse_char_accepts_literal_and_code_point() {
+        assert_eq!(parse_char("≤"), Ok('≤'));
+        assert_eq!(parse_char("U+2265"), Ok('≥'));
+        assert_eq!(parse_char("u+a0"), Ok('\u{A0}'));
+    }
+
+    #[test]
+    fn parse_char_rejects_malformed_entries() {
+        assert!(parse_char("U+ZZZZ").unwrap_err().contains("U+ZZZZ"));
+        assert!(parse_char("U+").is_err());
+        assert!(parse_char("U+D800").is_err());
+        assert!(parse_char("").is_err());
+        assert!(parse_char("ab").is_err());
+    }
+
+    #[test]
+    fn parse_config_allow_list() {
+        let config = parse_config(r#"allow = ["≤", "U+2265"]"#).unwrap();
+        assert_eq!(config.allow, HashSet::from(['≤', '≥']));
+    }
+
+    #[test]
+    fn parse_config_allow_list_reports_bad_entry() {
+        let err = parse_config(r#"allow = ["U+ZZZZ"]"#).unwrap_err();
+        assert!(err.to_string().contains("invalid code point 'U+ZZZZ'"));
+    }
+
+    #[test]
+    fn parse_config_deny_list() {
+        let config = parse_config(r#"deny = ["·", "U+00B7", "U+2022"]"#).unwrap();
+        assert_eq!(config.deny, HashSet::from(['·', '•']));
+    }
+
+    #[test]
+    fn config_checker_prefers_deny_over_allow() {
+        let config = Config {
+            allow: HashSet::from(['·', '≤']),
+            deny: HashSet::from(['·']),
+            ..Config::default()
+        };
+        let checker = config.checker();
+        assert!(checker.is_denied('·'));
+        assert!(!checker.is_allowed('·'));
+        assert!(checker.is_allowed('≤'));
+    }
+
+    #[test]
+    fn parse_config_suggestions() {
+        let config =
+            parse_config("[suggestions]\n\"↑\" = \"up\"\n\"U+2193\" = \"down\"\n").unwrap();
+        assert_eq!(config.suggestions.get(&'↑').map(String::as_str), Some("up"));
+        assert_eq!(
+            config.suggestions.get(&'↓').map(String::as_str),
+            Some("down")
+        );
+        assert_eq!(config.checker().suggestion('→').as_deref(), Some("->"));
+
+        let err = parse_config("[suggestions]\n\"↑\" = \"\"\n").unwrap_err();
+        assert!(
+            err.to_string().contains("empty suggestion for '↑'"),
+            "{err}"
+        );
+        assert!(parse_config("[suggestions]\n\"ab\" = \"x\"\n").is_err());
+    }
+
+    #[test]
+    fn config_or_prefers_child_suggestions() {
+        let child = parse_config("[suggestions]\n\"↑\" = \"up\"\n").unwrap();
+        let parent = parse_config("[suggestions]\n\"↑\" = \"^\"\n\"↓\" = \"down\"\n").unwrap();
+        let merged = child.or(parent);
+        assert_eq!(merged.suggestions.get(&'↑').map(String::as_str), Some("up"));
+        assert_eq!(
+            merged.suggestions.get(&'↓').map(String::as_str),
+            Some("down")
+        );
+    }
+
+    #[test]
+    fn config_or_extends_allow_list() {
+        let child = Config {
+            allow: HashSet::from(['≤']),
+            ..Config::default()
+        };
+        let parent = Config {
+            allow: HashSet::from(['≥']),
+            ..Config::default()
+        };
+        assert_eq!(child.or(parent).allow, HashSet::from(['≤', '≥']));
+    }
+
+    #[test]
+    fn parse_config_column_mode() {
+        let config = parse_config(r#"column = "grapheme""#).unwrap();
+        assert_eq!(config.column, Some(ColumnMode::Grapheme));
+        assert!(parse_config(r#"column = "glyph""#).is_err());
+    }
+
+    #[test]
+    fn parse_config_normalize() {
+        let config = parse_config("normalize = \"nfd\"\n").unwrap();
+        assert_eq!(config.normalize, Some(NormalizationForm::Nfd));
+        assert!(config.checker().is_enabled(CharCategory::Normalization));
+        assert!(
+            !Config::default()
+                .checker()
+                .is_enabled(CharCategory::Normalization)
+        );
+        assert!(parse_config("normalize = \"nfkc\"\n").is_err());
+    }
+
+    #[test]
+    fn parse_config_binary_threshold() {
+        let config = parse_config("binary_threshold = 0.25\n").unwrap();
+        assert_eq!(config.binary_threshold, BinaryThreshold::new(0.25));
+        assert_eq!(
+            parse_config("binary_threshold = 1\n")
+                .unwrap()
+                .binary_threshold,
+            BinaryThreshold::new(1.0)
+        );
+        assert!(parse_config("binary_threshold = 2.0\n").is_err());
+    }
+
     #[test]
     fn parse_config_with_inherit_false() {
         let toml_str = r#"
@@ -0,0 +1,196 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Letters and digits that are confusable with ASCII
+//!
+//! Generated text sometimes slips a full-width `Ａ` or a Cyrillic `а` into an
+//! otherwise English identifier, where it looks right but no longer matches
+//! when searched for or compiled. These characters are letters, so they pass
+//! as international text; the opt-in `confusables` rule reports them only
+//! where they stand in for ASCII, leaving runs of genuine Cyrillic or Greek
+//! text alone.
+
+/// Non-ASCII letters that render like an ASCII letter, sorted by code point
+///
+/// A subset of the Unicode confusables data (UTS #39) limited to Greek and
+/// Cyrillic letters that are visually identical to ASCII in common fonts.
+/// Full-width forms are mapped arithmetically by [`confusable`].
+const CONFUSABLES: &[(char, char)] = &[
+    ('\u{0391}', 'A'), // GREEK CAPITAL LETTER ALPHA
+    ('\u{0392}', 'B'), // GREEK CAPITAL LETTER BETA
+    ('\u{0395}', 'E'), // GREEK CAPITAL LETTER EPSILON
+    ('\u{0396}', 'Z'), // GREEK CAPITAL LETTER ZETA
+    ('\u{0397}', 'H'), // GREEK CAPITAL LETTER ETA
+    ('\u{0399}', 'I'), // GREEK CAPITAL LETTER IOTA
+    ('\u{039A}', 'K'), // GREEK CAPITAL LETTER KAPPA
+    ('\u{039C}', 'M'), // GREEK CAPITAL LETTER MU
+    ('\u{039D}', 'N'), // GREEK CAPITAL LETTER NU
+    ('\u{039F}', 'O'), // GREEK CAPITAL LETTER OMICRON
+    ('\u{03A1}', 'P'), // GREEK CAPITAL LETTER RHO
+    ('\u{03A4}', 'T'), // GREEK CAPITAL LETTER TAU
+    ('\u{03A5}', 'Y'), // GREEK CAPITAL LETTER UPSILON
+    ('\u{03A7}', 'X'), // GREEK CAPITAL LETTER CHI
+    ('\u{03BF}', 'o'), // GREEK SMALL LETTER OMICRON
+    ('\u{0405}', 'S'), // CYRILLIC CAPITAL LETTER DZE
+    ('\u{0406}', 'I'), // CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0408}', 'J'), // CYRILLIC CAPITAL LETTER JE
+    ('\u{0410}', 'A'), // CYRILLIC CAPITAL LETTER A
+    ('\u{0412}', 'B'), // CYRILLIC CAPITAL LETTER VE
+    ('\u{0415}', 'E'), // CYRILLIC CAPITAL LETTER IE
+    ('\u{041A}', 'K'), // CYRILLIC CAPITAL LETTER KA
+    ('\u{041C}', 'M'), // CYRILLIC CAPITAL LETTER EM
+    ('\u{041D}', 'H'), // CYRILLIC CAPITAL LETTER EN
+    ('\u{041E}', 'O'), // CYRILLIC CAPITAL LETTER O
+    ('\u{0420}', 'P'), // CYRILLIC CAPITAL LETTER ER
+    ('\u{0421}', 'C'), // CYRILLIC CAPITAL LETTER ES
+    ('\u{0422}', 'T'), // CYRILLIC CAPITAL LETTER TE
+    ('\u{0425}', 'X'), // CYRILLIC CAPITAL LETTER HA
+    ('\u{0430}', 'a'), // CYRILLIC SMALL LETTER A
+    ('\u{0435}', 'e'), // CYRILLIC SMALL LETTER IE
+    ('\u{043E}', 'o'), // CYRILLIC SMALL LETTER O
+    ('\u{0440}', 'p'), // CYRILLIC SMALL LETTER ER
+    ('\u{0441}', 'c'), // CYRILLIC SMALL LETTER ES
+    ('\u{0443}', 'y'), // CYRILLIC SMALL LETTER U
+    ('\u{0445}', 'x'), // CYRILLIC SMALL LETTER HA
+    ('\u{0455}', 's'), // CYRILLIC SMALL LETTER DZE
+    ('\u{0456}', 'i'), // CYRILLIC SMALL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0458}', 'j'), // CYRILLIC SMALL LETTER JE
+    ('\u{04AE}', 'Y'), // CYRILLIC CAPITAL LETTER STRAIGHT U
+    ('\u{04BB}', 'h'), // CYRILLIC SMALL LETTER SHHA
+    ('\u{04CF}', 'l'), // CYRILLIC SMALL LETTER PALOCHKA
+    ('\u{0501}', 'd'), // CYRILLIC SMALL LETTER KOMI DE
+    ('\u{051B}', 'q'), // CYRILLIC SMALL LETTER QA
+    ('\u{051D}', 'w'), // CYRILLIC SMALL LETTER WE
+];
+
+/// Offset from a full-width form to its ASCII character
+const FULL_WIDTH_OFFSET: u32 = 0xFEE0;
+
+/// Returns the ASCII letter or digit that `ch` is confusable with, if any
+///
+/// # Examples
+///
+/// ```
+/// use noemoji::confusables::confusable;
+///
+/// assert_eq!(confusable('Ａ'), Some('A'));
+/// assert_eq!(confusable('\u{0430}'), Some('a')); // Cyrillic
+/// assert_eq!(confusable('ж'), None);
+/// assert_eq!(confusable('a'), None);
+/// ```
+pub fn confusable(ch: char) -> Option<char> {
+    if matches!(ch, '０'..='９' | 'Ａ'..='Ｚ' | 'ａ'..='ｚ') {
+        return char::from_u32(ch as u32 - FULL_WIDTH_OFFSET);
+    }
+    CONFUSABLES
+        .binary_search_by_key(&ch, |&(from, _)| from)
+        .ok()
+        .map(|index| CONFUSABLES[index].1)
+}
+
+/// Returns the byte offsets of the confusables in `line` that stand in for
+/// ASCII, in order
+///
+/// Words are runs of letters, digits, and underscores. A confusable is
+/// reported when its word mixes it with ASCII (`pаssword`), or when the word
+/// is made only of confusables and the line has no other non-ASCII letters
+/// (`ＡＢＣ` in English prose). Words containing any other non-ASCII letter,
+/// and lines of genuine non-Latin text, are left alone.
+///
+/// # Examples
+///
+/// ```
+/// use noemoji::confusables::find;
+///
+/// assert_eq!(find("let Ａpple = 1;"), vec![4]);
+/// assert!(find("Привет, как дела?").is_empty());
+/// ```
+pub fn find(line: &str) -> Vec<usize> {
+    let foreign = line
+        .chars()
+        .any(|ch| !ch.is_ascii() && ch.is_alphanumeric() && confusable(ch).is_none());
+    let mut found = Vec::new();
+    let mut word = Word::default();
+    for (index, ch) in line.char_indices() {
+        if ch.is_alphanumeric() || ch == '_' {
+            word.push(index, ch);
+        } else {
+            word.finish(foreign, &mut found);
+        }
+    }
+    word.finish(foreign, &mut found);
+    found
+}
+
+/// The word being scanned by [`find`]
+#[derive(Debug, Default)]
+struct Word {
+    /// Byte offsets of the confusables seen in the word
+    confusables: Vec<usize>,
+    /// The word contains an ASCII letter, digit, or underscore
+    ascii: bool,
+    /// The word contains a non-ASCII letter or digit that is not a confusable
+    foreign: bool,
+}
+
+impl Word {
+    /// Add the character `ch` at byte offset `index` to the word
+    fn push(&mut self, index: usize, ch: char) {
+        if ch.is_ascii() {
+            self.ascii = true;
+        } else if confusable(ch).is_some() {
+            self.confusables.push(index);
+        } else {
+            self.foreign = true;
+        }
+    }
+
+    /// End the word, moving its confusables to `found` if they stand in for
+    /// ASCII on a line that is `foreign` or not
+    fn finish(&mut self, foreign: bool, found: &mut Vec<usize>) {
+        if !self.foreign && (self.ascii || !foreign) {
+            found.append(&mut self.confusables);
+        }
+        *self = Word::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn confusables_are_sorted_and_unique() {
+        assert!(CONFUSABLES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn confusable_maps_full_width_forms() {
+        assert_eq!(confusable('０'), Some('0'));
+        assert_eq!(confusable('ｚ'), Some('z'));
+        assert_eq!(confusable('！'), None);
+    }
+
+    #[test]
+    fn find_flags_confusables_mixed_into_ascii_words() {
+        // Cyrillic а in an English identifier
+        assert_eq!(find("let p\u{0430}ss = 1;"), vec![5]);
+        assert_eq!(find("ＡＢＣ"), vec![0, 3, 6]);
+    }
+
+    #[test]
+    fn find_skips_genuine_foreign_text() {
+        assert!(find("Москва").is_empty());
+        // Every letter of "сор" is a confusable, but the line is Cyrillic
+        assert!(find("Это сор").is_empty());
+        assert!(find("café").is_empty());
+    }
+
+    #[test]
+    fn find_flags_mixed_words_on_foreign_lines() {
+        assert_eq!(find("Привет p\u{0430}ss"), vec![14]);
+    }
+}
+
+// EOF
@@ -0,0 +1,335 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Lines added by a git diff
+//!
+//! `--diff <RANGE>` limits reports to lines added relative to a git revision
+//! range, so a codebase can adopt the linter for new code without first
+//! cleaning up existing files. The added lines come from
+//! `git diff --unified=0 <RANGE>`, whose paths are relative to the repository
+//! root, so inputs are resolved against the working directory's place in the
+//! repository before they are looked up.
+//!
+//! `--changed-only` instead checks whole files, namely the tracked files with
+//! uncommitted changes, listed by [`changed_files`].
+
+use std::{
+    collections::HashMap,
+    ops::RangeInclusive,
+    path::{Component, Path, PathBuf},
+    process::Command,
+};
+
+use thiserror::Error;
+
+/// Errors that can occur while asking git for a diff
+#[derive(Debug, Error)]
+pub enum DiffError {
+    /// git could not be run at all
+    #[error("failed to run git: {0}")]
+    Spawn(#[source] std::io::Error),
+    /// git ran but reported an error, such as an unknown revision
+    #[error("git diff {range} failed: {message}")]
+    Git {
+        /// The revision range passed to git
+        range: String,
+        /// What git printed to stderr
+        message: String,
+    },
+    /// git printed a diff that is not valid UTF-8
+    #[error("git diff output is not valid UTF-8")]
+    InvalidUtf8,
+}
+
+/// Line numbers added to each file by a diff
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use noemoji::diff::ChangedLines;
+///
+/// let changed = ChangedLines::parse(
+///     "--- a/src/lib.rs\n\
+///      +++ b/src/lib.rs\n\
+///      @@ -3,0 +4,2 @@\n\
+///      +added\n\
+///      +also added\n",
+/// );
+/// assert!(changed.contains(Path::new("src/lib.rs"), 5));
+/// assert!(!changed.contains(Path::new("src/lib.rs"), 3));
+/// assert!(!changed.contains(Path::new("README.md"), 1));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChangedLines {
+    /// Added line ranges, keyed by normalized path from the repository root
+    files: HashMap<PathBuf, Vec<RangeInclusive<usize>>>,
+    /// Absolute path of the repository root, for absolute inputs
+    root: Option<PathBuf>,
+    /// The working directory, relative to the repository root
+    prefix: PathBuf,
+}
+
+impl ChangedLines {
+    /// Run `git diff` for `range` in the working directory and collect the
+    /// lines it adds
+    pub fn from_git(range: &str) -> Result<Self, DiffError> {
+        let location = git(range, &["rev-parse", "--show-toplevel", "--show-prefix"])?;
+        let mut location = location.lines();
+        let root = location.next().map(PathBuf::from);
+        let prefix = normalize(location.next().unwrap_or_default()).unwrap_or_default();
+        let diff = git(
+            range,
+            &[
+                "diff",
+                "--unified=0",
+                "--no-color",
+                "--no-ext-diff",
+                "--no-relative",
+                "--src-prefix=a/",
+                "--dst-prefix=b/",
+                range,
+                "--",
+            ],
+        )?;
+        Ok(ChangedLines {
+            root,
+            prefix,
+            ..Self::parse(&diff)
+        })
+    }
+
+    /// Parse the output of `git diff --unified=0`
+    ///
+    /// Deleted files and hunks that only remove lines contribute nothing.
+    pub fn parse(diff: &str) -> Self {
+        let mut changed = Self::default();
+        let mut current: Option<PathBuf> = None;
+
+        for line in diff.lines() {
+            if let Some(target) = line.strip_prefix("+++ ") {
+                // git appends a tab to names containing spaces
+                current = target
+                    .trim_end_matches('\t')
+                    .strip_prefix("b/")
+                    .and_then(normalize);
+            } else if let Some(hunk) = line.strip_prefix("@@ ")
+                && let Some(path) = &current
+                && let Some(lines) = added_lines(hunk)
+            {
+                changed.files.entry(path.clone()).or_default().push(lines);
+            }
+        }
+        changed
+    }
+
+    /// Returns true if `line` of the file at `path` was added
+    ///
+    /// A relative `path` is taken from the working directory, and may lead
+    /// out of it with `..`; an absolute one must lie inside the repository.
+    pub fn contains(&self, path: &Path, line: usize) -> bool {
+        self.key(path)
+            .and_then(|key| self.files.get(&key))
+            .is_some_and(|ranges| ranges.iter().any(|range| range.contains(&line)))
+    }
+
+    /// Iterate over the files with added lines within the working directory,
+    /// relative to it, in no particular order
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files
+            .keys()
+            .filter_map(|path| path.strip_prefix(&self.prefix).ok())
+    }
+
+    /// Returns the path of `path` from the repository root, if it lies
+    /// inside the repository
+    fn key(&self, path: &Path) -> Option<PathBuf> {
+        if !path.is_absolute() {
+            return normalize(self.prefix.join(path));
+        }
+        let root = self.root.as_deref()?;
+        if let Some(key) = path.strip_prefix(root).ok().and_then(normalize) {
+            return Some(key);
+        }
+        // The path may reach the repository through a symbolic link, such as
+        // a shell's logical working directory
+        let dir = path.parent()?.canonicalize().ok()?;
+        normalize(dir.strip_prefix(root).ok()?.join(path.file_name()?))
+    }
+}
+
+/// Run git with `args` in the working directory, returning its standard
+/// output, with failures attributed to `range`
+fn git(range: &str, args: &[&str]) -> Result<String, DiffError> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(DiffError::Spawn)?;
+    if !output.status.success() {
+        return Err(DiffError::Git {
+            range: range.to_owned(),
+            message: String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+        });
+    }
+    String::from_utf8(output.stdout).map_err(|_| DiffError::InvalidUtf8)
+}
+
+/// Run `git diff` in the working directory to list tracked files with
+/// uncommitted changes, staged or not, relative to the working directory
+///
+/// Deleted files are listed too, so callers should skip paths that no longer
+/// exist.
+pub fn changed_files() -> Result<Vec<PathBuf>, DiffError> {
+    let output = Command::new("git")
+        .args([
+            "diff",
+            "--name-only",
+            "-z",
+            "--no-ext-diff",
+            "--relative",
+            "HEAD",
+            "--",
+        ])
+        .output()
+        .map_err(DiffError::Spawn)?;
+    if !output.status.success() {
+        return Err(DiffError::Git {
+            range: "HEAD".to_owned(),
+            message: String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+        });
+    }
+    let names = String::from_utf8(output.stdout).map_err(|_| DiffError::InvalidUtf8)?;
+    Ok(parse_names(&names))
+}
+
+/// Parse the NUL-separated output of `git diff --name-only -z`
+fn parse_names(names: &str) -> Vec<PathBuf> {
+    names
+        .split('\0')
+        .filter(|name| !name.is_empty())
+        .filter_map(normalize)
+        .collect()
+}
+
+/// Parse the added line range from a hunk header, after its leading `@@ `
+///
+/// Returns `None` for a hunk that adds no lines.
+fn added_lines(hunk: &str) -> Option<RangeInclusive<usize>> {
+    let added = hunk.split(' ').find_map(|part| part.strip_prefix('+'))?;
+    let (start, count) = match added.split_once(',') {
+        Some((start, count)) => (start.parse().ok()?, count.parse().ok()?),
+        None => (added.parse().ok()?, 1),
+    };
+    (count > 0).then(|| start..=start + count - 1)
+}
+
+/// Resolve `.` and `..` components so `./src/lib.rs`, `src/../src/lib.rs`,
+/// and `src/lib.rs` compare equal
+///
+/// Returns `None` if the path climbs above its starting point or is absolute.
+fn normalize(path: impl AsRef<Path>) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.as_ref().components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            Component::Normal(part) => normalized.push(part),
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_collects_added_ranges_per_file() {
+        let diff = "\
+diff --git a/a.txt b/a.txt
+--- a/a.txt
++++ b/a.txt
+@@ -1 +1 @@
+-old
++new
+@@ -5,2 +5,0 @@ context
+-gone
+-gone
+@@ -9,0 +8,3 @@
++x
++y
++z
+diff --git a/old.txt b/old.txt
+--- a/old.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-bye
+diff --git a/b c.txt b/b c.txt
+--- /dev/null
++++ b/b c.txt\t
+@@ -0,0 +1 @@
++hi
+";
+        let changed = ChangedLines::parse(diff);
+        let a = Path::new("a.txt");
+        assert!(changed.contains(a, 1));
+        assert!(!changed.contains(a, 5));
+        assert!(!changed.contains(a, 7));
+        assert!(changed.contains(a, 8));
+        assert!(changed.contains(a, 10));
+        assert!(!changed.contains(a, 11));
+        assert!(!changed.contains(Path::new("old.txt"), 1));
+        assert!(changed.contains(Path::new("./b c.txt"), 1));
+        assert!(changed.contains(Path::new("sub/../a.txt"), 1));
+        assert!(!changed.contains(Path::new("../a.txt"), 1));
+
+        let mut paths: Vec<_> = changed.paths().collect();
+        paths.sort();
+        assert_eq!(paths, vec![Path::new("a.txt"), Path::new("b c.txt")]);
+    }
+
+    #[test]
+    fn added_lines_defaults_count_to_one() {
+        assert_eq!(added_lines("-3 +4 @@"), Some(4..=4));
+        assert_eq!(added_lines("-3,2 +4,3 @@ fn main()"), Some(4..=6));
+        assert_eq!(added_lines("-3,2 +2,0 @@"), None);
+    }
+
+    #[test]
+    fn contains_resolves_paths_from_the_working_directory() {
+        let changed = ChangedLines {
+            root: Some(PathBuf::from("/repo")),
+            prefix: PathBuf::from("sub"),
+            ..ChangedLines::parse(
+                "+++ b/a.txt\n@@ -0,0 +1 @@\n+x\n+++ b/sub/b.txt\n@@ -0,0 +1 @@\n+y\n",
+            )
+        };
+        assert!(changed.contains(Path::new("../a.txt"), 1));
+        assert!(changed.contains(Path::new("b.txt"), 1));
+        assert!(changed.contains(Path::new("/repo/a.txt"), 1));
+        assert!(changed.contains(Path::new("/repo/sub/./b.txt"), 1));
+        assert!(!changed.contains(Path::new("a.txt"), 1));
+        assert!(!changed.contains(Path::new("/elsewhere/a.txt"), 1));
+        assert_eq!(
+            changed.paths().collect::<Vec<_>>(),
+            vec![Path::new("b.txt")]
+        );
+    }
+
+    #[test]
+    fn parse_names_splits_on_nul() {
+        assert_eq!(
+            parse_names("a.txt\0src/b c.rs\0"),
+            vec![PathBuf::from("a.txt"), PathBuf::from("src/b c.rs")]
+        );
+        assert!(parse_names("").is_empty());
+    }
+}
+
+// EOF
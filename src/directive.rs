@@ -0,0 +1,286 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Inline suppression directives
+//!
+//! A line can carry a `noemoji:` directive, typically in a trailing comment,
+//! to suppress violations that are intentional:
+//!
+//! ```text
+//! let arrow = '→'; // noemoji: allow
+//! // noemoji: allow-next-line U+2192
+//! let arrow = '→';
+//! ```
+//!
+//! - `noemoji: allow` suppresses violations on the same line
+//! - `noemoji: allow-next-line` suppresses violations on the following line
+//!
+//! Either form can be followed by characters, written literally or as
+//! `U+XXXX`, to suppress only those characters.
+//!
+//! A `noemoji: disable-file` directive within the first [`HEADER_LINES`]
+//! lines skips the whole file, for generated or vendored files that are
+//! tracked but intentionally contain Unicode.
+
+use std::ops::Range;
+
+use crate::config::parse_char;
+
+/// Text that introduces a directive
+const MARKER: &str = "noemoji:";
+
+/// Number of leading lines searched for a `disable-file` directive
+pub const HEADER_LINES: usize = 5;
+
+/// Characters suppressed on a line
+///
+/// Besides directives, `--lang` suppresses whole spans of a line that lie in
+/// string literals and comments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Allowed {
+    /// Every character is suppressed
+    all: bool,
+    /// Individually suppressed characters, if not `all`
+    chars: Vec<char>,
+    /// Byte ranges of the line in which every character is suppressed
+    spans: Vec<Range<usize>>,
+}
+
+impl Allowed {
+    /// Suppress every character
+    pub fn all() -> Self {
+        Allowed {
+            all: true,
+            chars: Vec::new(),
+            spans: Vec::new(),
+        }
+    }
+
+    /// Suppress only the given characters
+    pub fn chars(chars: impl IntoIterator<Item = char>) -> Self {
+        Allowed {
+            all: false,
+            chars: chars.into_iter().collect(),
+            spans: Vec::new(),
+        }
+    }
+
+    /// Also suppress every character within `spans` of the line
+    pub(crate) fn with_spans(mut self, spans: Vec<Range<usize>>) -> Self {
+        self.spans = spans;
+        self
+    }
+
+    /// Returns true if `ch` is suppressed wherever it appears on the line
+    pub fn contains(&self, ch: char) -> bool {
+        self.all || self.chars.contains(&ch)
+    }
+
+    /// Returns true if `ch`, starting at byte `index` of the line, is
+    /// suppressed
+    pub fn contains_at(&self, ch: char, index: usize) -> bool {
+        self.contains(ch) || self.spans.iter().any(|span| span.contains(&index))
+    }
+
+    /// Returns true if nothing is suppressed
+    pub fn is_empty(&self) -> bool {
+        !self.all && self.chars.is_empty() && self.spans.is_empty()
+    }
+
+    /// Add everything suppressed by `other`
+    fn extend(&mut self, other: Allowed) {
+        self.all |= other.all;
+        self.chars.extend(other.chars);
+        self.spans.extend(other.spans);
+    }
+}
+
+/// A suppression directive found on a line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Directive {
+    /// `noemoji: allow`, applying to the same line
+    Allow(Allowed),
+    /// `noemoji: allow-next-line`, applying to the following line
+    AllowNextLine(Allowed),
+    /// `noemoji: disable-file`, skipping the whole file when in its header
+    DisableFile,
+}
+
+impl Directive {
+    /// Parse the directive on `line`, if it has one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noemoji::directive::{Allowed, Directive};
+    ///
+    /// assert_eq!(
+    ///     Directive::parse("x = '→' # noemoji: allow U+2192"),
+    ///     Some(Directive::Allow(Allowed::chars(['→'])))
+    /// );
+    /// assert_eq!(Directive::parse("no directive here"), None);
+    /// ```
+    pub fn parse(line: &str) -> Option<Self> {
+        let rest = &line[line.find(MARKER)? + MARKER.len()..];
+        let rest = rest.trim_start();
+        let keyword_len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (keyword, args) = rest.split_at(keyword_len);
+
+        let allowed = parse_allowed(args);
+        match keyword {
+            "allow" => Some(Directive::Allow(allowed)),
+            "allow-next-line" => Some(Directive::AllowNextLine(allowed)),
+            "disable-file" => Some(Directive::DisableFile),
+            _ => None,
+        }
+    }
+}
+
+/// Returns true if any of the first [`HEADER_LINES`] lines of `text` has a
+/// `disable-file` directive
+///
+/// # Examples
+///
+/// ```
+/// use noemoji::directive::is_file_disabled;
+///
+/// assert!(is_file_disabled("#!/bin/sh\n# noemoji: disable-file\necho →\n"));
+/// assert!(!is_file_disabled("echo →\n"));
+/// ```
+pub fn is_file_disabled(text: &str) -> bool {
+    text.lines()
+        .take(HEADER_LINES)
+        .any(|line| Directive::parse(line) == Some(Directive::DisableFile))
+}
+
+/// Parse the characters following a directive keyword
+///
+/// Parsing stops at the first word that is not a non-ASCII character or a
+/// `U+XXXX` code point, so a directive may be followed by a free-form reason.
+/// If no characters are listed, everything is suppressed.
+fn parse_allowed(args: &str) -> Allowed {
+    let chars: Vec<char> = args
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|word| !word.is_empty())
+        .map_while(|word| parse_char(word).ok().filter(|ch| !ch.is_ascii()))
+        .collect();
+    if chars.is_empty() {
+        Allowed::all()
+    } else {
+        Allowed::chars(chars)
+    }
+}
+
+/// Tracks directives while scanning lines in order
+#[derive(Debug, Default)]
+pub(crate) struct Suppressions {
+    /// Suppressions carried over from an `allow-next-line` directive
+    next_line: Allowed,
+}
+
+impl Suppressions {
+    /// Returns what is suppressed on `line`, which must follow the line
+    /// previously passed to this method
+    pub(crate) fn line(&mut self, line: &str) -> Allowed {
+        let mut allowed = std::mem::take(&mut self.next_line);
+        // Fast path: most lines have no directive
+        if line.contains(MARKER) {
+            match Directive::parse(line) {
+                Some(Directive::Allow(same_line)) => allowed.extend(same_line),
+                Some(Directive::AllowNextLine(next_line)) => self.next_line = next_line,
+                // Only meaningful in the header, which is checked up front
+                Some(Directive::DisableFile) | None => {}
+            }
+        }
+        allowed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_allow_without_chars_allows_everything() {
+        for line in [
+            "x → y // noemoji: allow",
+            "x → y # noemoji:allow",
+            "x → y <!-- noemoji: allow -->",
+        ] {
+            assert_eq!(
+                Directive::parse(line),
+                Some(Directive::Allow(Allowed::all())),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_allow_with_chars() {
+        assert_eq!(
+            Directive::parse("// noemoji: allow U+2192, ≤ because the spec says so"),
+            Some(Directive::Allow(Allowed::chars(['→', '≤'])))
+        );
+    }
+
+    #[test]
+    fn parse_allow_next_line() {
+        assert_eq!(
+            Directive::parse("# noemoji: allow-next-line"),
+            Some(Directive::AllowNextLine(Allowed::all()))
+        );
+        assert_eq!(
+            Directive::parse("# noemoji: allow-next-line U+2014"),
+            Some(Directive::AllowNextLine(Allowed::chars(['—'])))
+        );
+    }
+
+    #[test]
+    fn parse_disable_file() {
+        assert_eq!(
+            Directive::parse("<!-- noemoji: disable-file -->"),
+            Some(Directive::DisableFile)
+        );
+    }
+
+    #[test]
+    fn file_disabled_only_by_header_directive() {
+        let mut text = "# generated\n".repeat(HEADER_LINES - 1);
+        text.push_str("# noemoji: disable-file\n→\n");
+        assert!(is_file_disabled(&text));
+
+        let late = format!("\n{text}");
+        assert!(!is_file_disabled(&late));
+    }
+
+    #[test]
+    fn parse_ignores_unknown_keywords() {
+        assert_eq!(Directive::parse("// noemoji: deny"), None);
+        assert_eq!(Directive::parse("// noemoji:"), None);
+        assert_eq!(Directive::parse("// noemoji allow"), None);
+    }
+
+    #[test]
+    fn suppressions_apply_next_line_once() {
+        let mut suppressions = Suppressions::default();
+
+        assert!(suppressions.line("// noemoji: allow-next-line").is_empty());
+        assert!(suppressions.line("x → y").contains('→'));
+        assert!(suppressions.line("x → y").is_empty());
+    }
+
+    #[test]
+    fn suppressions_combine_same_and_next_line() {
+        let mut suppressions = Suppressions::default();
+
+        suppressions.line("// noemoji: allow-next-line U+2192");
+        let allowed = suppressions.line("x → y ≤ z // noemoji: allow ≤");
+
+        assert!(allowed.contains('→'));
+        assert!(allowed.contains('≤'));
+        assert!(!allowed.contains('≥'));
+    }
+}
+
+// EOF
@@ -0,0 +1,96 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Unified error type for embedding applications
+//!
+//! Each module keeps its own error type for fine-grained handling. Code that
+//! only needs to report failures can use [`NoemojiError`] and `?` across
+//! argument parsing, configuration loading, and checking alike:
+//!
+//! ```
+//! use noemoji::{NoemojiError, check::InputSource, config::Config};
+//!
+//! fn has_violations(path: &str) -> Result<bool, NoemojiError> {
+//!     let checker = Config::load()?.checker();
+//!     Ok(checker.check_input(&InputSource::File(path.into()), |_| {})?)
+//! }
+//!
+//! assert!(matches!(
+//!     has_violations("no/such/file.txt"),
+//!     Err(NoemojiError::Check(_))
+//! ));
+//! ```
+
+use thiserror::Error;
+
+use crate::{check::CheckError, cli::CliError, config::ConfigError};
+
+/// Any error reported by this crate
+///
+/// Display and [`source`](std::error::Error::source) are those of the wrapped
+/// error, so the chain leads straight to the underlying I/O or TOML error.
+#[derive(Debug, Error)]
+pub enum NoemojiError {
+    /// Invalid command line arguments
+    #[error(transparent)]
+    Cli(#[from] CliError),
+
+    /// Configuration could not be read or parsed
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+
+    /// An input could not be checked or fixed
+    #[error(transparent)]
+    Check(#[from] CheckError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error, io, path::PathBuf};
+
+    use super::*;
+
+    #[test]
+    fn cli_error_converts() {
+        let err = NoemojiError::from(CliError::UnknownOption("--bogus".to_owned()));
+        assert!(matches!(err, NoemojiError::Cli(_)));
+        assert_eq!(err.to_string(), "unknown option '--bogus'");
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn cli_error_source_chains_to_io_error() {
+        let err = NoemojiError::from(CliError::ResponseFile {
+            path: "args.txt".to_owned(),
+            source: io::Error::new(io::ErrorKind::NotFound, "gone"),
+        });
+        let source = err.source().unwrap();
+        assert_eq!(
+            source.downcast_ref::<io::Error>().unwrap().kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn config_error_source_chains_to_toml_error() {
+        let toml_err = toml::from_str::<toml::Table>("not = [valid").unwrap_err();
+        let err = NoemojiError::from(ConfigError::from(toml_err));
+        assert!(matches!(err, NoemojiError::Config(_)));
+        assert!(err.to_string().starts_with("Invalid TOML configuration"));
+        assert!(err.source().unwrap().is::<toml::de::Error>());
+    }
+
+    #[test]
+    fn check_error_source_chains_to_io_error() {
+        let err = NoemojiError::from(CheckError::OpenFile {
+            path: PathBuf::from("missing.txt"),
+            source: io::Error::new(io::ErrorKind::PermissionDenied, "denied"),
+        });
+        assert!(matches!(err, NoemojiError::Check(_)));
+        assert_eq!(err.to_string(), "missing.txt: denied");
+        assert!(err.source().unwrap().is::<io::Error>());
+    }
+}
+
+// EOF
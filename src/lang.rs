@@ -0,0 +1,504 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Language-aware checking
+//!
+//! With `--lang rust`, the contents of string literals and comments are left
+//! unchecked, so a project can forbid Unicode in code while still allowing
+//! user-facing messages such as `"café"` and prose in comments. Character
+//! literals are still checked.
+//!
+//! With `--lang markdown`, fenced code blocks and inline code spans are left
+//! unchecked, so documentation can show example output verbatim while its
+//! prose stays ASCII.
+//!
+//! The lexers are deliberately small: they only need to find where literals,
+//! comments, and code start and end. If one cannot make sense of the input,
+//! such as an unterminated string, the whole input is checked as plain text
+//! instead. A Markdown code fence that is never closed runs to the end of the
+//! input, as CommonMark specifies.
+
+use std::{fmt, ops::Range, str::FromStr};
+
+use thiserror::Error;
+
+/// Source language whose string literals and comments are left unchecked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    /// Rust source code
+    Rust,
+    /// Markdown documentation
+    Markdown,
+}
+
+impl Language {
+    /// Accepted values, for error messages
+    pub const EXPECTED: &'static str = "rust, markdown";
+
+    /// Returns the command line name of this language
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Rust => "rust",
+            Self::Markdown => "markdown",
+        }
+    }
+
+    /// Returns the byte ranges of `text` to leave unchecked, in order, or
+    /// `None` if `text` cannot be lexed
+    ///
+    /// These are string literals and comments in Rust, and code blocks and
+    /// code spans in Markdown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noemoji::lang::Language;
+    ///
+    /// let text = "let s = \"→\"; // ←\n";
+    /// assert_eq!(Language::Rust.unchecked(text), Some(vec![8..13, 15..21]));
+    /// assert_eq!(Language::Rust.unchecked("let s = \"→;\n"), None);
+    /// ```
+    pub fn unchecked(self, text: &str) -> Option<Vec<Range<usize>>> {
+        match self {
+            Self::Rust => RustLexer { text, pos: 0 }.spans(),
+            Self::Markdown => Some(markdown_spans(text)),
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error returned when parsing an unsupported language name
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid language '{value}', expected: {}", Language::EXPECTED)]
+pub struct ParseLanguageError {
+    /// The invalid value that was provided
+    pub value: String,
+}
+
+impl FromStr for Language {
+    type Err = ParseLanguageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rust" => Ok(Self::Rust),
+            "markdown" => Ok(Self::Markdown),
+            _ => Err(ParseLanguageError {
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
+
+/// Finds the string literals and comments in Rust source
+///
+/// Every delimiter the lexer looks for is ASCII, so it scans bytes and only
+/// ever stops at character boundaries.
+struct RustLexer<'a> {
+    text: &'a str,
+    /// Byte offset of the next byte to scan
+    pos: usize,
+}
+
+impl RustLexer<'_> {
+    /// Lex the whole text, returning the spans of literals and comments
+    fn spans(mut self) -> Option<Vec<Range<usize>>> {
+        let bytes = self.text.as_bytes();
+        let mut spans = Vec::new();
+        while let Some(&byte) = bytes.get(self.pos) {
+            let start = self.pos;
+            match byte {
+                b'/' if bytes.get(start + 1) == Some(&b'/') => {
+                    self.pos = self.find(b'\n').unwrap_or(bytes.len());
+                    spans.push(start..self.pos);
+                }
+                b'/' if bytes.get(start + 1) == Some(&b'*') => {
+                    self.block_comment()?;
+                    spans.push(start..self.pos);
+                }
+                b'"' => {
+                    self.pos += 1;
+                    self.string()?;
+                    spans.push(start..self.pos);
+                }
+                b'\'' => self.quote()?,
+                _ if is_ident(byte) => {
+                    while bytes.get(self.pos).copied().is_some_and(is_ident) {
+                        self.pos += 1;
+                    }
+                    if self.prefixed_literal(&bytes[start..self.pos])? {
+                        spans.push(start..self.pos);
+                    }
+                }
+                _ => self.pos += 1,
+            }
+        }
+        Some(spans)
+    }
+
+    /// Returns the offset of the next `byte` at or after the current position
+    fn find(&self, byte: u8) -> Option<usize> {
+        self.text.as_bytes()[self.pos..]
+            .iter()
+            .position(|&b| b == byte)
+            .map(|index| self.pos + index)
+    }
+
+    /// Skip a block comment starting at the current position, which may nest
+    fn block_comment(&mut self) -> Option<()> {
+        let bytes = self.text.as_bytes();
+        let mut depth = 0;
+        loop {
+            match (bytes.get(self.pos)?, bytes.get(self.pos + 1)) {
+                (b'/', Some(b'*')) => {
+                    depth += 1;
+                    self.pos += 2;
+                }
+                (b'*', Some(b'/')) => {
+                    depth -= 1;
+                    self.pos += 2;
+                    if depth == 0 {
+                        return Some(());
+                    }
+                }
+                _ => self.pos += 1,
+            }
+        }
+    }
+
+    /// Skip the rest of a string literal whose opening quote was consumed
+    fn string(&mut self) -> Option<()> {
+        let bytes = self.text.as_bytes();
+        loop {
+            match bytes.get(self.pos)? {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    return Some(());
+                }
+                _ => self.pos += 1,
+            }
+        }
+    }
+
+    /// Skip the rest of a raw string literal from its first `#` or quote,
+    /// closed by a quote and as many `#`s as opened it
+    fn raw_string(&mut self, hashes: usize) -> Option<()> {
+        self.pos += hashes + 1;
+        loop {
+            self.pos = self.find(b'"')? + 1;
+            let closing = &self.text.as_bytes()[self.pos..];
+            if closing.len() >= hashes && closing[..hashes].iter().all(|&b| b == b'#') {
+                self.pos += hashes;
+                return Some(());
+            }
+        }
+    }
+
+    /// Skip a character literal or the quote of a lifetime or label
+    fn quote(&mut self) -> Option<()> {
+        let bytes = self.text.as_bytes();
+        let start = self.pos;
+        if bytes.get(start + 1) == Some(&b'\\') {
+            // An escape such as '\n', '\'', or '\u{2192}' ends at the next
+            // quote after the escaped character
+            self.pos = bytes.len().min(start + 3);
+            self.pos = self.find(b'\'')? + 1;
+            return (!self.text[start..self.pos].contains('\n')).then_some(());
+        }
+        let ch = self.text[start + 1..].chars().next();
+        let after = start + 1 + ch.map_or(0, char::len_utf8);
+        self.pos = if ch != Some('\n') && bytes.get(after) == Some(&b'\'') {
+            after + 1
+        } else {
+            // A lifetime or label, whose name is lexed as an identifier
+            start + 1
+        };
+        Some(())
+    }
+
+    /// Finish a literal introduced by the identifier `prefix`, which was just
+    /// consumed, such as `b"..."`, `r#"..."#`, or `b'x'`
+    ///
+    /// Returns whether a string literal was skipped.
+    fn prefixed_literal(&mut self, prefix: &[u8]) -> Option<bool> {
+        let bytes = &self.text.as_bytes()[self.pos..];
+        match prefix {
+            b"b" | b"c" if bytes.first() == Some(&b'"') => {
+                self.pos += 1;
+                self.string()?;
+                Some(true)
+            }
+            b"r" | b"br" | b"cr" => {
+                let hashes = bytes.iter().take_while(|&&b| b == b'#').count();
+                // Otherwise a raw identifier such as r#type, or not a literal
+                if bytes.get(hashes) != Some(&b'"') {
+                    return Some(false);
+                }
+                self.raw_string(hashes)?;
+                Some(true)
+            }
+            b"b" if bytes.first() == Some(&b'\'') => {
+                self.quote()?;
+                Some(false)
+            }
+            _ => Some(false),
+        }
+    }
+}
+
+/// Returns true if `byte` can be part of an identifier or number, counting
+/// every byte of a non-ASCII character
+fn is_ident(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || !byte.is_ascii()
+}
+
+/// An open Markdown code fence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Fence {
+    /// Fence character, a backtick or tilde
+    marker: u8,
+    /// Number of marker characters that opened the fence
+    len: usize,
+}
+
+impl Fence {
+    /// Returns the fence opened by `line`, if it is a fence line
+    ///
+    /// A fence is three or more backticks or tildes, indented by at most
+    /// three spaces. The rest of the line is an info string, which for a
+    /// backtick fence cannot contain backticks.
+    fn parse(line: &str) -> Option<Fence> {
+        let indent = line.bytes().take_while(|&b| b == b' ').count();
+        if indent > 3 {
+            return None;
+        }
+        let rest = &line.as_bytes()[indent..];
+        let marker = *rest.first().filter(|&&b| b == b'`' || b == b'~')?;
+        let len = rest.iter().take_while(|&&b| b == marker).count();
+        let info = &rest[len..];
+        (len >= 3 && !(marker == b'`' && info.contains(&b'`'))).then_some(Fence { marker, len })
+    }
+
+    /// Returns true if `line` closes this fence
+    fn is_closed_by(self, line: &str) -> bool {
+        Fence::parse(line).is_some_and(|close| {
+            close.marker == self.marker
+                && close.len >= self.len
+                && line.trim_start_matches(' ')[close.len..].trim().is_empty()
+        })
+    }
+}
+
+/// Returns the byte ranges of the fenced code blocks and inline code spans
+/// in Markdown `text`, in order
+///
+/// Fenced blocks are tracked line by line, and include their fence lines; a
+/// fence that is never closed runs to the end of `text`. Code spans are
+/// matched within a line, so a span broken across lines is checked.
+fn markdown_spans(text: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut fence: Option<(Fence, usize)> = None;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let end = offset + line.len();
+        match fence {
+            Some((open, start)) if open.is_closed_by(line) => {
+                spans.push(start..end);
+                fence = None;
+            }
+            Some(_) => {}
+            None => match Fence::parse(line) {
+                Some(open) => fence = Some((open, offset)),
+                None => spans.extend(
+                    code_spans(line)
+                        .into_iter()
+                        .map(|span| offset + span.start..offset + span.end),
+                ),
+            },
+        }
+        offset = end;
+    }
+    if let Some((_, start)) = fence {
+        spans.push(start..text.len());
+    }
+    spans
+}
+
+/// Returns the byte ranges of the inline code spans in `line`
+///
+/// A code span opens with a run of backticks and closes with the next run of
+/// the same length. A run with no match is literal text.
+fn code_spans(line: &str) -> Vec<Range<usize>> {
+    let bytes = line.as_bytes();
+    let run_at = |pos: usize| bytes[pos..].iter().take_while(|&&b| b == b'`').count();
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    while let Some(index) = bytes[pos..].iter().position(|&b| b == b'`') {
+        let start = pos + index;
+        let len = run_at(start);
+        pos = start + len;
+        let mut search = pos;
+        while let Some(index) = bytes[search..].iter().position(|&b| b == b'`') {
+            let close = search + index;
+            let close_len = run_at(close);
+            if close_len == len {
+                spans.push(start..close + close_len);
+                pos = close + close_len;
+                break;
+            }
+            search = close + close_len;
+        }
+    }
+    spans
+}
+
+/// Tracks the unchecked spans of an input while scanning its lines in order
+#[derive(Debug, Default)]
+pub(crate) struct Unchecked {
+    /// Spans of the whole input, in order
+    spans: Vec<Range<usize>>,
+    /// Index of the first span not yet passed
+    next: usize,
+}
+
+impl Unchecked {
+    /// Track `spans` of the whole input, which must be in order
+    pub(crate) fn new(spans: Vec<Range<usize>>) -> Self {
+        Unchecked { spans, next: 0 }
+    }
+
+    /// Returns the spans overlapping the line of `len` bytes at `offset`,
+    /// relative to the start of the line
+    ///
+    /// Lines must be passed in order.
+    pub(crate) fn line(&mut self, offset: usize, len: usize) -> Vec<Range<usize>> {
+        let end = offset + len;
+        while self
+            .spans
+            .get(self.next)
+            .is_some_and(|span| span.end <= offset)
+        {
+            self.next += 1;
+        }
+        self.spans[self.next..]
+            .iter()
+            .take_while(|span| span.start < end)
+            .map(|span| span.start.max(offset) - offset..span.end.min(end) - offset)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the text of each unchecked span of Rust `text`
+    fn unchecked(text: &str) -> Option<Vec<&str>> {
+        let spans = Language::Rust.unchecked(text)?;
+        Some(spans.into_iter().map(|span| &text[span]).collect())
+    }
+
+    #[test]
+    fn strings_and_comments_are_unchecked() {
+        assert_eq!(
+            unchecked("f(\"a → b\", '→'); // café\n/* x /* y */ z */ g()").unwrap(),
+            vec!["\"a → b\"", "// café", "/* x /* y */ z */"]
+        );
+    }
+
+    #[test]
+    fn escapes_and_prefixes_are_lexed() {
+        assert_eq!(
+            unchecked(r#"a("\"→", b"x", c"y", '"', b'\'', "z")"#).unwrap(),
+            vec![r#""\"→""#, r#"b"x""#, r#"c"y""#, r#""z""#]
+        );
+        assert_eq!(
+            unchecked(r"a('\'', '\\', '\u{2192}', '→') // x").unwrap(),
+            vec!["// x"]
+        );
+        assert_eq!(
+            unchecked("r##\"a \"# b\"## r#type br\"c\"").unwrap(),
+            vec!["r##\"a \"# b\"##", "br\"c\""]
+        );
+    }
+
+    #[test]
+    fn lifetimes_are_not_char_literals() {
+        assert_eq!(
+            unchecked("fn f<'a>(s: &'a str) -> &'a str { \"→\" }").unwrap(),
+            vec!["\"→\""]
+        );
+        assert_eq!(
+            unchecked("'outer: loop { '→'; }").unwrap(),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn strings_may_span_lines() {
+        assert_eq!(unchecked("a(\"x\n→\n\") b").unwrap(), vec!["\"x\n→\n\""]);
+    }
+
+    #[test]
+    fn unterminated_literals_do_not_lex() {
+        for text in ["\"abc", "/* a /* b */", "r#\"abc\"", "'\\n", "x = '\\\n'"] {
+            assert_eq!(Language::Rust.unchecked(text), None, "{text}");
+        }
+    }
+
+    #[test]
+    fn unchecked_line_clips_spans_to_the_line() {
+        let mut unchecked = Unchecked::new(vec![2..9, 12..14]);
+        assert_eq!(unchecked.line(0, 5), vec![2..5]);
+        assert_eq!(unchecked.line(5, 5), vec![0..4]);
+        assert_eq!(unchecked.line(10, 5), vec![2..4]);
+        assert_eq!(unchecked.line(15, 5), Vec::<Range<usize>>::new());
+    }
+
+    /// Returns the text of each unchecked span of Markdown `text`
+    fn markdown(text: &str) -> Option<Vec<&str>> {
+        let spans = Language::Markdown.unchecked(text)?;
+        Some(spans.into_iter().map(|span| &text[span]).collect())
+    }
+
+    #[test]
+    fn markdown_fences_are_unchecked() {
+        assert_eq!(
+            markdown("a → b\n```text\n→\n```\nc\n~~~~\n```\n~~~\n~~~~~\n").unwrap(),
+            vec!["```text\n→\n```\n", "~~~~\n```\n~~~\n~~~~~\n"]
+        );
+        // Indented four spaces, or with a backtick in the info string, a line
+        // is not a fence
+        assert_eq!(markdown("    ```\n→\n").unwrap(), Vec::<&str>::new());
+        assert_eq!(markdown("```a`b\n→\n").unwrap(), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn markdown_code_spans_are_unchecked() {
+        assert_eq!(
+            markdown("use `→` or ``a ` b`` but not ` or ``` alone\n").unwrap(),
+            vec!["`→`", "``a ` b``"]
+        );
+    }
+
+    #[test]
+    fn markdown_unclosed_fence_runs_to_end() {
+        assert_eq!(markdown("a\n```\n→\n").unwrap(), vec!["```\n→\n"]);
+        assert_eq!(markdown("````\n→\n```").unwrap(), vec!["````\n→\n```"]);
+    }
+
+    #[test]
+    fn language_from_str() {
+        assert_eq!("rust".parse(), Ok(Language::Rust));
+        assert_eq!("markdown".parse(), Ok(Language::Markdown));
+        assert!("python".parse::<Language>().is_err());
+    }
+}
+
+// EOF
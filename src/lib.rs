@@ -32,8 +32,12 @@
 //! - **Fractions**: Use `1/2`, `3/4` instead of ½, ¾
 //! - **Decorative**: No stars, bullets, or shapes like ★, ●, ♦
 //! - **Lookalike Punctuation**: Use ASCII apostrophes and quotes, not Unicode variants
+//! - **Ligatures**: Spell out `fi`, `ff`, and `?!` instead of ﬁ, ﬀ, and ‽
 //! - **Special Spaces**: Only regular ASCII spaces, not non-breaking or other Unicode spaces
 //!
+//! Run `noemoji --explain <RULE>` (for example `--explain arrows`) to print the rationale
+//! and ASCII alternatives for a rule, or `noemoji --explain <CHAR>` for a single character.
+//!
 //! ### ALLOWED EXCEPTIONS
 //!
 //! - **International Content**: Non-ASCII required for international text (café, 世界, Москва)
@@ -41,13 +45,43 @@
 //! - **Currency Symbols**: All Unicode currency symbols (¢, £, ¥, €, ₹, ₽, ₩, etc.) - Unicode category `CurrencySymbol`
 //! - **Technical/Scientific Symbols**: ° (degree), ∞ (infinity) - Used for measurements, tolerances, and technical specifications
 //!
+//! Projects that must stay pure ASCII can drop these exceptions with `--ascii-only`, which
+//! reports every other non-ASCII character as well.
+//!
 //! ### Guiding Principle
 //!
 //! Good documentation looks like a human wrote it. When in doubt, use ASCII.
+//!
+//! ## Library Usage
+//!
+//! The checker can be called directly without going through the binary:
+//!
+//! ```
+//! use noemoji::{CharCategory, Violation, check_str};
+//!
+//! let mut violations: Vec<Violation> = Vec::new();
+//! let found = check_str("Ship it 🚀\nx → y", |v| violations.push(v.clone()));
+//!
+//! assert!(found);
+//! assert_eq!(violations.len(), 2);
+//! assert_eq!((violations[0].line, violations[0].column), (1, 9));
+//! assert_eq!(violations[0].category, CharCategory::Emoji);
+//! assert_eq!(violations[1].suggestion.as_deref(), Some("->"));
+//! ```
 
+pub mod baseline;
 pub mod check;
 pub mod cli;
 pub mod config;
+pub mod confusables;
+pub mod diff;
+pub mod directive;
+pub mod error;
+pub mod lang;
 pub mod logging;
+pub mod output;
+
+pub use check::{CharCategory, Checker, Severity, Violation, check_str};
+pub use error::NoemojiError;
 
 // EOF
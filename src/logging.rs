@@ -50,6 +50,18 @@ impl LogLevel {
         (&["trace"], LogLevel::Trace),
     ];
 
+    /// Returns the canonical configuration name of this level
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Disabled => "off",
+            Self::Error => "error",
+            Self::Warn => "warn",
+            Self::Info => "info",
+            Self::Debug => "debug",
+            Self::Trace => "trace",
+        }
+    }
+
     /// Convert to log::LevelFilter for use with logging infrastructure
     pub const fn to_level_filter(self) -> log::LevelFilter {
         match self {
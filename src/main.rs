@@ -2,68 +2,1739 @@
 // If a copy of the MPL was not distributed with this file,
 // You can obtain one at <https://mozilla.org/MPL/2.0/>.
 
-use std::env;
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    env, fmt,
+    fs::{self, OpenOptions},
+    io::{self, IsTerminal, Write},
+    mem,
+    ops::ControlFlow,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use indicatif::{ProgressBar, ProgressStyle};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use serde::Serialize;
 
 use noemoji::{
-    cli::{CliCommand, Outcome, parse_args, print_help, print_version, program_name},
-    config::Config,
+    baseline::Baseline,
+    check::{
+        CharCategory, CheckError, Checker, DisplayChar, InputSource, LineEdit, Severity, Violation,
+        is_visible, short_note,
+    },
+    cli::{
+        CliCommand, Options, Outcome, OutputFormat, SortOrder, expand_response_files, parse_args,
+        print_explanation, print_help, print_version, program_name,
+    },
+    config::{CONFIG_ENV, CONFIG_FILE, Config, ConfigSource, TEMPLATE, global_config_path},
+    diff::{self, ChangedLines},
     logging::init_logger,
 };
 
 fn main() -> Outcome {
     let args: Vec<String> = env::args().collect();
     let program = program_name(&args[0]);
-    let config = Config::load().unwrap_or_default();
+    let command = expand_response_files(&args[1..]).and_then(|args| parse_args(&args));
+    let source = match &command {
+        Ok(CliCommand::Check { options, .. } | CliCommand::Fix { options, .. }) => {
+            options.config.clone()
+        }
+        Ok(CliCommand::CheckConfig(source)) => source.clone(),
+        _ => ConfigSource::default(),
+    };
+    let source = with_env_config(source);
+    let global = global_config_path();
+    let config = match &source {
+        ConfigSource::Discover => discovered_config(program, global.as_deref()),
+        // A file named explicitly must load, unlike discovered ones
+        ConfigSource::File(path) | ConfigSource::Overlay(path) => match Config::load_file(path) {
+            Ok(config) if matches!(source, ConfigSource::File(_)) || !config.inherit => config,
+            Ok(config) => config.or(discovered_config(program, global.as_deref())),
+            Err(err) => {
+                eprintln!("{}: {}: {}", program, path.display(), err);
+                return Outcome::Error;
+            }
+        },
+        ConfigSource::Defaults => Config::default(),
+    };
     match init_logger(program, config.log.level.unwrap_or_default()) {
         Ok(()) => log::debug!("logger initialized"),
         Err(_) => log::debug!("logger already initialized"),
     }
+    let command = command.and_then(CliCommand::expand_directories);
+    let exit_zero = match &command {
+        Ok(CliCommand::Check { options, .. } | CliCommand::Fix { options, .. })
+            if options.exit_zero =>
+        {
+            true
+        }
+        // A hook must fail the commit, whatever the configuration says
+        Ok(CliCommand::Check { options, .. } | CliCommand::Fix { options, .. }) if options.hook => {
+            false
+        }
+        _ => config.behavior.violations_exit_code == Some(0),
+    };
 
-    match parse_args(&args[1..]) {
+    let outcome = match command {
         Ok(CliCommand::Help) => {
             print_help(&args[0]);
             Outcome::Success
         }
-        Ok(CliCommand::Version) => {
-            print_version();
+        Ok(CliCommand::Version { verbose }) => {
+            print_version(verbose);
+            Outcome::Success
+        }
+        Ok(CliCommand::Explain(topic)) => {
+            print_explanation(topic);
+            Outcome::Success
+        }
+        Ok(CliCommand::CheckConfig(_)) => check_config(program, &source, global.as_deref()),
+        Ok(CliCommand::Init { force }) => init(program, force),
+        Ok(CliCommand::Check { inputs, options }) => {
+            let format = options.format.or(config.output.format).unwrap_or_default();
+            let checker = options.apply(config.checker());
+            if options.watch {
+                watch(program, &checker, &inputs, &options, format)
+            } else {
+                check(program, &checker, &inputs, &options, format)
+            }
+        }
+        Ok(CliCommand::Fix {
+            inputs, options, ..
+        }) if empty(program, &inputs, &options) => Outcome::Error,
+        Ok(CliCommand::Fix {
+            inputs,
+            dry_run: true,
+            options,
+        }) => fix_preview(program, &options.apply(config.checker()), &inputs, &options),
+        Ok(CliCommand::Fix {
+            inputs,
+            dry_run: false,
+            options,
+        }) => fix(program, &options.apply(config.checker()), &inputs, &options),
+        Err(err) => {
+            eprintln!("{}: {}", program, err);
+            eprintln!("Try '{} --help' for more information.", program);
+            Outcome::Error
+        }
+    };
+    match outcome {
+        Outcome::Violations if exit_zero => Outcome::Success,
+        outcome => outcome,
+    }
+}
+
+/// Load configuration discovered from the working directory, with `global`
+/// as the global configuration file
+///
+/// Problems with discovered files are reported by --check-config, so that a
+/// broken config elsewhere in the tree does not block checking. A broken
+/// global file is skipped with a warning instead, so that it cannot silently
+/// discard the project's settings.
+fn discovered_config(program: &str, global: Option<&Path>) -> Config {
+    let mut last = None;
+    match ConfigSource::Discover.load_with(global, |path| last = Some(path.to_path_buf())) {
+        Ok(config) => config,
+        Err(err) => match global {
+            Some(path) if last.as_deref() == Some(path) => {
+                eprintln!(
+                    "{}: warning: {}: {}; ignoring it",
+                    program,
+                    path.display(),
+                    err
+                );
+                ConfigSource::Discover
+                    .load_with(None, |_| {})
+                    .unwrap_or_default()
+            }
+            _ => Config::default(),
+        },
+    }
+}
+
+/// Returns `source` with discovery replaced by an overlay of the file named
+/// by `NOEMOJI_CONFIG`, if set
+///
+/// `--config` and `--no-config` take precedence over the environment.
+fn with_env_config(source: ConfigSource) -> ConfigSource {
+    match env::var_os(CONFIG_ENV) {
+        Some(path) if !path.is_empty() && source == ConfigSource::Discover => {
+            ConfigSource::Overlay(PathBuf::from(path))
+        }
+        _ => source,
+    }
+}
+
+/// Load configuration from `source`, with `global` as the global
+/// configuration file, listing the files used and printing the merged result
+fn check_config(program: &str, source: &ConfigSource, global: Option<&Path>) -> Outcome {
+    let mut paths = Vec::new();
+    match source.load_with(global, |path| paths.push(path.to_path_buf())) {
+        Ok(config) => {
+            if paths.is_empty() {
+                println!("# No configuration files found; using defaults");
+            } else {
+                println!("# Merged from (closest first):");
+                for path in &paths {
+                    println!("#   {}", path.display());
+                }
+            }
+            println!();
+            print!("{}", config);
+            Outcome::Success
+        }
+        Err(err) => {
+            match paths.last() {
+                Some(path) => eprintln!("{}: {}: {}", program, path.display(), err),
+                None => eprintln!("{}: {}", program, err),
+            }
+            Outcome::Error
+        }
+    }
+}
+
+/// Write the commented configuration template to the current directory,
+/// refusing to replace an existing file unless `force` is set
+fn init(program: &str, force: bool) -> Outcome {
+    let mut open = OpenOptions::new();
+    if force {
+        open.write(true).create(true).truncate(true);
+    } else {
+        open.write(true).create_new(true);
+    }
+    let result = open
+        .open(CONFIG_FILE)
+        .and_then(|mut file| file.write_all(TEMPLATE.as_bytes()));
+    match result {
+        Ok(()) => {
+            println!("created {}", CONFIG_FILE);
             Outcome::Success
         }
-        Ok(CliCommand::Check { inputs }) => {
-            let mut has_violations = false;
-            let mut has_errors = false;
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+            eprintln!(
+                "{}: {} already exists; use --force to overwrite",
+                program, CONFIG_FILE
+            );
+            Outcome::Error
+        }
+        Err(err) => {
+            eprintln!("{}: {}: {}", program, CONFIG_FILE, err);
+            Outcome::Error
+        }
+    }
+}
+
+/// Check inputs in the requested format, honoring `--diff`, `--changed-only`,
+/// and `--baseline`
+fn check(
+    program: &str,
+    checker: &Checker,
+    inputs: &[InputSource],
+    options: &Options,
+    format: OutputFormat,
+) -> Outcome {
+    let mut filter = Filter::default();
+    let mut diff_inputs = Vec::new();
+    if let Some(range) = &options.diff {
+        let changed = match ChangedLines::from_git(range) {
+            Ok(changed) => changed,
+            Err(err) => {
+                eprintln!("{}: {}", program, err);
+                return Outcome::Error;
+            }
+        };
+        if inputs.is_empty() {
+            let mut paths: Vec<_> = changed.paths().filter(|path| path.is_file()).collect();
+            paths.sort();
+            diff_inputs = paths
+                .into_iter()
+                .map(|path| InputSource::File(path.to_path_buf()))
+                .collect();
+        }
+        filter.changed = Some(changed);
+    }
+    if options.changed_only {
+        match diff::changed_files() {
+            Ok(paths) => {
+                diff_inputs = paths
+                    .into_iter()
+                    .filter(|path| path.is_file())
+                    .map(InputSource::File)
+                    .collect();
+            }
+            Err(err) => {
+                eprintln!("{}: {}", program, err);
+                return Outcome::Error;
+            }
+        }
+    }
+    let inputs = if diff_inputs.is_empty() {
+        inputs
+    } else {
+        &diff_inputs
+    };
+    if empty(program, inputs, options) {
+        return Outcome::Error;
+    }
+
+    if let Some(path) = &options.baseline {
+        if options.write_baseline {
+            return write_baseline(program, checker, inputs, options, path);
+        }
+        match Baseline::load(path) {
+            Ok(baseline) => {
+                if options.prune_baseline {
+                    filter.loaded = Some(baseline.clone());
+                }
+                filter.known = baseline;
+            }
+            Err(err) => {
+                eprintln!("{}: {}: {}", program, path.display(), err);
+                return Outcome::Error;
+            }
+        }
+    }
+    // A format set in configuration gives way to --count and --stats
+    let outcome = match format {
+        OutputFormat::Json if !options.count && !options.stats => {
+            check_json(program, checker, inputs, options, &mut filter)
+        }
+        OutputFormat::Csv if !options.count && !options.stats => {
+            check_csv(program, checker, inputs, options, &mut filter)
+        }
+        _ => check_text(program, checker, inputs, options, format, &mut filter),
+    };
+
+    // A run that stopped early or saw only a diff or the changed files has
+    // not seen every baselined violation
+    if let Some(path) = &options.baseline
+        && options.prune_baseline
+        && outcome != Outcome::Error
+        && !options.fail_fast
+        && options.diff.is_none()
+        && !options.changed_only
+        && let Err(err) = filter.pruned().save(path)
+    {
+        eprintln!("{}: {}: {}", program, path.display(), err);
+        return Outcome::Error;
+    }
+    outcome
+}
+
+/// Time to wait for further changes before rechecking, so that the burst of
+/// events from a single save triggers one recheck
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Check inputs, then recheck each input file whenever it changes
+///
+/// Runs until interrupted, returning the outcome of the first pass if the
+/// watcher stops on its own.
+fn watch(
+    program: &str,
+    checker: &Checker,
+    inputs: &[InputSource],
+    options: &Options,
+    format: OutputFormat,
+) -> Outcome {
+    if inputs.contains(&InputSource::Stdin) {
+        eprintln!("{}: --watch cannot be used with stdin", program);
+        return Outcome::Error;
+    }
+    let outcome = check(program, checker, inputs, options, format);
+
+    // Editors often save by replacing the file, which ends a watch on the
+    // file itself, so watch each input's directory instead
+    let mut watched = Vec::new();
+    let mut dirs = HashSet::new();
+    for input in included(inputs, options) {
+        let InputSource::File(path) = input else {
+            continue;
+        };
+        let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+            continue;
+        };
+        let dir = if dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            dir
+        };
+        match dir.canonicalize() {
+            Ok(dir) => {
+                watched.push((dir.join(file_name), input));
+                dirs.insert(dir);
+            }
+            Err(err) => eprintln!("{}: {}: {}", program, path.display(), err),
+        }
+    }
+
+    let (sender, events) = mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(sender) {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            eprintln!("{}: cannot watch inputs: {}", program, err);
+            return Outcome::Error;
+        }
+    };
+    for dir in &dirs {
+        if let Err(err) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+            eprintln!("{}: cannot watch {}: {}", program, dir.display(), err);
+            return Outcome::Error;
+        }
+    }
+    log::debug!(
+        "watching {} inputs in {} directories",
+        watched.len(),
+        dirs.len()
+    );
+
+    while let Ok(event) = events.recv() {
+        let mut changed = HashSet::new();
+        record_changes(event, &mut changed);
+        while let Ok(event) = events.recv_timeout(WATCH_DEBOUNCE) {
+            record_changes(event, &mut changed);
+        }
+        let recheck: Vec<InputSource> = watched
+            .iter()
+            .filter(|(path, _)| changed.contains(path))
+            .map(|&(_, input)| input.clone())
+            .collect();
+        if recheck.is_empty() {
+            continue;
+        }
+        let names: Vec<_> = recheck
+            .iter()
+            .map(|input| options.report_name(input))
+            .collect();
+        eprintln!(
+            "{}: [{}] rechecking {}",
+            program,
+            timestamp(),
+            names.join(", ")
+        );
+        check(program, checker, &recheck, options, format);
+    }
+    outcome
+}
+
+/// Add the paths created or modified by a watcher `event` to `changed`
+///
+/// Other events are ignored; in particular, reading a file to recheck it
+/// must not trigger another recheck.
+fn record_changes(event: notify::Result<Event>, changed: &mut HashSet<PathBuf>) {
+    match event {
+        Ok(event) if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) => {
+            changed.extend(event.paths);
+        }
+        Ok(_) => {}
+        Err(err) => log::warn!("watch error: {}", err),
+    }
+}
+
+/// Format the current time of day, in UTC, as `HH:MM:SS`
+fn timestamp() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Record every violation in `inputs` to the baseline file at `path`
+fn write_baseline(
+    program: &str,
+    checker: &Checker,
+    inputs: &[InputSource],
+    options: &Options,
+    path: &Path,
+) -> Outcome {
+    // A baseline stopped at the first violation would be incomplete
+    let options = &Options {
+        fail_fast: false,
+        ..options.clone()
+    };
+    let mut baseline = Baseline::new();
+    let scanned = scan(
+        program,
+        checker,
+        inputs,
+        options,
+        &mut Filter::default(),
+        &ProgressBar::hidden(),
+        &mut baseline,
+    );
+    // Don't replace a baseline with one missing the inputs that failed
+    if scanned.has_errors {
+        return Outcome::Error;
+    }
+
+    match baseline.save(path) {
+        Ok(()) => {
+            if !options.quiet {
+                eprintln!(
+                    "{}: recorded {} {} in {}",
+                    program,
+                    baseline.len(),
+                    plural(baseline.len(), "violation", "violations"),
+                    path.display()
+                );
+            }
+            Outcome::Success
+        }
+        Err(err) => {
+            eprintln!("{}: {}: {}", program, path.display(), err);
+            Outcome::Error
+        }
+    }
+}
+
+/// Violations left out of a report by `--diff` and `--baseline`
+#[derive(Debug, Default)]
+struct Filter {
+    /// Lines added by `--diff`; violations elsewhere are skipped
+    changed: Option<ChangedLines>,
+    /// Baselined occurrences not yet seen in this run
+    known: Baseline,
+    /// Baselined occurrences seen in this run, for `--prune-baseline`
+    matched: Baseline,
+    /// Baseline as loaded, for `--prune-baseline`
+    loaded: Option<Baseline>,
+    /// Names of the inputs checked in this run, for `--prune-baseline`
+    checked: HashSet<String>,
+}
+
+impl Filter {
+    /// Returns true if `v` in the input named `name` should not be reported
+    ///
+    /// A baselined violation is counted as seen when it is skipped.
+    fn skips(&mut self, name: &str, v: &Violation) -> bool {
+        if let Some(changed) = &self.changed
+            && !changed.contains(Path::new(name), v.line)
+        {
+            return true;
+        }
+        let known = self.known.suppress(name, v);
+        if known {
+            self.matched.record(name, v);
+        }
+        known
+    }
+
+    /// Returns the baseline to save under `--prune-baseline`
+    ///
+    /// Entries for inputs checked in this run keep only the occurrences seen,
+    /// and entries for files no longer on disk are dropped; entries for any
+    /// other input, including ones that could not be read, are kept as they
+    /// were.
+    fn pruned(&self) -> Baseline {
+        let mut pruned = self.loaded.clone().unwrap_or_default();
+        pruned.retain_files(|file| !self.checked.contains(file) && Path::new(file).exists());
+        pruned.merge(self.matched.clone());
+        pruned
+    }
+}
+
+/// Names of an input being checked
+struct Names<'a> {
+    /// Name matched against `--diff` and the baseline
+    key: Cow<'a, str>,
+    /// Name printed in reports
+    report: Cow<'a, str>,
+}
+
+/// Receives what [`scan`] finds, to report it in one output format
+///
+/// Everything the formats share, such as filtering, exit status, timing,
+/// and stopping early, is handled by [`scan`], so that an option affecting
+/// every format only needs adding there.
+trait Sink {
+    /// Handle a violation that passed the filters
+    fn violation(&mut self, names: &Names, v: &Violation);
+
+    /// Handle an input that was checked, with the number of its violations
+    /// passed to [`Sink::violation`]
+    fn checked(&mut self, _names: Names, _violations: usize) {}
+
+    /// Handle an input that could not be checked, before the error is
+    /// reported
+    fn failed(&mut self, _names: Names, _err: &CheckError) {}
+}
+
+/// Totals from [`scan`]
+struct Scanned {
+    /// Violation counts over every input checked
+    summary: Summary,
+    /// Whether any violation fails the run
+    has_violations: bool,
+    /// Whether any input could not be checked
+    has_errors: bool,
+}
+
+/// Check inputs, passing each violation that is not filtered out to `sink`
+///
+/// Inputs are checked one at a time, in argument order, and violations are
+/// passed on as they are found, so output is reproducible byte for byte. Keep
+/// it that way: CI logs and baselines are diffed across runs.
+fn scan(
+    program: &str,
+    checker: &Checker,
+    inputs: &[InputSource],
+    options: &Options,
+    filter: &mut Filter,
+    progress: &ProgressBar,
+    sink: &mut impl Sink,
+) -> Scanned {
+    let mut has_violations = false;
+    let mut has_errors = false;
+    let mut summary = Summary::default();
+    let mut timing = Timing::new(options);
+
+    for input in included(inputs, options) {
+        let names = Names {
+            key: options.display_name(input),
+            report: options.report_name(input),
+        };
+        let mut file_summary = Summary::default();
+        let mut found = false;
+
+        let start = Instant::now();
+        let result = checker.try_check_input(input, |v| {
+            if filter.skips(&names.key, v) {
+                return ControlFlow::Continue(());
+            }
+            let fails = fails(checker, options, v);
+            found |= fails;
+            file_summary.record(v);
+            sink.violation(&names, v);
+            stop_if(options.fail_fast && fails)
+        });
+        timing.record(input, &names.report, start);
+
+        match result {
+            Ok(_) => {
+                filter.checked.insert(names.key.clone().into_owned());
+                has_violations |= found;
+                sink.checked(names, file_summary.violations);
+                summary.merge_file(file_summary);
+                if found && options.fail_fast {
+                    break;
+                }
+            }
+            Err(err) => {
+                sink.failed(names, &err);
+                progress.suspend(|| input_error(program, options, &err));
+                has_errors = true;
+                if options.stop_on_error {
+                    break;
+                }
+            }
+        }
+        progress.inc(1);
+        progress.set_message(format!(
+            "{} {} found",
+            summary.violations,
+            plural(summary.violations, "violation", "violations")
+        ));
+    }
+    progress.finish_and_clear();
+    timing.finish(program, &summary);
+
+    Scanned {
+        summary,
+        has_violations,
+        has_errors,
+    }
+}
+
+impl Sink for Baseline {
+    fn violation(&mut self, names: &Names, v: &Violation) {
+        self.record(&names.key, v);
+    }
+}
+
+/// Prints text violations as they are found, or collects them for
+/// `--sort`, `--count`, and `--stats`
+struct TextSink<'a> {
+    checker: &'a Checker,
+    options: &'a Options,
+    printer: Printer,
+    /// Progress bar to hide while printing
+    progress: ProgressBar,
+    /// Violations to print once every input is checked, under `--sort`
+    sorted: Vec<(String, Violation)>,
+    /// Violations per input, for `--count --group`
+    counts: Vec<(String, usize)>,
+    stats: Stats,
+}
+
+impl Sink for TextSink<'_> {
+    fn violation(&mut self, names: &Names, v: &Violation) {
+        if self.options.stats {
+            self.stats.record(v);
+        } else if prints_violations(self.options) {
+            if self.options.sort == SortOrder::None {
+                let severity = self.checker.severity(v.category);
+                self.progress
+                    .suspend(|| self.printer.violation(&names.report, v, severity));
+            } else {
+                self.sorted
+                    .push((names.report.clone().into_owned(), v.clone()));
+            }
+        }
+    }
+
+    fn checked(&mut self, names: Names, violations: usize) {
+        self.progress
+            .suspend(|| self.printer.finish_file(&names.report));
+        if self.options.show_clean && violations == 0 && prints_violations(self.options) {
+            self.progress.suspend(|| self.printer.clean(&names.report));
+        }
+        self.counts.push((names.report.into_owned(), violations));
+    }
+
+    fn failed(&mut self, names: Names, _err: &CheckError) {
+        self.progress
+            .suspend(|| self.printer.finish_file(&names.report));
+    }
+}
+
+/// Check inputs, printing one line per violation, or only counts under
+/// `--count` or `--stats`
+fn check_text(
+    program: &str,
+    checker: &Checker,
+    inputs: &[InputSource],
+    options: &Options,
+    format: OutputFormat,
+    filter: &mut Filter,
+) -> Outcome {
+    let mut sink = TextSink {
+        checker,
+        options,
+        printer: Printer::new(options, format),
+        progress: progress_bar(options, inputs.len()),
+        sorted: Vec::new(),
+        counts: Vec::new(),
+        stats: Stats::default(),
+    };
+    let progress = sink.progress.clone();
+    let scanned = scan(
+        program, checker, inputs, options, filter, &progress, &mut sink,
+    );
+    let TextSink {
+        mut printer,
+        mut sorted,
+        counts,
+        stats,
+        ..
+    } = sink;
+
+    sort_violations(&mut sorted, options.sort, checker);
+    let mut current: Option<&str> = None;
+    for (name, v) in &sorted {
+        if let Some(previous) = current
+            && previous != name
+        {
+            printer.finish_file(previous);
+        }
+        current = Some(name);
+        printer.violation(name, v, checker.severity(v.category));
+    }
+    if let Some(name) = current {
+        printer.finish_file(name);
+    }
+    printer.finish();
+
+    if options.count && !options.quiet {
+        if options.group {
+            for (name, count) in &counts {
+                print!("{}\t{}{}", name, count, options.terminator());
+            }
+        } else {
+            print!("{}{}", scanned.summary.violations, options.terminator());
+        }
+    }
+
+    if options.stats && !options.quiet {
+        stats.print();
+    }
+
+    if options.summary {
+        scanned.summary.print(program, options.verbose);
+    }
+
+    outcome(scanned.has_violations, scanned.has_errors)
+}
+
+/// Number of inputs at which text output shows a progress bar
+const PROGRESS_THRESHOLD: usize = 100;
+
+/// Create a progress bar on stderr for checking `count` inputs
+///
+/// The bar is hidden unless stderr is a terminal and there are at least
+/// [`PROGRESS_THRESHOLD`] inputs, and always under `--quiet`. Output printed
+/// while it is shown must go through [`ProgressBar::suspend`].
+fn progress_bar(options: &Options, count: usize) -> ProgressBar {
+    if options.quiet || options.hook || count < PROGRESS_THRESHOLD || !io::stderr().is_terminal() {
+        return ProgressBar::hidden();
+    }
+    let style = ProgressStyle::with_template("{bar:40} {pos}/{len} files, {msg}")
+        .expect("progress template is valid")
+        .progress_chars("=> ");
+    ProgressBar::new(count as u64).with_style(style)
+}
+
+/// Collects a JSON report entry for each input
+struct JsonSink<'a> {
+    checker: &'a Checker,
+    options: &'a Options,
+    /// Violations in the input being checked
+    violations: Vec<Violation>,
+    report: Vec<JsonFile>,
+}
+
+impl Sink for JsonSink<'_> {
+    fn violation(&mut self, _names: &Names, v: &Violation) {
+        self.violations.push(v.clone());
+    }
+
+    fn checked(&mut self, names: Names, _violations: usize) {
+        let mut violations = mem::take(&mut self.violations);
+        if self.options.sort == SortOrder::Severity {
+            violations.sort_by_key(|v| (self.checker.severity(v.category), v.category.priority()));
+        }
+        self.report.push(JsonFile {
+            status: if violations.is_empty() {
+                JsonStatus::Clean
+            } else {
+                JsonStatus::Violations
+            },
+            error: None,
+            file: names.report.into_owned(),
+            violations: violations
+                .iter()
+                .map(|v| JsonViolation::new(v, self.checker.severity(v.category)))
+                .collect(),
+        });
+    }
+
+    fn failed(&mut self, names: Names, err: &CheckError) {
+        self.violations.clear();
+        self.report.push(JsonFile {
+            file: names.report.into_owned(),
+            status: JsonStatus::Error,
+            error: Some(err.to_string()),
+            violations: Vec::new(),
+        });
+    }
+}
+
+/// Check inputs, printing a JSON report once all inputs are processed
+fn check_json(
+    program: &str,
+    checker: &Checker,
+    inputs: &[InputSource],
+    options: &Options,
+    filter: &mut Filter,
+) -> Outcome {
+    let mut sink = JsonSink {
+        checker,
+        options,
+        violations: Vec::new(),
+        report: Vec::with_capacity(inputs.len()),
+    };
+    let scanned = scan(
+        program,
+        checker,
+        inputs,
+        options,
+        filter,
+        &ProgressBar::hidden(),
+        &mut sink,
+    );
+    let mut report = sink.report;
+    let mut has_errors = scanned.has_errors;
+
+    if options.sort == SortOrder::Path {
+        report.sort_by(|a, b| a.file.cmp(&b.file));
+    }
+
+    if !options.quiet {
+        match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{}", json),
+            Err(err) => {
+                eprintln!("{}: {}", program, err);
+                has_errors = true;
+            }
+        }
+    }
+
+    // Stdout is for parsers, so give people reading the log a line on
+    // stderr, unless --summary prints a fuller one
+    if options.summary {
+        scanned.summary.print(program, options.verbose);
+    } else if !options.quiet {
+        scanned.summary.print_brief(program);
+    }
+
+    outcome(scanned.has_violations, has_errors)
+}
+
+/// Collects a CSV row for each violation
+#[derive(Default)]
+struct CsvSink {
+    rows: Vec<(String, Violation)>,
+}
+
+impl Sink for CsvSink {
+    fn violation(&mut self, names: &Names, v: &Violation) {
+        self.rows
+            .push((names.report.clone().into_owned(), v.clone()));
+    }
+}
+
+/// Check inputs, printing a CSV report once all inputs are processed
+fn check_csv(
+    program: &str,
+    checker: &Checker,
+    inputs: &[InputSource],
+    options: &Options,
+    filter: &mut Filter,
+) -> Outcome {
+    let mut sink = CsvSink::default();
+    let scanned = scan(
+        program,
+        checker,
+        inputs,
+        options,
+        filter,
+        &ProgressBar::hidden(),
+        &mut sink,
+    );
+    let mut rows = sink.rows;
+    let mut has_errors = scanned.has_errors;
+    sort_violations(&mut rows, options.sort, checker);
+
+    if !options.quiet
+        && let Err(err) = write_csv(&rows)
+    {
+        eprintln!("{}: {}", program, err);
+        has_errors = true;
+    }
+
+    if options.summary {
+        scanned.summary.print(program, options.verbose);
+    }
+
+    outcome(scanned.has_violations, has_errors)
+}
 
-            for input in &inputs {
-                let name = input.name();
+/// Write a header row and one row per `(name, violation)` pair to stdout
+fn write_csv(rows: &[(String, Violation)]) -> Result<(), csv::Error> {
+    let mut writer = csv::Writer::from_writer(io::stdout().lock());
+    writer.write_record([
+        "file",
+        "line",
+        "column",
+        "codepoint",
+        "category",
+        "suggestion",
+    ])?;
+    for (name, v) in rows {
+        writer.write_record([
+            name,
+            &v.line.to_string(),
+            &v.column.to_string(),
+            &format!("U+{:04X}", v.ch as u32),
+            v.category.name(),
+            v.suggestion.as_deref().unwrap_or(""),
+        ])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Print a diff of the changes `--fix` would make, without writing
+fn fix_preview(
+    program: &str,
+    checker: &Checker,
+    inputs: &[InputSource],
+    options: &Options,
+) -> Outcome {
+    let mut has_changes = false;
+    let mut has_errors = false;
 
-                match input.check(|line, col, ch| {
-                    println!("{}:{}:{}: prohibited character '{}'", name, line, col, ch);
-                }) {
-                    Ok(found) => {
-                        if found {
-                            has_violations = true;
-                        }
+    for input in included(inputs, options) {
+        match checker.preview_input(input) {
+            Ok(edits) => {
+                if !edits.is_empty() {
+                    has_changes = true;
+                    if !options.quiet {
+                        print_diff(&options.report_name(input), &edits);
                     }
-                    Err(err) => {
-                        eprintln!("{}: {}", program, err);
-                        has_errors = true;
+                    if options.fail_fast {
+                        break;
                     }
                 }
             }
+            Err(err) => {
+                input_error(program, options, &err);
+                has_errors = true;
+                if options.stop_on_error {
+                    break;
+                }
+            }
+        }
+    }
+
+    outcome(has_changes, has_errors)
+}
+
+/// Fix inputs in place, reporting violations that could not be fixed
+fn fix(program: &str, checker: &Checker, inputs: &[InputSource], options: &Options) -> Outcome {
+    let mut has_violations = false;
+    let mut has_errors = false;
+    let color = options.color.enabled();
+    let end = options.terminator();
+
+    for input in included(inputs, options) {
+        let name = options.report_name(input);
+        let prefix = options.with_filename.then_some(&*name);
+        let mut found = false;
+
+        let result = checker.fix_input(input, |v| {
+            found |= fails(checker, options, v);
+            if options.quiet {
+                return;
+            }
+            let severity = checker.severity(v.category);
+            // Fixed stdin is streamed to stdout, so report leftovers on stderr
+            match input {
+                InputSource::Stdin => {
+                    eprint!(
+                        "{}{}",
+                        format_violation(prefix, v, severity, false, false),
+                        end
+                    )
+                }
+                InputSource::File(_) => {
+                    print!(
+                        "{}{}",
+                        format_violation(prefix, v, severity, color, false),
+                        end
+                    )
+                }
+            }
+        });
+        match result {
+            Ok(_) => {
+                if found {
+                    has_violations = true;
+                    if options.fail_fast {
+                        break;
+                    }
+                }
+            }
+            Err(err) => {
+                input_error(program, options, &err);
+                has_errors = true;
+                if options.stop_on_error {
+                    break;
+                }
+            }
+        }
+    }
+
+    outcome(has_violations, has_errors)
+}
+
+/// Prints text violations, applying `--max-violations` caps and `--group`
+struct Printer {
+    /// Caps on the number of violations printed
+    limit: Limit,
+    /// Whether to style output with ANSI colors
+    color: bool,
+    /// Whether to print violations under a header per input
+    group: bool,
+    /// Whether to prefix ungrouped violations with their input name
+    with_filename: bool,
+    /// Whether input names are `file://` URLs, under `--link`
+    link: bool,
+    /// Character ending each violation record, NUL under `--null`
+    terminator: char,
+    /// Violation lines for the current input, buffered under `--group`
+    grouped: Vec<String>,
+    /// Whether a group has been printed, so the next needs a separator
+    any_group: bool,
+    /// Whether to collapse repeated violations on a line under `--dedupe`
+    dedupe: bool,
+    /// Run of repeated violations not yet printed, under `--dedupe`
+    run: Option<Run>,
+}
+
+impl Printer {
+    /// Create a printer for the text or editor output `options`
+    fn new(options: &Options, format: OutputFormat) -> Self {
+        let editor = format == OutputFormat::Editor;
+        Printer {
+            limit: Limit::new(options),
+            color: options.color.enabled(),
+            group: options.group && !editor,
+            with_filename: options.with_filename || editor,
+            link: options.link,
+            terminator: options.terminator(),
+            grouped: Vec::new(),
+            any_group: false,
+            dedupe: options.dedupe,
+            run: None,
+        }
+    }
+
+    /// Print, or buffer for grouping, a violation in the input named `name`
+    ///
+    /// Under `--dedupe`, a violation repeating the previous one is counted
+    /// instead, and the run is printed once it ends.
+    fn violation(&mut self, name: &str, v: &Violation, severity: Severity) {
+        if !self.dedupe {
+            self.print(name, v, severity, 1);
+            return;
+        }
+        if let Some(run) = &mut self.run
+            && run.continues(name, v)
+        {
+            run.count += 1;
+            run.end = v.byte_offset + v.ch.len_utf8();
+            return;
+        }
+        self.end_run();
+        self.run = Some(Run {
+            name: name.to_owned(),
+            violation: v.clone(),
+            severity,
+            count: 1,
+            end: v.byte_offset + v.ch.len_utf8(),
+        });
+    }
+
+    /// Print the pending `--dedupe` run, if any
+    fn end_run(&mut self) {
+        if let Some(run) = self.run.take() {
+            self.print(&run.name, &run.violation, run.severity, run.count);
+        }
+    }
+
+    /// Print, or buffer for grouping, a violation occurring `count` times in
+    /// a row
+    fn print(&mut self, name: &str, v: &Violation, severity: Severity, count: usize) {
+        if !self.limit.admit() {
+            return;
+        }
+        let name = (self.with_filename && !self.group).then_some(name);
+        // Stdin has no URL, so keeps its plain `name:line:col` location
+        let link = self.link && name.is_some_and(|name| name.starts_with("file://"));
+        let mut message = format_violation(name, v, severity, self.color, link);
+        if count > 1 {
+            message.push_str(&format!(" ({} occurrences on this line)", count));
+        }
+        if self.group {
+            self.grouped.push(message);
+        } else {
+            print!("{}{}", message, self.terminator);
+        }
+    }
+
+    /// Finish the input named `name`, printing its group and any truncation
+    /// notice
+    fn finish_file(&mut self, name: &str) {
+        self.end_run();
+        if !self.grouped.is_empty() {
+            if self.any_group {
+                println!();
+            }
+            println!(
+                "{}",
+                paint(format_args!("== {} ==", name), BOLD, self.color)
+            );
+            for line in self.grouped.drain(..) {
+                println!("    {}", line);
+            }
+            self.any_group = true;
+        }
+        self.limit.finish_file();
+    }
+
+    /// Print that the input named `name` has no violations, for
+    /// `--show-clean`
+    fn clean(&mut self, name: &str) {
+        if self.group {
+            if self.any_group {
+                println!();
+            }
+            println!(
+                "{}",
+                paint(format_args!("== {} ==", name), BOLD, self.color)
+            );
+            println!("    clean");
+            self.any_group = true;
+        } else {
+            print!(
+                "{} clean{}",
+                paint(format_args!("{}:", name), BOLD, self.color),
+                self.terminator
+            );
+        }
+    }
+
+    /// Finish the run, printing any run-wide truncation notice
+    fn finish(&mut self) {
+        self.end_run();
+        self.limit.finish();
+    }
+}
+
+/// Consecutive violations of the same character on one line, collapsed by
+/// `--dedupe`
+#[derive(Debug)]
+struct Run {
+    /// Name of the input containing the run
+    name: String,
+    /// First violation of the run
+    violation: Violation,
+    /// Severity of the violations
+    severity: Severity,
+    /// Number of violations in the run
+    count: usize,
+    /// Byte offset just past the last violation of the run
+    end: usize,
+}
+
+impl Run {
+    /// Returns true if `v` in the input named `name` repeats this run
+    /// directly after its last violation
+    fn continues(&self, name: &str, v: &Violation) -> bool {
+        self.name == name
+            && self.end == v.byte_offset
+            && self.violation.category == v.category
+            && self.violation.ch == v.ch
+    }
+}
+
+/// Returns true if text output lists violations one by one, rather than
+/// printing nothing or only counts
+fn prints_violations(options: &Options) -> bool {
+    !options.quiet && !options.count && !options.stats
+}
+
+/// Sort `(name, violation)` pairs into the requested `--sort` order
+///
+/// Sorting by severity puts errors before warnings, then orders categories
+/// by [`CharCategory::priority`]. Sorting is stable, so violations that
+/// compare equal keep input order.
+fn sort_violations(violations: &mut [(String, Violation)], order: SortOrder, checker: &Checker) {
+    let position = |(name, v): &(String, Violation)| (name.clone(), v.line, v.column);
+    match order {
+        SortOrder::None => {}
+        SortOrder::Path => violations.sort_by_key(position),
+        SortOrder::Severity => violations.sort_by_key(|entry| {
+            let category = entry.1.category;
+            (
+                checker.severity(category),
+                category.priority(),
+                position(entry),
+            )
+        }),
+    }
+}
+
+/// Returns true if `v` fails the run: it is an error, or a warning under
+/// `--warnings-as-errors`
+fn fails(checker: &Checker, options: &Options, v: &Violation) -> bool {
+    options.warnings_as_errors || checker.severity(v.category) == Severity::Error
+}
+
+/// Violation counts accumulated across a run
+#[derive(Debug, Default)]
+struct Summary {
+    /// Total number of violations
+    violations: usize,
+    /// Number of inputs checked successfully
+    files: usize,
+    /// Number of inputs with at least one violation
+    affected_files: usize,
+    /// Violation counts per category
+    by_category: HashMap<CharCategory, usize>,
+}
 
-            if has_errors {
-                Outcome::Error
-            } else if has_violations {
-                Outcome::Violations
+impl Summary {
+    /// Count a single violation
+    fn record(&mut self, v: &Violation) {
+        self.violations += 1;
+        *self.by_category.entry(v.category).or_default() += 1;
+    }
+
+    /// Fold the counts for one successfully checked input into the run total
+    fn merge_file(&mut self, file: Summary) {
+        self.files += 1;
+        if file.violations > 0 {
+            self.affected_files += 1;
+        }
+        self.violations += file.violations;
+        for (category, count) in file.by_category {
+            *self.by_category.entry(category).or_default() += count;
+        }
+    }
+
+    /// Print a one-line summary to stderr, such as `4 violations in 2 files`
+    fn print_brief(&self, program: &str) {
+        if self.violations == 0 {
+            eprintln!(
+                "{}: no violations in {} {}",
+                program,
+                self.files,
+                plural(self.files, "file", "files")
+            );
+            return;
+        }
+        eprintln!(
+            "{}: {} {} in {} {}",
+            program,
+            self.violations,
+            plural(self.violations, "violation", "violations"),
+            self.affected_files,
+            plural(self.affected_files, "file", "files")
+        );
+    }
+
+    /// Print the summary to stderr, with per-category counts if `verbose`
+    fn print(&self, program: &str, verbose: bool) {
+        eprintln!(
+            "{}: {} {} in {} of {} {} ({} clean)",
+            program,
+            self.violations,
+            plural(self.violations, "violation", "violations"),
+            self.affected_files,
+            self.files,
+            plural(self.files, "file", "files"),
+            self.files - self.affected_files
+        );
+        if verbose {
+            for category in CharCategory::ALL {
+                if let Some(count) = self.by_category.get(category) {
+                    eprintln!("{}:   {}: {}", program, category, count);
+                }
+            }
+        }
+    }
+}
+
+/// Violation counts per character, for `--stats`
+#[derive(Debug, Default)]
+struct Stats {
+    /// Number of violations for each category and character
+    counts: HashMap<(CharCategory, char), usize>,
+}
+
+impl Stats {
+    /// Count a single violation
+    fn record(&mut self, v: &Violation) {
+        *self.counts.entry((v.category, v.ch)).or_default() += 1;
+    }
+
+    /// Print a table of counts to stdout, most frequent first, then a total
+    ///
+    /// Ties are ordered by category priority and then by code point. Invisible
+    /// characters are shown by their Unicode name.
+    fn print(&self) {
+        let mut rows: Vec<_> = self.counts.iter().collect();
+        rows.sort_by_key(|&(&(category, ch), &count)| {
+            (std::cmp::Reverse(count), category.priority(), ch)
+        });
+        let total: usize = self.counts.values().sum();
+        let count_width = total.to_string().len();
+        let category_width = rows
+            .iter()
+            .map(|((category, _), _)| category.name().len())
+            .max()
+            .unwrap_or(0);
+        for ((category, ch), count) in rows {
+            let shown = if is_visible(*ch) {
+                ch.to_string()
             } else {
-                Outcome::Success
+                unicode_names2::name(*ch).map_or_else(String::new, |name| name.to_string())
+            };
+            let row = format!(
+                "{:>count_width$}  {:<category_width$}  U+{:04X}  {}",
+                count,
+                category.name(),
+                *ch as u32,
+                shown
+            );
+            println!("{}", row.trim_end());
+        }
+        println!("{:>count_width$}  total", total);
+    }
+}
+
+/// Scan times logged at debug level, and printed under `--profile`, for
+/// profiling slow runs
+#[derive(Debug)]
+struct Timing {
+    /// Whether to print totals under `--profile`
+    profile: bool,
+    /// When the run began, for wall-clock time
+    started: Instant,
+    /// Number of inputs scanned
+    inputs: usize,
+    /// Size of the scanned input files; stdin is not counted
+    bytes: u64,
+    /// Total time spent scanning
+    elapsed: Duration,
+}
+
+impl Timing {
+    /// Start timing a run with `options`
+    fn new(options: &Options) -> Self {
+        Timing {
+            profile: options.profile,
+            started: Instant::now(),
+            inputs: 0,
+            bytes: 0,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Log the scan of `input`, reported as `name`, which began at `start`
+    fn record(&mut self, input: &InputSource, name: &str, start: Instant) {
+        if !self.profile && !log::log_enabled!(log::Level::Debug) {
+            return;
+        }
+        let elapsed = start.elapsed();
+        self.inputs += 1;
+        self.elapsed += elapsed;
+        match input {
+            InputSource::File(path) => {
+                let bytes = fs::metadata(path).map_or(0, |metadata| metadata.len());
+                self.bytes += bytes;
+                log::debug!("scanned {} ({} bytes) in {:.3?}", name, bytes, elapsed);
             }
+            InputSource::Stdin => log::debug!("scanned {} in {:.3?}", name, elapsed),
         }
-        Err(err) => {
-            eprintln!("{}: {}", program, err);
-            eprintln!("Try '{} --help' for more information.", program);
-            Outcome::Error
+    }
+
+    /// Log the totals for the run, and print them with the violations in
+    /// `summary` under `--profile`
+    fn finish(&self, program: &str, summary: &Summary) {
+        if self.inputs > 0 {
+            log::debug!(
+                "scanned {} {} ({} bytes) in {:.3?}",
+                self.inputs,
+                plural(self.inputs, "input", "inputs"),
+                self.bytes,
+                self.elapsed
+            );
+        }
+        if !self.profile {
+            return;
+        }
+        let wall = self.started.elapsed();
+        // A run too quick to measure has no meaningful rate
+        let throughput = match wall.as_secs_f64() {
+            secs if secs > 0.0 => self.bytes as f64 / secs / 1e6,
+            _ => 0.0,
+        };
+        eprintln!(
+            "{}: profile: {} {} ({} bytes) scanned, {} {} found, in {:.3?} ({:.2} MB/s)",
+            program,
+            self.inputs,
+            plural(self.inputs, "file", "files"),
+            self.bytes,
+            summary.violations,
+            plural(summary.violations, "violation", "violations"),
+            wall,
+            throughput
+        );
+    }
+}
+
+/// Caps on the number of violations printed by text output
+#[derive(Debug, Default)]
+struct Limit {
+    /// Maximum number of violations to print across the run
+    max: Option<usize>,
+    /// Maximum number of violations to print per input
+    max_per_file: Option<usize>,
+    /// Violations printed so far across the run
+    shown: usize,
+    /// Violations printed so far for the current input
+    file_shown: usize,
+    /// Violations withheld by the run-wide cap
+    hidden: usize,
+    /// Violations withheld by the per-input cap for the current input
+    file_hidden: usize,
+    /// Character ending each printed truncation notice
+    terminator: char,
+}
+
+impl Limit {
+    /// Create a limit from the `--max-violations` options
+    fn new(options: &Options) -> Self {
+        Limit {
+            max: options.max_violations,
+            max_per_file: options.max_violations_per_file,
+            terminator: options.terminator(),
+            ..Limit::default()
+        }
+    }
+
+    /// Returns true if another violation may be printed, counting it either way
+    fn admit(&mut self) -> bool {
+        if self.max_per_file.is_some_and(|max| self.file_shown >= max) {
+            self.file_hidden += 1;
+            false
+        } else if self.max.is_some_and(|max| self.shown >= max) {
+            self.hidden += 1;
+            false
+        } else {
+            self.shown += 1;
+            self.file_shown += 1;
+            true
         }
     }
+
+    /// Print the truncation notice for the current input, if any, and reset
+    fn finish_file(&mut self) {
+        print_truncated(self.file_hidden, self.terminator);
+        self.file_shown = 0;
+        self.file_hidden = 0;
+    }
+
+    /// Print the truncation notice for the whole run, if any
+    fn finish(&self) {
+        print_truncated(self.hidden, self.terminator);
+    }
+}
+
+/// Print a notice that `count` violations were not shown, ended by
+/// `terminator`
+fn print_truncated(count: usize, terminator: char) {
+    if count > 0 {
+        print!("... (truncated, {} more){}", count, terminator);
+    }
+}
+
+/// Report an input that could not be opened or read, only logging it under
+/// `--quiet-errors`
+///
+/// Either way the caller still counts the failure toward the exit status.
+fn input_error(program: &str, options: &Options, err: &impl fmt::Display) {
+    if options.quiet_errors {
+        log::debug!("{}", err);
+    } else {
+        eprintln!("{}: {}", program, err);
+    }
+}
+
+/// Continue scanning unless `stop` is set
+fn stop_if(stop: bool) -> ControlFlow<()> {
+    if stop {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+/// Choose the singular or plural form of a noun for `count`
+fn plural(count: usize, singular: &'static str, plural: &'static str) -> &'static str {
+    if count == 1 { singular } else { plural }
+}
+
+/// JSON report entry for one input
+#[derive(Debug, Serialize)]
+struct JsonFile {
+    file: String,
+    status: JsonStatus,
+    /// Why the input could not be checked, for [`JsonStatus::Error`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    violations: Vec<JsonViolation>,
+}
+
+/// Result of checking one input, as reported in JSON
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum JsonStatus {
+    /// Checked with no violations
+    Clean,
+    /// Checked with at least one violation
+    Violations,
+    /// Could not be checked
+    Error,
+}
+
+/// JSON representation of a single violation
+#[derive(Debug, Serialize)]
+struct JsonViolation {
+    line: usize,
+    column: usize,
+    byte_offset: usize,
+    char: char,
+    codepoint: String,
+    category: &'static str,
+    severity: &'static str,
+    suggestion: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    grapheme: Option<String>,
+}
+
+impl JsonViolation {
+    /// Describe `v`, reported at `severity`
+    fn new(v: &Violation, severity: Severity) -> Self {
+        JsonViolation {
+            line: v.line,
+            column: v.column,
+            byte_offset: v.byte_offset,
+            char: v.ch,
+            codepoint: format!("U+{:04X}", v.ch as u32),
+            category: v.category.name(),
+            severity: severity.name(),
+            suggestion: v.suggestion.clone(),
+            grapheme: v.grapheme.clone(),
+        }
+    }
+}
+
+/// ANSI escape starting bold text
+const BOLD: &str = "\x1b[1m";
+/// ANSI escape starting bold red text
+const BOLD_RED: &str = "\x1b[1;31m";
+/// ANSI escape starting bold yellow text
+const BOLD_YELLOW: &str = "\x1b[1;33m";
+/// ANSI escape resetting all styling
+const RESET: &str = "\x1b[0m";
+
+/// Wrap `text` in `style` if `color` is set
+fn paint(text: impl fmt::Display, style: &str, color: bool) -> String {
+    if color {
+        format!("{}{}{}", style, text, RESET)
+    } else {
+        text.to_string()
+    }
+}
+
+/// Format a violation as a `name:line:col: severity: message` line
+///
+/// Without a `name`, as under `--group`, the line starts at `line:col:`. With
+/// `link`, the name is a URL and the location a `name#LlineCcol` fragment.
+/// With `color`, the location is bold, the severity red or yellow, and the
+/// offending character red.
+fn format_violation(
+    name: Option<&str>,
+    v: &Violation,
+    severity: Severity,
+    color: bool,
+    link: bool,
+) -> String {
+    let location = match name {
+        Some(name) if link => paint(
+            format_args!("{}#L{}C{}:", name, v.line, v.column),
+            BOLD,
+            color,
+        ),
+        Some(name) => paint(
+            format_args!("{}:{}:{}:", name, v.line, v.column),
+            BOLD,
+            color,
+        ),
+        None => paint(format_args!("{}:{}:", v.line, v.column), BOLD, color),
+    };
+    let style = match severity {
+        Severity::Error => BOLD_RED,
+        Severity::Warning => BOLD_YELLOW,
+    };
+    let location = format!(
+        "{} {}",
+        location,
+        paint(format_args!("{}:", severity), style, color)
+    );
+    if v.category == CharCategory::MissingFinalNewline {
+        return format!("{} {}", location, v.category);
+    }
+    let suggestion = match (&v.suggestion, short_note(v.ch)) {
+        (Some(replacement), Some(note)) => format!("'{}' {}", replacement, note),
+        (Some(replacement), None) => format!("'{}'", replacement),
+        (None, _) => "remove".to_owned(),
+    };
+    // Name the whole cluster, since one part of an emoji sequence alone
+    // may look like a different emoji
+    let cluster = match &v.grapheme {
+        Some(grapheme) => format!(" in '{}'", grapheme),
+        None => String::new(),
+    };
+    format!(
+        "{} prohibited {} {}{} (suggest: {})",
+        location,
+        v.category,
+        paint(DisplayChar(v.ch), BOLD_RED, color),
+        cluster,
+        suggestion
+    )
+}
+
+/// Print proposed line edits for one input as a unified diff
+fn print_diff(name: &str, edits: &[LineEdit]) {
+    println!("--- {}", name);
+    println!("+++ {}", name);
+    for edit in edits {
+        println!("@@ -{} +{} @@", edit.line, edit.line);
+        println!("-{}", edit.original);
+        println!("+{}", edit.fixed);
+    }
+}
+
+/// Iterate over the inputs not skipped by `--exclude`
+fn included<'a>(
+    inputs: &'a [InputSource],
+    options: &'a Options,
+) -> impl Iterator<Item = &'a InputSource> {
+    inputs.iter().filter(move |input| {
+        let excluded = options.is_excluded(input);
+        if excluded {
+            log::debug!("excluded {}", input.name());
+        }
+        !excluded
+    })
+}
+
+/// Report a run with no inputs left to check, which is more likely a
+/// mistake than a clean tree, returning true if it fails under
+/// `--error-on-empty`
+fn empty(program: &str, inputs: &[InputSource], options: &Options) -> bool {
+    if included(inputs, options).next().is_some() {
+        return false;
+    }
+    if options.error_on_empty {
+        eprintln!("{}: no input files", program);
+    } else if !options.quiet && !options.hook {
+        eprintln!("{}: warning: no input files", program);
+    }
+    options.error_on_empty
+}
+
+/// Combine per-input results into the process outcome
+fn outcome(has_violations: bool, has_errors: bool) -> Outcome {
+    if has_errors {
+        Outcome::Error
+    } else if has_violations {
+        Outcome::Violations
+    } else {
+        Outcome::Success
+    }
 }
 
 // EOF
@@ -0,0 +1,52 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Report formats, shared by the command line and configuration
+
+use serde::Deserialize;
+
+/// Output format for violation reports
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// One `name:line:col: message` line per violation
+    #[default]
+    Text,
+    /// A JSON array with one object per input
+    Json,
+    /// A header row, then one comma-separated row per violation
+    Csv,
+    /// One `name:line:col: message` line per violation, always naming the
+    /// input and never grouped, for editors and terminals that turn
+    /// locations into links
+    Editor,
+}
+
+impl OutputFormat {
+    /// Accepted values, for error messages
+    pub(crate) const EXPECTED: &'static str = "text, json, csv, editor";
+
+    /// Returns the `--format` name of this format
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Json => "json",
+            Self::Csv => "csv",
+            Self::Editor => "editor",
+        }
+    }
+
+    /// Parse a `--format` value
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match value {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            "editor" => Some(Self::Editor),
+            _ => None,
+        }
+    }
+}
+
+// EOF
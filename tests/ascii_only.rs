@@ -0,0 +1,84 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--ascii-only` and the `non_ascii` rule
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+#[test]
+fn ascii_only_reports_international_text() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--ascii-only")
+        .write_stdin("café\n")
+        .assert()
+        .code(1)
+        .stdout(
+            "1:4: error: prohibited non-ASCII character 'é' U+00E9 LATIN SMALL LETTER E WITH ACUTE (suggest: remove)\n",
+        );
+}
+
+#[test]
+fn ascii_only_reports_currency_symbols() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--ascii-only")
+        .write_stdin("costs €5\n")
+        .assert()
+        .code(1)
+        .stdout(
+            "1:7: error: prohibited non-ASCII character '€' U+20AC EURO SIGN (suggest: remove)\n",
+        );
+}
+
+#[test]
+fn ascii_only_passes_plain_ascii() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--ascii-only")
+        .write_stdin("plain ASCII, $5 & (c) 2024\n")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn ascii_only_keeps_specific_categories() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--ascii-only")
+        .write_stdin("a → b\n")
+        .assert()
+        .code(1)
+        .stdout("1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n");
+}
+
+#[test]
+fn exceptions_are_allowed_without_ascii_only() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("café costs €5\n")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn config_rule_enables_strict_mode_and_allow_list_still_applies() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "inherit = false\nallow = [\"é\"]\n\n[rules]\nnon_ascii = true\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .write_stdin("café costs €5\n")
+        .assert()
+        .code(1)
+        .stdout(
+            "1:12: error: prohibited non-ASCII character '€' U+20AC EURO SIGN (suggest: remove)\n",
+        );
+}
+
+// EOF
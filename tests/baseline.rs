@@ -0,0 +1,210 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--baseline`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// Create a directory with a violating file and a baseline recording it
+fn baselined_dir() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("notes.txt"), "a → b\nc ✓ d\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "--baseline",
+            "baseline.json",
+            "--write-baseline",
+            "notes.txt",
+        ])
+        .assert()
+        .success()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "recorded 2 violations in baseline.json",
+        ));
+    temp_dir
+}
+
+#[test]
+fn baselined_violations_pass() {
+    let temp_dir = baselined_dir();
+    // Moving a known violation to another line keeps it known
+    fs::write(temp_dir.path().join("notes.txt"), "c ✓ d\n\na → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--baseline", "baseline.json", "notes.txt"])
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn new_violations_fail() {
+    let temp_dir = baselined_dir();
+    fs::write(temp_dir.path().join("notes.txt"), "a → b\nc ✓ d\ne → f\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--baseline", "baseline.json", "notes.txt"])
+        .assert()
+        .code(1)
+        .stdout("3:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n");
+}
+
+#[test]
+fn baseline_applies_to_json_output() {
+    let temp_dir = baselined_dir();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "--baseline",
+            "baseline.json",
+            "--format",
+            "json",
+            "notes.txt",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"violations\": []"));
+}
+
+#[test]
+fn prune_baseline_drops_fixed_violations() {
+    let temp_dir = baselined_dir();
+    fs::write(temp_dir.path().join("notes.txt"), "a -> b\nc ✓ d\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "--baseline",
+            "baseline.json",
+            "--prune-baseline",
+            "notes.txt",
+        ])
+        .assert()
+        .success();
+
+    let baseline = fs::read_to_string(temp_dir.path().join("baseline.json")).unwrap();
+    assert!(!baseline.contains("\"arrows\""));
+    assert!(baseline.contains("\"checkmarks\""));
+
+    // The pruned arrow is no longer covered
+    fs::write(temp_dir.path().join("notes.txt"), "a → b\nc ✓ d\n").unwrap();
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--baseline", "baseline.json", "notes.txt"])
+        .assert()
+        .code(1);
+}
+
+#[test]
+fn prune_baseline_keeps_entries_for_unchecked_files() {
+    let temp_dir = baselined_dir();
+    fs::write(temp_dir.path().join("other.txt"), "e → f\n").unwrap();
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "--baseline",
+            "baseline.json",
+            "--write-baseline",
+            "notes.txt",
+            "other.txt",
+        ])
+        .assert()
+        .success();
+
+    // Prune while checking only notes.txt, whose arrow has been fixed
+    fs::write(temp_dir.path().join("notes.txt"), "a -> b\nc ✓ d\n").unwrap();
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "--baseline",
+            "baseline.json",
+            "--prune-baseline",
+            "notes.txt",
+        ])
+        .assert()
+        .success();
+
+    // other.txt was not checked, so its arrow is still covered
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--baseline", "baseline.json", "notes.txt", "other.txt"])
+        .assert()
+        .success()
+        .stdout("");
+
+    // ...while the arrow pruned from notes.txt is not
+    fs::write(temp_dir.path().join("notes.txt"), "a → b\nc ✓ d\n").unwrap();
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--baseline", "baseline.json", "notes.txt", "other.txt"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("notes.txt:1:3"));
+}
+
+#[test]
+fn prune_baseline_drops_entries_for_deleted_files() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a → b\n").unwrap();
+    fs::write(temp_dir.path().join("b.txt"), "c ✓ d\n").unwrap();
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "--baseline",
+            "bl.json",
+            "--write-baseline",
+            "a.txt",
+            "b.txt",
+        ])
+        .assert()
+        .success();
+
+    fs::remove_file(temp_dir.path().join("b.txt")).unwrap();
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--baseline", "bl.json", "--prune-baseline", "a.txt"])
+        .assert()
+        .success();
+
+    let baseline = fs::read_to_string(temp_dir.path().join("bl.json")).unwrap();
+    assert!(baseline.contains("a.txt"));
+    assert!(!baseline.contains("b.txt"));
+}
+
+#[test]
+fn missing_baseline_file_is_an_error() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--baseline", "baseline.json"])
+        .write_stdin("plain\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("baseline.json"));
+}
+
+#[test]
+fn write_baseline_requires_baseline() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--write-baseline")
+        .write_stdin("plain\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "option '--write-baseline' requires '--baseline'",
+        ));
+}
+
+// EOF
@@ -0,0 +1,147 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for skipping binary inputs
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// Bytes resembling an image header, with NULs and invalid UTF-8
+const BINARY: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR\xff\xfe\xe2\x86\x92";
+
+#[test]
+fn binary_file_is_skipped() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("image.png"), BINARY).unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("image.png")
+        .assert()
+        .success()
+        .stdout("")
+        .stderr("");
+}
+
+#[test]
+fn binary_file_does_not_hide_text_violations() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("image.png"), BINARY).unwrap();
+    fs::write(temp_dir.path().join("notes.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["image.png", "notes.txt"])
+        .assert()
+        .code(1)
+        .stdout(
+            "notes.txt:1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n",
+        );
+}
+
+#[test]
+fn binary_stdin_is_skipped() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin(BINARY).assert().success().stdout("");
+}
+
+#[test]
+fn binary_stdin_passes_through_fix_unchanged() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix")
+        .write_stdin(BINARY)
+        .assert()
+        .success()
+        .stdout(BINARY);
+}
+
+#[test]
+fn no_binary_skip_scans_binary_file() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("image.png"), BINARY).unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--no-binary-skip", "image.png"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("valid UTF-8"));
+}
+
+/// Text in which 2 of 11 bytes are control characters, about 18%
+const BORDERLINE: &[u8] = b"a\x01\x02 \xe2\x86\x92 bc\n";
+
+#[test]
+fn binary_threshold_flips_skip_decision() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("odd.txt"), BORDERLINE).unwrap();
+
+    for threshold in ["0.1", "0.15"] {
+        let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+        cmd.current_dir(temp_dir.path())
+            .args(["--binary-threshold", threshold, "odd.txt"])
+            .assert()
+            .success()
+            .stdout("");
+    }
+    for threshold in ["0.2", "0.5"] {
+        let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+        cmd.current_dir(temp_dir.path())
+            .args(["--binary-threshold", threshold, "odd.txt"])
+            .assert()
+            .code(1)
+            .stdout(predicate::str::contains("U+2192 RIGHTWARDS ARROW"));
+    }
+}
+
+#[test]
+fn binary_threshold_can_be_configured() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("odd.txt"), BORDERLINE).unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "binary_threshold = 0.25\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("odd.txt")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("U+2192 RIGHTWARDS ARROW"));
+
+    // The command line overrides the configuration
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--binary-threshold", "0.1", "odd.txt"])
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn binary_threshold_of_one_never_skips() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--binary-threshold", "1.0"])
+        .write_stdin("\0a → b\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("U+2192 RIGHTWARDS ARROW"));
+}
+
+#[test]
+fn binary_threshold_rejects_ratio_above_one() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--binary-threshold", "1.5"])
+        .write_stdin("a\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("1.5"));
+}
+
+// EOF
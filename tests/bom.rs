@@ -0,0 +1,62 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for UTF-8 byte order mark handling
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+#[test]
+fn bom_is_not_counted_in_columns() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("bom.txt"), "\u{FEFF}→ first\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("bom.txt")
+        .assert()
+        .code(1)
+        .stdout("1:1: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n");
+}
+
+#[test]
+fn bom_alone_is_clean_by_default() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("\u{FEFF}plain text\n")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn check_bom_reports_leading_bom() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--check-bom")
+        .write_stdin("\u{FEFF}plain text\n")
+        .assert()
+        .code(1)
+        .stdout(
+            "1:1: error: prohibited byte order mark U+FEFF ZERO WIDTH NO-BREAK SPACE \
+             (suggest: remove)\n",
+        );
+}
+
+#[test]
+fn check_bom_fix_removes_bom() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("bom.txt");
+    fs::write(&path, "\u{FEFF}plain text\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--fix", "--check-bom"])
+        .arg(&path)
+        .assert()
+        .success();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "plain text\n");
+}
+
+// EOF
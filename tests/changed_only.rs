@@ -0,0 +1,94 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--changed-only`
+
+use std::{fs, path::Path, process};
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// Run git in `dir` with a fixed identity, panicking on failure
+fn git(dir: &Path, args: &[&str]) {
+    let status = process::Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .status()
+        .unwrap();
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+/// Create a repository with a violation in each of two committed files
+fn repo() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    git(temp_dir.path(), &["init", "-q"]);
+    fs::write(temp_dir.path().join("changed.txt"), "old → line\n").unwrap();
+    fs::write(temp_dir.path().join("same.txt"), "untouched ✓\n").unwrap();
+    git(temp_dir.path(), &["add", "."]);
+    git(temp_dir.path(), &["commit", "-q", "-m", "initial"]);
+    temp_dir
+}
+
+#[test]
+fn changed_only_checks_whole_modified_files() {
+    let temp_dir = repo();
+    fs::write(temp_dir.path().join("changed.txt"), "old → line\nnew\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--changed-only")
+        .assert()
+        .code(1)
+        .stdout(
+            "changed.txt:1:5: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n",
+        );
+}
+
+#[test]
+fn changed_only_includes_staged_files_and_ignores_arguments() {
+    let temp_dir = repo();
+    fs::write(temp_dir.path().join("changed.txt"), "old → line\nnew\n").unwrap();
+    git(temp_dir.path(), &["add", "changed.txt"]);
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--changed-only", "same.txt"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::starts_with("changed.txt:1:5:"))
+        .stdout(predicate::str::contains("same.txt").not());
+}
+
+#[test]
+fn changed_only_with_clean_tree_checks_nothing() {
+    let temp_dir = repo();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--changed-only")
+        .assert()
+        .success()
+        .stdout("")
+        .stderr("noemoji: warning: no input files\n");
+}
+
+#[test]
+fn changed_only_fails_outside_repository() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .env("GIT_CEILING_DIRECTORIES", temp_dir.path().parent().unwrap())
+        .arg("--changed-only")
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr(predicate::str::starts_with(
+            "noemoji: git diff HEAD failed: ",
+        ));
+}
+
+// EOF
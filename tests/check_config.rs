@@ -0,0 +1,76 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--check-config`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// Create a tree with a valid config at the root and an invalid one in `sub`
+fn config_tree() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "inherit = false\nallow = [\"≤\"]\n\n[rules]\npunctuation = false\n",
+    )
+    .unwrap();
+    fs::create_dir(temp_dir.path().join("sub")).unwrap();
+    fs::write(temp_dir.path().join("sub/.noemoji.toml"), "[rules\n").unwrap();
+    temp_dir
+}
+
+#[test]
+fn check_config_prints_sources_and_effective_config() {
+    let temp_dir = config_tree();
+    let config_path = temp_dir.path().join(".noemoji.toml");
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--check-config")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "#   {}\n",
+            config_path.display()
+        )))
+        .stdout(predicate::str::contains("allow = [\"U+2264\"]\n"))
+        .stdout(predicate::str::contains("punctuation = false\n"))
+        .stdout(predicate::str::contains("arrows = true\n"));
+}
+
+#[test]
+fn check_config_reports_invalid_file() {
+    let temp_dir = config_tree();
+    let bad_path = temp_dir.path().join("sub/.noemoji.toml");
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path().join("sub"))
+        .arg("--check-config")
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr(predicate::str::contains(format!(
+            "{}: Invalid TOML configuration",
+            bad_path.display()
+        )));
+}
+
+#[test]
+fn check_config_fills_in_defaults() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join(".noemoji.toml"), "inherit = false\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--check-config")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("level = \"off\"\n"))
+        .stdout(predicate::str::contains("final_newline = false\n"));
+}
+
+// EOF
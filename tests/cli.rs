@@ -64,6 +64,31 @@ fn version_output_format_is_standard() {
         .stdout(predicates::str::is_match(r"^noemoji \d+\.\d+\.\d+").unwrap());
 }
 
+#[test]
+fn version_output_is_one_line() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--version")
+        .assert()
+        .success()
+        .stdout(format!("noemoji {}\n", env!("CARGO_PKG_VERSION")));
+}
+
+#[test]
+fn verbose_version_shows_build_metadata() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--version", "--verbose"])
+        .assert()
+        .success()
+        .stdout(predicates::str::starts_with(format!(
+            "noemoji {}\n",
+            env!("CARGO_PKG_VERSION")
+        )))
+        // Builds from a source archive have no git metadata
+        .stdout(predicates::str::is_match(r"(?m)^commit: ([0-9a-f]{7,}|unknown)$").unwrap())
+        .stdout(predicates::str::is_match(r"(?m)^build date: \d{4}-\d{2}-\d{2}$").unwrap())
+        .stdout(predicates::str::contains("rustc: rustc "));
+}
+
 #[test]
 fn with_args_exits_success() {
     let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
@@ -110,4 +135,25 @@ fn error_message_suggests_help() {
         .stderr(predicates::str::contains("-h"));
 }
 
+#[test]
+fn quiet_flag_suppresses_violation_output() {
+    let mut cmd = assert_cmd::Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--quiet")
+        .write_stdin("a → b\n")
+        .assert()
+        .code(1)
+        .stdout("");
+}
+
+#[test]
+fn quiet_flag_still_reports_errors() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("-q")
+        .arg("nonexistent_file_quiet.txt")
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr(predicates::str::contains("nonexistent_file_quiet.txt"));
+}
+
 // EOF
@@ -0,0 +1,40 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--color`
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+
+#[test]
+fn color_never_emits_no_escape_codes() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--color", "never"])
+        .write_stdin("a → b\n")
+        .assert()
+        .code(1)
+        .stdout("1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n");
+}
+
+#[test]
+fn color_always_styles_location_severity_and_character() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--color", "always"])
+        .env("NO_COLOR", "1")
+        .write_stdin("a → b\n")
+        .assert()
+        .code(1)
+        .stdout("\x1b[1m1:3:\x1b[0m \x1b[1;31merror:\x1b[0m prohibited arrow \x1b[1;31m'→' U+2192 RIGHTWARDS ARROW\x1b[0m (suggest: '->')\n");
+}
+
+#[test]
+fn color_auto_is_plain_when_not_a_terminal() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("a → b\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("\x1b[").not());
+}
+
+// EOF
@@ -0,0 +1,119 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for reported column numbering
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+/// "café" with the accent as a combining mark, followed by an arrow
+const COMBINING: &str = "cafe\u{301} → x\n";
+
+#[test]
+fn column_defaults_to_chars() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin(COMBINING)
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("1:7:"));
+}
+
+#[test]
+fn column_grapheme_counts_combining_sequence_once() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--column=grapheme")
+        .write_stdin(COMBINING)
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("1:6:"));
+}
+
+#[test]
+fn column_byte_counts_utf8_bytes() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--column")
+        .arg("byte")
+        .write_stdin(COMBINING)
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("1:8:"));
+}
+
+#[test]
+fn column_mode_from_config_is_overridden_by_flag() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "column = \"grapheme\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .write_stdin(COMBINING)
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("1:6:"));
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--column=char")
+        .write_stdin(COMBINING)
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("1:7:"));
+}
+
+#[test]
+fn column_rejects_unknown_unit() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--column=glyph")
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("glyph"));
+}
+
+#[test]
+fn tab_width_expands_tabs() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--tab-width=4")
+        .write_stdin("\t→\n")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("1:5:"));
+}
+
+#[test]
+fn tab_width_defaults_to_eight() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("\t→\n")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("1:9:"));
+}
+
+#[test]
+fn crlf_file_reports_correct_column_and_offset() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("windows.txt");
+    fs::write(&path, "a→\r\nb\r\n→\r\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let output = cmd.arg("--format=json").arg(&path).output().unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let violations = json[0]["violations"].as_array().unwrap();
+    assert_eq!(violations.len(), 2);
+    assert_eq!(violations[0]["line"], 1);
+    assert_eq!(violations[0]["column"], 2);
+    assert_eq!(violations[0]["byte_offset"], 1);
+    assert_eq!(violations[1]["line"], 3);
+    assert_eq!(violations[1]["column"], 1);
+    assert_eq!(violations[1]["byte_offset"], 9);
+}
+
+// EOF
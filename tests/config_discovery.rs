@@ -162,4 +162,81 @@ fn load_config_prefers_closer_config_file() {
     assert_eq!(config.log.level, Some(noemoji::logging::LogLevel::Debug));
 }
 
+#[test]
+fn load_config_reports_sources_child_first() {
+    let temp_dir = TempDir::new().unwrap();
+
+    // Stop the search at the parent so configs above the temp dir don't leak in
+    let parent_config = temp_dir.path().join(".noemoji.toml");
+    let mut file = File::create(&parent_config).unwrap();
+    writeln!(file, "inherit = false").unwrap();
+    writeln!(file, "[log]").unwrap();
+    writeln!(file, "level = \"info\"").unwrap();
+
+    let sub_dir = temp_dir.path().join("subdir");
+    fs::create_dir(&sub_dir).unwrap();
+    let sub_config = sub_dir.join(".noemoji.toml");
+    let mut file = File::create(&sub_config).unwrap();
+    writeln!(file, "[rules]").unwrap();
+    writeln!(file, "emoji = false").unwrap();
+
+    let (config, sources) = Config::load_from_with_sources(&sub_dir).unwrap();
+
+    assert_eq!(sources, vec![sub_config, parent_config]);
+    assert_eq!(config.log.level, Some(noemoji::logging::LogLevel::Info));
+    assert_eq!(config.rules.emoji, Some(false));
+}
+
+#[test]
+fn load_config_reads_tool_table_without_dotfile() {
+    let temp_dir = TempDir::new().unwrap();
+    let pyproject = temp_dir.path().join("pyproject.toml");
+    fs::write(
+        &pyproject,
+        "[project]\nname = \"demo\"\n\n[tool.noemoji]\ninherit = false\n\n[tool.noemoji.log]\nlevel = \"debug\"\n",
+    )
+    .unwrap();
+
+    let (config, sources) = Config::load_from_with_sources(temp_dir.path()).unwrap();
+
+    assert_eq!(sources, vec![pyproject]);
+    assert_eq!(config.log.level, Some(noemoji::logging::LogLevel::Debug));
+}
+
+#[test]
+fn load_config_prefers_dotfile_over_tool_table() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("pyproject.toml"),
+        "[tool.noemoji.log]\nlevel = \"debug\"\n",
+    )
+    .unwrap();
+    let dotfile = temp_dir.path().join(".noemoji.toml");
+    fs::write(&dotfile, "inherit = false\n[log]\nlevel = \"warn\"\n").unwrap();
+
+    let (config, sources) = Config::load_from_with_sources(temp_dir.path()).unwrap();
+
+    assert_eq!(sources, vec![dotfile]);
+    assert_eq!(config.log.level, Some(noemoji::logging::LogLevel::Warn));
+}
+
+#[test]
+fn load_config_skips_shared_file_without_tool_table() {
+    let temp_dir = TempDir::new().unwrap();
+    let sub_dir = temp_dir.path().join("subdir");
+    fs::create_dir(&sub_dir).unwrap();
+    fs::write(
+        sub_dir.join("pyproject.toml"),
+        "[tool.black]\nline-length = 88\n",
+    )
+    .unwrap();
+    let parent_config = temp_dir.path().join(".noemoji.toml");
+    fs::write(&parent_config, "inherit = false\n[log]\nlevel = \"info\"\n").unwrap();
+
+    let (config, sources) = Config::load_from_with_sources(&sub_dir).unwrap();
+
+    assert_eq!(sources, vec![parent_config]);
+    assert_eq!(config.log.level, Some(noemoji::logging::LogLevel::Info));
+}
+
 // EOF
@@ -0,0 +1,120 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for the `NOEMOJI_CONFIG` environment variable
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// Create a project whose `.noemoji.toml` sets the log level to warn and
+/// disables emoji, and a separate file setting the log level to debug
+fn project_and_env_config() -> (TempDir, TempDir) {
+    let project_dir = TempDir::new().unwrap();
+    fs::write(
+        project_dir.path().join(".noemoji.toml"),
+        "inherit = false\n\n[log]\nlevel = \"warn\"\n\n[rules]\nemoji = false\n",
+    )
+    .unwrap();
+    let env_dir = TempDir::new().unwrap();
+    fs::write(env_dir.path().join("ci.toml"), "[log]\nlevel = \"debug\"\n").unwrap();
+    (project_dir, env_dir)
+}
+
+#[test]
+fn env_config_log_level_wins_over_project_config() {
+    let (project_dir, env_dir) = project_and_env_config();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(project_dir.path())
+        .env("NOEMOJI_CONFIG", env_dir.path().join("ci.toml"))
+        .write_stdin("plain\n")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "noemoji[debug]: logger initialized",
+        ));
+}
+
+#[test]
+fn env_config_layers_over_discovered_config() {
+    let (project_dir, env_dir) = project_and_env_config();
+    let env_path = env_dir.path().join("ci.toml");
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(project_dir.path())
+        .env("NOEMOJI_CONFIG", &env_path)
+        .arg("--check-config")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "#   {}\n#   {}\n",
+            env_path.display(),
+            project_dir.path().join(".noemoji.toml").display()
+        )))
+        .stdout(predicate::str::contains("level = \"debug\"\n"))
+        // Left unset by the environment's file, so the project's value holds
+        .stdout(predicate::str::contains("emoji = false\n"));
+}
+
+#[test]
+fn missing_env_config_is_reported_by_check_config() {
+    let project_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(project_dir.path())
+        .env("NOEMOJI_CONFIG", project_dir.path().join("missing.toml"))
+        .arg("--check-config")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("missing.toml"));
+}
+
+#[test]
+fn missing_env_config_is_an_error_when_checking() {
+    let project_dir = TempDir::new().unwrap();
+    fs::write(project_dir.path().join("a.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(project_dir.path())
+        .env("NOEMOJI_CONFIG", project_dir.path().join("missing.toml"))
+        .arg("a.txt")
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr(predicate::str::contains("missing.toml: "));
+}
+
+#[test]
+fn invalid_env_config_is_an_error_when_checking() {
+    let project_dir = TempDir::new().unwrap();
+    fs::write(project_dir.path().join("a.txt"), "a → b\n").unwrap();
+    fs::write(project_dir.path().join("ci.toml"), "bogus = [\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(project_dir.path())
+        .env("NOEMOJI_CONFIG", project_dir.path().join("ci.toml"))
+        .arg("a.txt")
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr(predicate::str::contains("ci.toml: "));
+}
+
+#[test]
+fn config_flag_ignores_env_config() {
+    let (project_dir, env_dir) = project_and_env_config();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(project_dir.path())
+        .env("NOEMOJI_CONFIG", env_dir.path().join("ci.toml"))
+        .args(["--config", ".noemoji.toml", "--check-config"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("level = \"warn\"\n"));
+}
+
+// EOF
@@ -0,0 +1,90 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--config` and `--no-config`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn config_flag_applies_file_log_level() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("ci.toml");
+    fs::write(&config_path, "[log]\nlevel = \"debug\"\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .env_remove("NOEMOJI_LOG")
+        .arg("--config")
+        .arg(&config_path)
+        .write_stdin("plain text\n")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("logger initialized"));
+}
+
+#[test]
+fn config_flag_skips_directory_search() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "[log]\nlevel = \"debug\"\n",
+    )
+    .unwrap();
+    let config_path = temp_dir.path().join("ci.toml");
+    fs::write(&config_path, "[rules]\nemoji = false\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .env_remove("NOEMOJI_LOG")
+        .arg("--config")
+        .arg(&config_path)
+        .write_stdin("Ship it 🚀\n")
+        .assert()
+        .success()
+        .stdout("")
+        .stderr("");
+}
+
+#[test]
+fn missing_config_file_is_an_error() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--config", "missing.toml"])
+        .write_stdin("plain text\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("missing.toml"));
+}
+
+#[test]
+fn no_config_ignores_adjacent_config_file() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "[rules]\nemoji = false\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .write_stdin("Ship it 🚀\n")
+        .assert()
+        .success();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--no-config")
+        .write_stdin("Ship it 🚀\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("prohibited emoji"));
+}
+
+// EOF
@@ -10,10 +10,12 @@ fn config_or_none_base_none_other_returns_none() {
     let base = Config {
         log: LogConfig { level: None },
         inherit: true,
+        ..Config::default()
     };
     let other = Config {
         log: LogConfig { level: None },
         inherit: true,
+        ..Config::default()
     };
 
     let result = base.or(other);
@@ -26,12 +28,14 @@ fn config_or_none_base_some_other_returns_other() {
     let base = Config {
         log: LogConfig { level: None },
         inherit: true,
+        ..Config::default()
     };
     let other = Config {
         log: LogConfig {
             level: Some(LogLevel::Debug),
         },
         inherit: false,
+        ..Config::default()
     };
 
     let result = base.or(other);
@@ -48,10 +52,12 @@ fn config_or_some_base_none_other_returns_base() {
             level: Some(LogLevel::Error),
         },
         inherit: false,
+        ..Config::default()
     };
     let other = Config {
         log: LogConfig { level: None },
         inherit: true,
+        ..Config::default()
     };
 
     let result = base.or(other);
@@ -68,12 +74,14 @@ fn config_or_some_base_some_other_returns_base() {
             level: Some(LogLevel::Error),
         },
         inherit: false,
+        ..Config::default()
     };
     let other = Config {
         log: LogConfig {
             level: Some(LogLevel::Debug),
         },
         inherit: true,
+        ..Config::default()
     };
 
     let result = base.or(other);
@@ -234,3 +242,73 @@ level = "debug"
     // Should get the most specific (child) config value
     assert_eq!(result.log.level, Some(LogLevel::Debug));
 }
+
+#[test]
+fn config_load_rules_merge_field_by_field() {
+    use std::fs;
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let parent_dir = temp_dir.path();
+    let child_dir = parent_dir.join("subdir");
+    fs::create_dir_all(&child_dir).unwrap();
+
+    // Parent disables punctuation and arrows
+    let parent_config = r#"
+[rules]
+punctuation = false
+arrows = false
+"#;
+    fs::write(parent_dir.join(".noemoji.toml"), parent_config).unwrap();
+
+    // Child re-enables arrows only
+    let child_config = r#"
+[rules]
+arrows = true
+"#;
+    fs::write(child_dir.join(".noemoji.toml"), child_config).unwrap();
+
+    let result = Config::load_from(child_dir).unwrap();
+
+    // Child overrides arrows; punctuation falls through from parent
+    assert_eq!(result.rules.arrows, Some(true));
+    assert_eq!(result.rules.punctuation, Some(false));
+    assert_eq!(result.rules.emoji, None);
+}
+
+#[test]
+fn config_load_from_ignores_global_config() {
+    use std::fs;
+    use tempfile::tempdir;
+
+    let project_dir = tempdir().unwrap();
+    let global_dir = tempdir().unwrap();
+    let global = global_dir.path().join("config.toml");
+    fs::write(&global, "[log]\nlevel = \"debug\"\n").unwrap();
+
+    assert_eq!(
+        Config::load_from(project_dir.path()).unwrap(),
+        Config::default()
+    );
+    let result = Config::load_from_with_global(project_dir.path(), Some(&global), |_| {}).unwrap();
+    assert_eq!(result.log.level, Some(LogLevel::Debug));
+}
+
+#[test]
+fn config_global_config_ignored_when_inheritance_stops() {
+    use std::fs;
+    use tempfile::tempdir;
+
+    let project_dir = tempdir().unwrap();
+    fs::write(
+        project_dir.path().join(".noemoji.toml"),
+        "inherit = false\n",
+    )
+    .unwrap();
+    let global_dir = tempdir().unwrap();
+    let global = global_dir.path().join("config.toml");
+    fs::write(&global, "[log]\nlevel = \"debug\"\n").unwrap();
+
+    let result = Config::load_from_with_global(project_dir.path(), Some(&global), |_| {}).unwrap();
+    assert_eq!(result.log.level, None);
+}
@@ -0,0 +1,66 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for the opt-in ASCII confusables rule
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+#[test]
+fn confusables_are_clean_by_default() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("let Ａpple = 1;\n")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn check_confusables_reports_full_width_letter_in_ascii_word() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--check-confusables")
+        .write_stdin("let Ａpple = 1;\n")
+        .assert()
+        .code(1)
+        .stdout(
+            "1:5: error: prohibited ASCII confusable 'Ａ' U+FF21 \
+             FULLWIDTH LATIN CAPITAL LETTER A (suggest: 'A')\n",
+        );
+}
+
+#[test]
+fn check_confusables_skips_genuine_cyrillic_text() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--check-confusables")
+        .write_stdin("Это сор, а не код.\n")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn config_enables_confusables_and_fix_replaces_them() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "inherit = false\n\n[rules]\nconfusables = true\n",
+    )
+    .unwrap();
+    // Cyrillic а in an English identifier
+    fs::write(temp_dir.path().join("test.txt"), "let p\u{0430}ss = 1;\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--fix", "test.txt"])
+        .assert()
+        .success();
+    assert_eq!(
+        fs::read_to_string(temp_dir.path().join("test.txt")).unwrap(),
+        "let pass = 1;\n"
+    );
+}
+
+// EOF
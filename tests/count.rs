@@ -0,0 +1,59 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--count`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn count_prints_only_the_total() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--count")
+        .write_stdin("a \u{2192} b\n\u{2713} done \u{1F680}\n")
+        .assert()
+        .code(1)
+        .stdout("3\n");
+}
+
+#[test]
+fn count_of_clean_input_is_zero() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--count")
+        .write_stdin("clean\n")
+        .assert()
+        .success()
+        .stdout("0\n");
+}
+
+#[test]
+fn count_with_group_prints_a_row_per_file() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "\u{2192} \u{2190}\n").unwrap();
+    fs::write(temp_dir.path().join("b.txt"), "clean\n").unwrap();
+
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .args(["--count", "--group", "a.txt", "b.txt"])
+        .assert()
+        .code(1)
+        .stdout("a.txt\t2\nb.txt\t0\n");
+}
+
+#[test]
+fn count_conflicts_with_json() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--count", "--format", "json"])
+        .write_stdin("")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "options '--count' and '--format json' cannot be used together",
+        ));
+}
+
+// EOF
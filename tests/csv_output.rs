@@ -0,0 +1,75 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--format csv` output
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+/// Parse CSV output into its header and rows
+fn parse(stdout: &[u8]) -> (csv::StringRecord, Vec<csv::StringRecord>) {
+    let mut reader = csv::Reader::from_reader(stdout);
+    let header = reader.headers().unwrap().clone();
+    let rows = reader.records().map(Result::unwrap).collect();
+    (header, rows)
+}
+
+#[test]
+fn csv_output_reports_arrow() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let output = cmd
+        .args(["--format", "csv"])
+        .write_stdin("a → b\n")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let (header, rows) = parse(&output.stdout);
+    assert_eq!(
+        header,
+        vec![
+            "file",
+            "line",
+            "column",
+            "codepoint",
+            "category",
+            "suggestion"
+        ]
+    );
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0], vec!["stdin", "1", "3", "U+2192", "arrow", "->"]);
+}
+
+#[test]
+fn csv_output_quotes_paths_with_commas() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a,b.txt"), "🚀\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let output = cmd
+        .current_dir(temp_dir.path())
+        .args(["--format", "csv", "a,b.txt"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout.clone()).unwrap();
+    assert!(stdout.contains("\"a,b.txt\""), "{stdout}");
+    let (_, rows) = parse(&output.stdout);
+    assert_eq!(rows[0], vec!["a,b.txt", "1", "1", "U+1F680", "emoji", ""]);
+}
+
+#[test]
+fn csv_output_has_only_header_when_clean() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--format", "csv"])
+        .write_stdin("a -> b\n")
+        .assert()
+        .success()
+        .stdout("file,line,column,codepoint,category,suggestion\n");
+}
+
+// EOF
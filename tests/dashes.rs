@@ -0,0 +1,66 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for the distinct handling of en and em dashes
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn dashes_have_distinct_suggestions() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("pages 1\u{2013}10\nwait \u{2014} really?\n")
+        .assert()
+        .code(1)
+        .stdout(concat!(
+            "1:8: error: prohibited punctuation '\u{2013}' U+2013 EN DASH \
+             (suggest: '-' for ranges)\n",
+            "2:6: error: prohibited punctuation '\u{2014}' U+2014 EM DASH \
+             (suggest: '--' for a break in a sentence)\n",
+        ));
+}
+
+#[test]
+fn fix_replaces_each_dash_with_its_own_form() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("dashes.txt");
+    fs::write(&path, "pages 1\u{2013}10\nwait \u{2014} really? x-y\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix").arg(&path).assert().success();
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "pages 1-10\nwait -- really? x-y\n"
+    );
+}
+
+#[test]
+fn hyphen_minus_is_never_flagged() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("x-y --flag a - b\n")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn explain_tells_the_dashes_apart() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--explain", "\u{2013}"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("single hyphen"));
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--explain", "\u{2014}"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("double hyphen"));
+}
+
+// EOF
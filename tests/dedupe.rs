@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--dedupe`
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+
+#[test]
+fn dedupe_collapses_a_run_into_one_line_with_a_count() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--dedupe")
+        .write_stdin("- ●●●● item\n")
+        .assert()
+        .code(1)
+        .stdout(
+            "1:3: error: prohibited decorative symbol '●' U+25CF BLACK CIRCLE \
+             (suggest: remove) (4 occurrences on this line)\n",
+        );
+}
+
+#[test]
+fn dedupe_keeps_different_characters_and_lines_apart() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let assert = cmd
+        .arg("--dedupe")
+        .write_stdin("→→ ●\n→\n")
+        .assert()
+        .code(1);
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("1:1: ") && lines[0].ends_with("(2 occurrences on this line)"));
+    assert!(lines[1].starts_with("1:4: ") && !lines[1].contains("occurrences"));
+    assert!(lines[2].starts_with("2:1: ") && !lines[2].contains("occurrences"));
+}
+
+#[test]
+fn dedupe_keeps_separated_repeats_apart() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--dedupe")
+        .write_stdin("●a●\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("occurrences").not())
+        .stdout(predicate::function(|out: &str| out.lines().count() == 2));
+}
+
+#[test]
+fn without_dedupe_each_violation_is_reported() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("●●●●\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("occurrences").not())
+        .stdout(predicate::function(|out: &str| out.lines().count() == 4));
+}
+
+#[test]
+fn dedupe_counts_toward_max_violations_once_per_run() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--dedupe", "--max-violations", "1"])
+        .write_stdin("●●●●\n→\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("(4 occurrences on this line)"))
+        .stdout(predicate::str::contains("2:1:").not());
+}
+
+#[test]
+fn dedupe_conflicts_with_json() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--dedupe", "--format", "json"])
+        .write_stdin("●\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("--dedupe"));
+}
+
+// EOF
@@ -0,0 +1,140 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--diff`
+
+use std::{fs, path::Path, process};
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// Run git in `dir` with a fixed identity, panicking on failure
+fn git(dir: &Path, args: &[&str]) {
+    let status = process::Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .status()
+        .unwrap();
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+/// Create a repository whose only commit has a violation on an existing line
+fn repo() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    git(temp_dir.path(), &["init", "-q"]);
+    fs::write(temp_dir.path().join("notes.txt"), "old → line\nplain\n").unwrap();
+    fs::write(temp_dir.path().join("other.txt"), "untouched ✓\n").unwrap();
+    git(temp_dir.path(), &["add", "."]);
+    git(temp_dir.path(), &["commit", "-q", "-m", "initial"]);
+    temp_dir
+}
+
+#[test]
+fn diff_ignores_unchanged_lines() {
+    let temp_dir = repo();
+    fs::write(
+        temp_dir.path().join("notes.txt"),
+        "old → line\nplain\nnew\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--diff", "HEAD", "notes.txt", "other.txt"])
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn diff_reports_added_lines() {
+    let temp_dir = repo();
+    fs::write(
+        temp_dir.path().join("notes.txt"),
+        "old → line\nnew ⇒ line\nplain\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--diff", "HEAD", "./notes.txt"])
+        .assert()
+        .code(1)
+        .stdout(
+            "2:5: error: prohibited arrow '⇒' U+21D2 RIGHTWARDS DOUBLE ARROW (suggest: '=>')\n",
+        );
+}
+
+#[test]
+fn diff_without_inputs_checks_changed_files() {
+    let temp_dir = repo();
+    fs::write(temp_dir.path().join("notes.txt"), "old → line\nplain 🚀\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--diff", "HEAD"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::starts_with(
+            "notes.txt:2:7: error: prohibited emoji",
+        ))
+        .stdout(predicate::str::contains("other.txt").not());
+}
+
+#[test]
+fn diff_with_unknown_revision_is_an_error() {
+    let temp_dir = repo();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--diff", "no-such-revision"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("git diff no-such-revision failed"));
+}
+
+#[test]
+fn diff_matches_absolute_input_paths() {
+    let temp_dir = repo();
+    fs::write(
+        temp_dir.path().join("notes.txt"),
+        "old → line\nnew ⇒ line\nplain\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--diff", "HEAD"])
+        .arg(temp_dir.path().join("notes.txt"))
+        .assert()
+        .code(1)
+        .stdout(
+            "2:5: error: prohibited arrow '⇒' U+21D2 RIGHTWARDS DOUBLE ARROW (suggest: '=>')\n",
+        );
+}
+
+#[test]
+fn diff_matches_inputs_outside_the_working_directory() {
+    let temp_dir = repo();
+    fs::write(
+        temp_dir.path().join("notes.txt"),
+        "old → line\nnew ⇒ line\nplain\n",
+    )
+    .unwrap();
+    let sub_dir = temp_dir.path().join("sub");
+    fs::create_dir(&sub_dir).unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(&sub_dir)
+        .args(["--diff", "HEAD", "../notes.txt", "../other.txt"])
+        .assert()
+        .code(1)
+        .stdout(
+            "../notes.txt:2:5: error: prohibited arrow '⇒' U+21D2 RIGHTWARDS DOUBLE ARROW (suggest: '=>')\n",
+        );
+}
+
+// EOF
@@ -0,0 +1,55 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `noemoji:` suppression directives
+
+use assert_cmd::{Command, cargo};
+
+#[test]
+fn allow_directive_suppresses_marked_line_only() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("let a = '→'; // noemoji: allow\nlet b = '→';\n")
+        .assert()
+        .code(1)
+        .stdout("2:10: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n");
+}
+
+#[test]
+fn allow_next_line_directive_suppresses_following_line() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("# noemoji: allow-next-line\n→ spec arrow\n")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn allow_directive_with_code_point_is_selective() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("→ and ≤ # noemoji: allow U+2192\n")
+        .assert()
+        .code(1)
+        .stdout(
+            "1:7: error: prohibited math symbol '≤' U+2264 LESS-THAN OR EQUAL TO (suggest: '<=')\n",
+        );
+}
+
+#[test]
+fn disable_file_directive_reports_file_clean() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let path = temp_dir.path().join("art.txt");
+    std::fs::write(&path, "# noemoji: disable-file\n\n╔══╗ → ★\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg(&path).assert().success().stdout("");
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix").arg(&path).assert().success();
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "# noemoji: disable-file\n\n╔══╗ → ★\n"
+    );
+}
+
+// EOF
@@ -0,0 +1,87 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--format editor` and `--link`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+const ARROW: &str = "error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n";
+
+#[test]
+fn editor_format_always_names_the_file() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--format", "editor", "a.txt"])
+        .assert()
+        .code(1)
+        .stdout(format!("a.txt:1:3: {}", ARROW));
+}
+
+#[test]
+fn editor_format_ignores_group() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--format", "editor", "--group", "a.txt"])
+        .assert()
+        .code(1)
+        .stdout(format!("a.txt:1:3: {}", ARROW));
+}
+
+#[test]
+fn editor_format_can_be_configured() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a → b\n").unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "[output]\nformat = \"editor\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("a.txt")
+        .assert()
+        .code(1)
+        .stdout(format!("a.txt:1:3: {}", ARROW));
+}
+
+#[test]
+fn link_prints_file_url_with_line_and_column() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("a.txt");
+    fs::write(&path, "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let output = cmd.arg("--link").arg(&path).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let url = stdout
+        .strip_suffix(&format!("#L1C3: {}", ARROW))
+        .unwrap_or_else(|| panic!("unexpected output {stdout:?}"));
+    assert!(url.starts_with("file:///"), "{url}");
+    assert!(url.ends_with("/a.txt"), "{url}");
+    assert!(!url.contains('\\'), "{url}");
+}
+
+#[test]
+fn link_leaves_stdin_location_plain() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--link", "-"])
+        .write_stdin("a → b\n")
+        .assert()
+        .code(1)
+        .stdout(format!("stdin:1:3: {}", ARROW));
+}
+
+// EOF
@@ -0,0 +1,58 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for the horizontal ellipsis and its expansion by `--fix`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+#[test]
+fn each_ellipsis_on_a_line_is_reported() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("wait…really…\n")
+        .assert()
+        .code(1)
+        .stdout(concat!(
+            "1:5: error: prohibited punctuation '…' U+2026 HORIZONTAL ELLIPSIS (suggest: '...')\n",
+            "1:12: error: prohibited punctuation '…' U+2026 HORIZONTAL ELLIPSIS (suggest: '...')\n",
+        ));
+}
+
+#[test]
+fn fix_expands_every_ellipsis_on_a_line() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("ellipsis.txt");
+    fs::write(&path, "wait…really…\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix").arg(&path).assert().success().stdout("");
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "wait...really...\n");
+}
+
+#[test]
+fn fix_reports_leftovers_at_their_column_after_expansion() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("ellipsis.txt");
+    fs::write(&path, "wait…really…🚀\n").unwrap();
+
+    // The rocket moves from column 13 to column 17 of the fixed line
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix")
+        .arg(&path)
+        .assert()
+        .code(1)
+        .stdout("1:17: error: prohibited emoji '🚀' U+1F680 ROCKET (suggest: remove)\n");
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "wait...really...🚀\n");
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg(&path)
+        .assert()
+        .code(1)
+        .stdout("1:17: error: prohibited emoji '🚀' U+1F680 ROCKET (suggest: remove)\n");
+}
+
+// EOF
@@ -0,0 +1,92 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for runs with no input files and `--error-on-empty`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn empty_file_list_warns_but_succeeds() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--files-from", "-"])
+        .write_stdin("")
+        .assert()
+        .success()
+        .stdout("")
+        .stderr("noemoji: warning: no input files\n");
+}
+
+#[test]
+fn error_on_empty_fails_for_empty_file_list() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--error-on-empty", "--files-from", "-"])
+        .write_stdin("")
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr("noemoji: no input files\n");
+}
+
+#[test]
+fn error_on_empty_fails_when_every_input_is_excluded() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--error-on-empty", "--exclude", "*.txt", "a.txt"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("no input files"));
+}
+
+#[test]
+fn error_on_empty_fails_for_empty_directory() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--error-on-empty", "-r"])
+        .arg(temp_dir.path())
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("no input files"));
+}
+
+#[test]
+fn error_on_empty_fails_for_unmatched_glob() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--error-on-empty", "*.md"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("no files matched pattern '*.md'"));
+}
+
+#[test]
+fn error_on_empty_applies_to_fix() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--error-on-empty", "--fix", "--files-from", "-"])
+        .write_stdin("")
+        .assert()
+        .code(2)
+        .stderr("noemoji: no input files\n");
+}
+
+#[test]
+fn error_on_empty_allows_clean_inputs() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--error-on-empty")
+        .write_stdin("clean\n")
+        .assert()
+        .success()
+        .stderr("");
+}
+
+// EOF
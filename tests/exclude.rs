@@ -0,0 +1,46 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--exclude` filtering
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn excluded_file_is_not_reported() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("app.min.js"), "a → b\n").unwrap();
+    fs::write(temp_dir.path().join("app.js"), "c → d\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--exclude")
+        .arg("*.min.js")
+        .arg("app.min.js")
+        .arg("app.js")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("app.js:1:3:"))
+        .stdout(predicates::str::contains("app.min.js").not());
+}
+
+#[test]
+fn excluding_every_violating_file_succeeds() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir_all(temp_dir.path().join("docs/generated")).unwrap();
+    fs::write(temp_dir.path().join("docs/generated/api.md"), "→\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--exclude=docs/generated/**")
+        .arg("docs/generated/api.md")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+// EOF
@@ -0,0 +1,51 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--exit-zero` and `violations_exit_code`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn exit_zero_reports_violations_but_succeeds() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--exit-zero")
+        .write_stdin("a → b\n")
+        .assert()
+        .success()
+        .stdout("1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n");
+}
+
+#[test]
+fn exit_zero_still_fails_on_errors() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--exit-zero", "missing.txt"])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn config_violations_exit_code_zero_succeeds() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "inherit = false\n\n[behavior]\nviolations_exit_code = 0\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .write_stdin("a → b\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("prohibited arrow"));
+}
+
+// EOF
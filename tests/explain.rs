@@ -0,0 +1,61 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--explain`
+
+use assert_cmd::{Command, cargo};
+
+#[test]
+fn explain_rule_prints_rationale() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--explain", "emoji"])
+        .assert()
+        .success()
+        .stdout(predicates::str::starts_with("emoji (rule: emoji)\n\n"))
+        .stdout(predicates::str::contains("Remove them"));
+}
+
+#[test]
+fn explain_arrows_gives_ascii_guidance() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--explain", "arrows"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("`->` and `<-`"));
+}
+
+#[test]
+fn explain_character_names_its_rule_and_suggestion() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--explain", "≤"])
+        .assert()
+        .success()
+        .stdout(predicates::str::starts_with(
+            "'≤' U+2264 LESS-THAN OR EQUAL TO is a prohibited math symbol (suggest: '<=')\n\n\
+             math symbol (rule: math)\n",
+        ));
+}
+
+#[test]
+fn explain_permitted_character() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--explain", "€"])
+        .assert()
+        .success()
+        .stdout("'€' U+20AC EURO SIGN is permitted\n");
+}
+
+#[test]
+fn explain_unknown_rule_is_an_error() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--explain", "sparkles"])
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr(predicates::str::contains(
+            "invalid value 'sparkles' for '--explain'",
+        ));
+}
+
+// EOF
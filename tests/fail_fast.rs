@@ -0,0 +1,47 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--fail-fast`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+#[test]
+fn fail_fast_prints_only_first_violation() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "clean\n").unwrap();
+    fs::write(temp_dir.path().join("b.txt"), "x → y → z\n≤\n").unwrap();
+    fs::write(temp_dir.path().join("c.txt"), "🚀\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--fail-fast")
+        .args(["a.txt", "b.txt", "c.txt"])
+        .assert()
+        .code(1)
+        .stdout("b.txt:1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n");
+}
+
+#[test]
+fn fail_fast_with_quiet_prints_nothing() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--fail-fast", "--quiet"])
+        .write_stdin("→ → →\n")
+        .assert()
+        .code(1)
+        .stdout("");
+}
+
+#[test]
+fn fail_fast_succeeds_on_clean_input() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fail-fast")
+        .write_stdin("all ascii\n")
+        .assert()
+        .success();
+}
+
+// EOF
@@ -0,0 +1,84 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--with-filename` and `--no-filename`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+const ARROW: &str = "1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n";
+
+/// Create a directory with arrows in `a.txt` and `b.txt`
+fn two_files() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a → b\n").unwrap();
+    fs::write(temp_dir.path().join("b.txt"), "c → d\n").unwrap();
+    temp_dir
+}
+
+#[test]
+fn single_file_omits_filename_by_default() {
+    let temp_dir = two_files();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("a.txt")
+        .assert()
+        .code(1)
+        .stdout(ARROW);
+}
+
+#[test]
+fn with_filename_restores_prefix_for_single_file() {
+    let temp_dir = two_files();
+
+    for flag in ["--with-filename", "-H"] {
+        let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+        cmd.current_dir(temp_dir.path())
+            .args([flag, "a.txt"])
+            .assert()
+            .code(1)
+            .stdout(format!("a.txt:{}", ARROW));
+    }
+}
+
+#[test]
+fn multiple_files_show_filename_by_default() {
+    let temp_dir = two_files();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["a.txt", "b.txt"])
+        .assert()
+        .code(1)
+        .stdout(format!("a.txt:{}b.txt:{}", ARROW, ARROW));
+}
+
+#[test]
+fn no_filename_omits_prefix_for_multiple_files() {
+    let temp_dir = two_files();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--no-filename", "a.txt", "b.txt"])
+        .assert()
+        .code(1)
+        .stdout(format!("{}{}", ARROW, ARROW));
+}
+
+#[test]
+fn last_filename_flag_wins() {
+    let temp_dir = two_files();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--with-filename", "--no-filename", "a.txt"])
+        .assert()
+        .code(1)
+        .stdout(ARROW);
+}
+
+// EOF
@@ -0,0 +1,73 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--files-from` and `--files-from0`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// Create a directory holding one clean and one violating file
+fn fixture() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("clean.txt"), "plain ascii\n").unwrap();
+    fs::write(temp_dir.path().join("dirty.txt"), "a → b\n").unwrap();
+    temp_dir
+}
+
+#[test]
+fn files_from_stdin_checks_each_listed_file() {
+    let temp_dir = fixture();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--files-from", "-", "--summary"])
+        .write_stdin("clean.txt\ndirty.txt\n")
+        .assert()
+        .code(1)
+        .stdout(
+            "dirty.txt:1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n",
+        )
+        .stderr(predicate::str::contains("1 violation in 1 of 2 files"));
+}
+
+#[test]
+fn files_from0_stdin_checks_each_listed_file() {
+    let temp_dir = fixture();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--files-from0", "-", "--summary"])
+        .write_stdin("clean.txt\0dirty.txt\0")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("1 violation in 1 of 2 files"));
+}
+
+#[test]
+fn files_from_coexists_with_positional_arguments() {
+    let temp_dir = fixture();
+    fs::write(temp_dir.path().join("list.txt"), "dirty.txt\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["clean.txt", "--files-from", "list.txt", "--summary"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("1 violation in 1 of 2 files"));
+}
+
+#[test]
+fn files_from_empty_list_succeeds() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--files-from", "-"])
+        .write_stdin("")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+// EOF
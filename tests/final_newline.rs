@@ -0,0 +1,86 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for the opt-in final newline rule
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+#[test]
+fn missing_final_newline_is_reported_when_required() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "one\ntwo").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--require-final-newline")
+        .arg("a.txt")
+        .assert()
+        .code(1)
+        .stdout("2:4: error: missing final newline\n");
+}
+
+#[test]
+fn terminated_file_passes_when_required() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "one\ntwo\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--require-final-newline")
+        .arg("a.txt")
+        .assert()
+        .success()
+        .stdout(predicates::str::is_empty());
+}
+
+#[test]
+fn missing_final_newline_is_allowed_by_default() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "one\ntwo").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("a.txt")
+        .assert()
+        .success();
+}
+
+#[test]
+fn final_newline_rule_from_config() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "[rules]\nfinal_newline = true\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "no newline").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("a.txt")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("missing final newline"));
+}
+
+#[test]
+fn fix_appends_final_newline_when_required() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("a.txt");
+    fs::write(&path, "a → b").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix")
+        .arg("--require-final-newline")
+        .arg(&path)
+        .assert()
+        .success();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "a -> b\n");
+}
+
+// EOF
@@ -0,0 +1,120 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--fix` mode
+
+use std::{
+    fs::{self, File},
+    time::{Duration, SystemTime},
+};
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+#[test]
+fn fix_rewrites_arrow_in_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("arrow.txt");
+    fs::write(&path, "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix").arg(&path).assert().success();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "a -> b\n");
+}
+
+#[test]
+fn fix_leaves_clean_file_untouched() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("clean.txt");
+    fs::write(&path, "nothing to fix\n").unwrap();
+
+    // Backdate the file so a rewrite would be detectable
+    let old_time = SystemTime::now() - Duration::from_secs(3600);
+    File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(old_time)
+        .unwrap();
+    let before = fs::metadata(&path).unwrap().modified().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix").arg(&path).assert().success();
+
+    let after = fs::metadata(&path).unwrap().modified().unwrap();
+    assert_eq!(before, after);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "nothing to fix\n");
+}
+
+#[test]
+fn fix_reports_unfixable_characters() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("emoji.txt");
+    fs::write(&path, "done → 🚀\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix")
+        .arg(&path)
+        .assert()
+        .code(1)
+        .stdout(predicates::str::starts_with("1:9: error: prohibited emoji"));
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "done -> 🚀\n");
+}
+
+#[test]
+fn fix_streams_stdin_to_stdout() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix")
+        .write_stdin("x ≤ y\n")
+        .assert()
+        .success()
+        .stdout("x <= y\n");
+}
+
+#[test]
+fn dry_run_prints_diff_without_writing() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("arrow.txt");
+    fs::write(&path, "first\na → b\nlast\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix")
+        .arg("--dry-run")
+        .arg(&path)
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("@@ -2 +2 @@"))
+        .stdout(predicates::str::contains("\n-a → b\n"))
+        .stdout(predicates::str::contains("\n+a -> b\n"));
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "first\na → b\nlast\n");
+}
+
+#[test]
+fn dry_run_on_clean_file_succeeds_silently() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("clean.txt");
+    fs::write(&path, "clean\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix")
+        .arg("--dry-run")
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn dry_run_without_fix_is_an_error() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--dry-run")
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("'--dry-run' requires '--fix'"));
+}
+
+// EOF
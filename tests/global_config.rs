@@ -0,0 +1,122 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for the user-level global configuration file
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// Create a global config directory whose `config.toml` sets the log level
+fn global_config_dir() -> TempDir {
+    let config_dir = TempDir::new().unwrap();
+    fs::write(
+        config_dir.path().join("config.toml"),
+        "[log]\nlevel = \"debug\"\n\n[rules]\nemoji = false\n",
+    )
+    .unwrap();
+    config_dir
+}
+
+#[test]
+fn global_config_applies_without_project_config() {
+    let config_dir = global_config_dir();
+    let project_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(project_dir.path())
+        .env("NOEMOJI_CONFIG_DIR", config_dir.path())
+        .arg("--check-config")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "#   {}\n",
+            config_dir.path().join("config.toml").display()
+        )))
+        .stdout(predicate::str::contains("level = \"debug\"\n"))
+        .stdout(predicate::str::contains("emoji = false\n"));
+}
+
+#[test]
+fn project_config_overrides_global_config() {
+    let config_dir = global_config_dir();
+    let project_dir = TempDir::new().unwrap();
+    fs::write(
+        project_dir.path().join(".noemoji.toml"),
+        "[log]\nlevel = \"warn\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(project_dir.path())
+        .env("NOEMOJI_CONFIG_DIR", config_dir.path())
+        .arg("--check-config")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("level = \"warn\"\n"))
+        // Settings the project leaves unset still fall through to the global config
+        .stdout(predicate::str::contains("emoji = false\n"));
+}
+
+#[test]
+fn global_config_is_ignored_when_inheritance_stops() {
+    let config_dir = global_config_dir();
+    let project_dir = TempDir::new().unwrap();
+    fs::write(
+        project_dir.path().join(".noemoji.toml"),
+        "inherit = false\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(project_dir.path())
+        .env("NOEMOJI_CONFIG_DIR", config_dir.path())
+        .arg("--check-config")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("config.toml").not())
+        .stdout(predicate::str::contains("emoji = true\n"));
+}
+
+#[test]
+fn global_config_disables_rule_when_checking() {
+    let config_dir = global_config_dir();
+    let project_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(project_dir.path())
+        .env("NOEMOJI_CONFIG_DIR", config_dir.path())
+        .write_stdin("Ship it 🚀\n")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn invalid_global_config_keeps_project_config() {
+    let config_dir = TempDir::new().unwrap();
+    fs::write(config_dir.path().join("config.toml"), "bogus = [\n").unwrap();
+    let project_dir = TempDir::new().unwrap();
+    fs::write(
+        project_dir.path().join(".noemoji.toml"),
+        "[rules]\narrows = false\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(project_dir.path())
+        .env("NOEMOJI_CONFIG_DIR", config_dir.path())
+        .write_stdin("a → b\n")
+        .assert()
+        .success()
+        .stdout("")
+        .stderr(predicate::str::contains(format!(
+            "warning: {}: ",
+            config_dir.path().join("config.toml").display()
+        )));
+}
+
+// EOF
@@ -0,0 +1,52 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for reporting the grapheme cluster around a violation
+
+use assert_cmd::{Command, cargo};
+
+/// Family emoji: woman, zero width joiner, girl
+const FAMILY: &str = "\u{1F469}\u{200D}\u{1F467}";
+
+#[test]
+fn family_emoji_reports_complete_cluster() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--column=grapheme"])
+        .write_stdin(format!("{FAMILY}\n"))
+        .assert()
+        .code(1)
+        .stdout(format!(
+            "1:1: error: prohibited emoji '\u{1F469}' U+1F469 WOMAN in '{FAMILY}' (suggest: remove)\n\
+             1:1: error: prohibited whitespace U+200D ZERO WIDTH JOINER in '{FAMILY}' (suggest: remove)\n\
+             1:1: error: prohibited emoji '\u{1F467}' U+1F467 GIRL in '{FAMILY}' (suggest: remove)\n"
+        ));
+}
+
+#[test]
+fn single_character_has_no_cluster() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("a → b\n")
+        .assert()
+        .code(1)
+        .stdout("1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n");
+}
+
+#[test]
+fn json_includes_cluster_only_when_partial() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let output = cmd
+        .args(["--format", "json"])
+        .write_stdin(format!("{FAMILY} \u{1F680}\n"))
+        .output()
+        .unwrap();
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let violations = report[0]["violations"].as_array().unwrap();
+    assert_eq!(violations.len(), 4);
+    assert_eq!(violations[0]["grapheme"], FAMILY);
+    assert_eq!(violations[2]["grapheme"], FAMILY);
+    assert!(violations[3].get("grapheme").is_none());
+}
+
+// EOF
@@ -0,0 +1,44 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--group` text output
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+#[test]
+fn group_prints_one_header_per_file_with_violations() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a → b\nc ✓\n").unwrap();
+    fs::write(temp_dir.path().join("clean.txt"), "plain\n").unwrap();
+    fs::write(temp_dir.path().join("b.txt"), "🚀\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--group", "a.txt", "clean.txt", "b.txt"])
+        .assert()
+        .code(1)
+        .stdout(
+            "== a.txt ==\n    \
+             1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n    \
+             2:3: error: prohibited checkmark '✓' U+2713 CHECK MARK (suggest: '[x]')\n\
+             \n\
+             == b.txt ==\n    \
+             1:1: error: prohibited emoji '🚀' U+1F680 ROCKET (suggest: remove)\n",
+        );
+}
+
+#[test]
+fn group_prints_nothing_for_clean_input() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--group")
+        .write_stdin("plain\n")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+// EOF
@@ -0,0 +1,68 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for the `--hook` preset
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+#[test]
+fn hook_reports_named_violations_and_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--hook", "a.txt"])
+        .assert()
+        .code(1)
+        .stdout("a.txt:1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n")
+        .stderr("");
+}
+
+#[test]
+fn hook_fails_despite_configured_exit_code() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a → b\n").unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "[behavior]\nviolations_exit_code = 0\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--hook", "a.txt"])
+        .assert()
+        .code(1);
+}
+
+#[test]
+fn hook_without_files_checks_nothing() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--hook")
+        .write_stdin("a → b\n")
+        .assert()
+        .success()
+        .stdout("")
+        .stderr("");
+}
+
+#[test]
+fn hook_passes_clean_files() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a -> b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--hook", "a.txt"])
+        .assert()
+        .success()
+        .stdout("")
+        .stderr("");
+}
+
+// EOF
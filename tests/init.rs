@@ -0,0 +1,88 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--init`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn init_writes_template_that_changes_nothing() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join(".noemoji.toml");
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--init")
+        .assert()
+        .success()
+        .stdout("created .noemoji.toml\n");
+    let template = fs::read_to_string(&config_path).unwrap();
+    assert!(template.contains("[rules]\n"));
+
+    // The template is valid, and every setting is left at its default
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--check-config")
+        .assert()
+        .success();
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .write_stdin("a \u{2192} b\n")
+        .assert()
+        .code(1);
+}
+
+#[test]
+fn init_refuses_to_overwrite_without_force() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join(".noemoji.toml");
+    fs::write(&config_path, "inherit = false\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--init")
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr(predicate::str::contains(
+            ".noemoji.toml already exists; use --force to overwrite",
+        ));
+    assert_eq!(
+        fs::read_to_string(&config_path).unwrap(),
+        "inherit = false\n"
+    );
+}
+
+#[test]
+fn init_force_overwrites_existing_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join(".noemoji.toml");
+    fs::write(&config_path, "inherit = false\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--init", "--force"])
+        .assert()
+        .success();
+    assert!(
+        fs::read_to_string(&config_path)
+            .unwrap()
+            .starts_with("# Configuration for noemoji")
+    );
+}
+
+#[test]
+fn force_requires_init() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--force")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("--force"));
+}
+
+// EOF
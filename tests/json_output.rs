@@ -0,0 +1,195 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--format json` output
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use serde_json::Value;
+use tempfile::TempDir;
+
+#[test]
+fn json_output_reports_codepoint() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let output = cmd
+        .arg("--format")
+        .arg("json")
+        .write_stdin("a → b")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let violation = &json[0]["violations"][0];
+    assert_eq!(json[0]["file"], "stdin");
+    assert_eq!(violation["codepoint"], "U+2192");
+    assert_eq!(violation["char"], "→");
+    assert_eq!(violation["line"], 1);
+    assert_eq!(violation["column"], 3);
+    assert_eq!(violation["category"], "arrow");
+    assert_eq!(violation["suggestion"], "->");
+}
+
+#[test]
+fn json_output_reports_byte_offset() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let output = cmd
+        .arg("--format=json")
+        .write_stdin("café\nx → y\n")
+        .output()
+        .unwrap();
+
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let violation = &json[0]["violations"][0];
+    assert_eq!(violation["line"], 2);
+    assert_eq!(violation["column"], 3);
+    // "café\n" is 6 bytes, then "x " is 2 more
+    assert_eq!(violation["byte_offset"], 8);
+}
+
+#[test]
+fn json_output_clean_input_succeeds() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let output = cmd
+        .arg("--format=json")
+        .write_stdin("clean")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(0));
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json[0]["violations"].as_array().unwrap().len(), 0);
+}
+
+#[test]
+fn json_output_errors_exit_two() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--format")
+        .arg("json")
+        .arg("nonexistent_file_json.txt")
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("nonexistent_file_json.txt"));
+}
+
+#[test]
+fn json_output_reports_status_per_file() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("clean.txt"), "clean\n").unwrap();
+    fs::write(temp_dir.path().join("dirty.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let output = cmd
+        .current_dir(temp_dir.path())
+        .args(["--format=json", "missing.txt", "clean.txt", "dirty.txt"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json[0]["file"], "missing.txt");
+    assert_eq!(json[0]["status"], "error");
+    assert!(json[0]["error"].as_str().unwrap().contains("missing.txt"));
+    assert_eq!(json[1]["file"], "clean.txt");
+    assert_eq!(json[1]["status"], "clean");
+    assert!(json[1].get("error").is_none());
+    assert_eq!(json[2]["status"], "violations");
+}
+
+#[test]
+fn invalid_format_is_usage_error() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--format")
+        .arg("yaml")
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("yaml"));
+}
+
+/// Create a directory whose config selects JSON output
+fn json_config_dir() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "inherit = false\n\n[output]\nformat = \"json\"\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[test]
+fn config_output_format_selects_json() {
+    let temp_dir = json_config_dir();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let output = cmd
+        .current_dir(temp_dir.path())
+        .write_stdin("a → b")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json[0]["violations"][0]["codepoint"], "U+2192");
+}
+
+#[test]
+fn format_flag_overrides_config_output_format() {
+    let temp_dir = json_config_dir();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--format", "text"])
+        .write_stdin("a → b")
+        .assert()
+        .code(1)
+        .stdout("1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n");
+}
+
+#[test]
+fn json_output_prints_brief_summary_on_stderr() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a → b ⇒ c\n").unwrap();
+    fs::write(temp_dir.path().join("b.txt"), "🚀\n").unwrap();
+    fs::write(temp_dir.path().join("c.txt"), "clean\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let output = cmd
+        .current_dir(temp_dir.path())
+        .args(["--format", "json", "a.txt", "b.txt", "c.txt"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 3);
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "noemoji: 3 violations in 2 files\n"
+    );
+}
+
+#[test]
+fn json_brief_summary_reports_clean_run() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--format", "json"])
+        .write_stdin("clean\n")
+        .assert()
+        .success()
+        .stderr("noemoji: no violations in 1 file\n");
+}
+
+#[test]
+fn quiet_suppresses_json_brief_summary() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--format", "json", "--quiet"])
+        .write_stdin("a → b\n")
+        .assert()
+        .code(1)
+        .stdout("")
+        .stderr("");
+}
+
+// EOF
@@ -0,0 +1,75 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--keep-going` and `--no-keep-going`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn keep_going_is_the_default() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("b.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["missing.txt", "b.txt"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("b.txt:1:3:"))
+        .stderr(predicate::str::contains("missing.txt"));
+}
+
+#[test]
+fn no_keep_going_stops_at_first_unreadable_input() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("b.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--no-keep-going", "missing.txt", "b.txt"])
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr(predicate::str::contains("missing.txt"));
+}
+
+#[test]
+fn no_keep_going_stops_json_report() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("b.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "--no-keep-going",
+            "--format",
+            "json",
+            "missing.txt",
+            "b.txt",
+        ])
+        .assert()
+        .code(2)
+        .stdout(
+            predicate::str::contains("missing.txt").and(predicate::str::contains("b.txt").not()),
+        );
+}
+
+#[test]
+fn keep_going_overrides_earlier_no_keep_going() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("b.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--no-keep-going", "--keep-going", "missing.txt", "b.txt"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("b.txt:1:3:"));
+}
+
+// EOF
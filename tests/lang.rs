@@ -0,0 +1,95 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--lang`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+const SOURCE: &str =
+    "// Maps a → b\nfn f() -> &'static str {\n    let _ = 1 → 2;\n    \"café → bar\"\n}\n";
+
+#[test]
+fn lang_rust_skips_strings_and_comments() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--lang", "rust"])
+        .write_stdin(SOURCE)
+        .assert()
+        .code(1)
+        .stdout("3:15: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n");
+}
+
+#[test]
+fn without_lang_strings_and_comments_are_checked() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin(SOURCE)
+        .assert()
+        .code(1)
+        .stdout(predicate::function(|out: &str| out.lines().count() == 3));
+}
+
+#[test]
+fn lang_rust_checks_unlexable_input_in_full() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--lang", "rust"])
+        .write_stdin("let s = \"a → b;\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::starts_with("1:12: error: prohibited arrow"));
+}
+
+#[test]
+fn lang_rust_fix_leaves_strings_alone() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("lib.rs");
+    fs::write(&path, "let s = \"→\"; // →\nlet t = 1 → 2;\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--lang", "rust", "--fix"])
+        .arg(&path)
+        .assert()
+        .success();
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "let s = \"→\"; // →\nlet t = 1 -> 2;\n"
+    );
+}
+
+#[test]
+fn lang_markdown_skips_code_fences_and_spans() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--lang", "markdown"])
+        .write_stdin("Output:\n\n```text\na → b\n```\n\nThen `x → y` maps a → b.\n")
+        .assert()
+        .code(1)
+        .stdout("7:21: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n");
+}
+
+#[test]
+fn lang_markdown_unclosed_fence_runs_to_end_of_input() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--lang", "markdown"])
+        .write_stdin("a → b\n```\nc → d\n")
+        .assert()
+        .code(1)
+        .stdout("1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n");
+}
+
+#[test]
+fn lang_rejects_unknown_language() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--lang", "cobol"])
+        .write_stdin("")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "invalid value 'cobol' for '--lang'",
+        ));
+}
+
+// EOF
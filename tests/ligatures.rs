@@ -0,0 +1,67 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for typographic ligatures and their expansion by `--fix`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+#[test]
+fn latin_ligature_is_reported_with_its_letters() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("open the ﬁle\n").assert().code(1).stdout(
+        "1:10: error: prohibited ligature 'ﬁ' U+FB01 LATIN SMALL LIGATURE FI (suggest: 'fi')\n",
+    );
+}
+
+#[test]
+fn interrobang_is_reported() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("really‽\n")
+        .assert()
+        .code(1)
+        .stdout("1:7: error: prohibited ligature '‽' U+203D INTERROBANG (suggest: '?!')\n");
+}
+
+#[test]
+fn fix_expands_ligatures() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("ligatures.txt");
+    fs::write(&path, "ﬁle and eﬀort, really‽\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix").arg(&path).assert().success().stdout("");
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "file and effort, really?!\n"
+    );
+}
+
+#[test]
+fn ligatures_rule_can_be_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "inherit = false\n\n[rules]\nligatures = false\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .write_stdin("ﬁle‽\n")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn armenian_ligatures_are_allowed() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("\u{FB13}\n").assert().success().stdout("");
+}
+
+// EOF
@@ -0,0 +1,100 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--lines`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+/// Create a file with arrows on lines 5 and 20
+fn arrows_on_5_and_20() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let content: String = (1..=20)
+        .map(|n| {
+            if n == 5 || n == 20 {
+                "a → b\n"
+            } else {
+                "clean\n"
+            }
+        })
+        .collect();
+    fs::write(temp_dir.path().join("test.txt"), content).unwrap();
+    temp_dir
+}
+
+#[test]
+fn lines_reports_only_violations_in_range() {
+    let temp_dir = arrows_on_5_and_20();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--lines", "1:10", "test.txt"])
+        .assert()
+        .code(1)
+        .stdout("5:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n");
+}
+
+#[test]
+fn lines_outside_violations_succeeds() {
+    let temp_dir = arrows_on_5_and_20();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--lines", "6:19", "test.txt"])
+        .assert()
+        .code(0)
+        .stdout("");
+}
+
+#[test]
+fn inverted_lines_is_usage_error() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--lines", "10:1"])
+        .write_stdin("a → b\n")
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("10:1"));
+}
+
+#[test]
+fn lines_requires_single_input() {
+    let temp_dir = arrows_on_5_and_20();
+    fs::write(temp_dir.path().join("other.txt"), "clean\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--lines", "1:10", "test.txt", "other.txt"])
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("single input"));
+}
+
+#[test]
+fn lines_requires_single_input_after_recursion() {
+    let temp_dir = arrows_on_5_and_20();
+    fs::write(temp_dir.path().join("other.txt"), "clean\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["-r", "--lines", "1:10", "."])
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("single input"));
+}
+
+#[test]
+fn lines_accepts_directory_holding_one_file() {
+    let temp_dir = arrows_on_5_and_20();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["-r", "--lines", "1:10", "."])
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("5:3: error: prohibited arrow"));
+}
+
+// EOF
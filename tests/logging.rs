@@ -104,4 +104,28 @@ fn noemoji_log_takes_precedence_over_rust_log() {
         .stderr(predicate::str::is_empty());
 }
 
+#[test]
+fn debug_logs_scan_timing_on_stderr() {
+    use assert_cmd::Command;
+    use predicates::prelude::*;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("a.txt"), "a → b\n").unwrap();
+
+    // Timing goes to stderr with the other logs, leaving stdout to violations
+    Command::new(assert_cmd::cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .arg("a.txt")
+        .env("NOEMOJI_LOG", "debug")
+        .assert()
+        .code(1)
+        .stdout("1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n")
+        .stderr(
+            predicate::str::contains("noemoji[debug]: scanned a.txt (8 bytes) in ").and(
+                predicate::str::contains("noemoji[debug]: scanned 1 input (8 bytes) in "),
+            ),
+        );
+}
+
 // EOF
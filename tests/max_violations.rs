@@ -0,0 +1,74 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--max-violations` and `--max-violations-per-file`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+#[test]
+fn max_violations_truncates_output() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--max-violations", "3"])
+        .write_stdin("→\n".repeat(10))
+        .assert()
+        .code(1)
+        .stdout(
+            "1:1: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n\
+             2:1: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n\
+             3:1: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n\
+             ... (truncated, 7 more)\n",
+        );
+}
+
+#[test]
+fn max_violations_spans_files() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "→\n→\n").unwrap();
+    fs::write(temp_dir.path().join("b.txt"), "→\n→\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--max-violations", "3", "a.txt", "b.txt"])
+        .assert()
+        .code(1)
+        .stdout(
+            "a.txt:1:1: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n\
+             a.txt:2:1: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n\
+             b.txt:1:1: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n\
+             ... (truncated, 1 more)\n",
+        );
+}
+
+#[test]
+fn max_violations_per_file_truncates_each_file() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "→\n→\n→\n").unwrap();
+    fs::write(temp_dir.path().join("b.txt"), "→\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--max-violations-per-file", "1", "a.txt", "b.txt"])
+        .assert()
+        .code(1)
+        .stdout(
+            "a.txt:1:1: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n\
+             ... (truncated, 2 more)\n\
+             b.txt:1:1: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n",
+        );
+}
+
+#[test]
+fn max_violations_does_not_change_summary() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--max-violations", "1", "--summary"])
+        .write_stdin("→ →\n")
+        .assert()
+        .code(1)
+        .stderr(predicates::str::contains("2 violations"));
+}
+
+// EOF
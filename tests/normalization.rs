@@ -0,0 +1,119 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--normalize` and the `normalize` setting
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+/// `café` with the accent as a separate combining character
+const NFD: &str = "cafe\u{301}\n";
+/// `café` with a precomposed `é`
+const NFC: &str = "caf\u{e9}\n";
+
+#[test]
+fn decomposed_text_is_reported_under_nfc() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--normalize", "nfc"])
+        .write_stdin(NFD)
+        .assert()
+        .code(1)
+        .stdout(concat!(
+            "1:4: error: prohibited unnormalized text 'e' U+0065 LATIN SMALL LETTER E ",
+            "in 'e\u{301}' (suggest: '\u{e9}')\n",
+        ));
+}
+
+#[test]
+fn normalized_text_is_clean() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--normalize", "nfc"])
+        .write_stdin(NFC)
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn normalization_is_not_checked_by_default() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin(NFD).assert().success().stdout("");
+}
+
+#[test]
+fn fix_composes_decomposed_text() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("cafe.txt");
+    fs::write(&path, NFD).unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--normalize", "nfc", "--fix"])
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout("");
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), NFC);
+}
+
+#[test]
+fn fix_decomposes_under_nfd() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("cafe.txt");
+    fs::write(&path, NFC).unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--normalize", "nfd", "--fix"])
+        .arg(&path)
+        .assert()
+        .success();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), NFD);
+}
+
+#[test]
+fn fix_leaves_lookalikes_that_are_not_equivalent() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("mixed.txt");
+    // The Latin small letter dotless i is not canonically equivalent to i
+    fs::write(&path, "\u{131}cafe\u{301}\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--normalize", "nfc", "--fix"])
+        .arg(&path)
+        .assert()
+        .success();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "\u{131}caf\u{e9}\n");
+}
+
+#[test]
+fn config_normalize_enables_the_check() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "inherit = false\nnormalize = \"nfc\"\n\n[severity]\nnormalization = \"warning\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .write_stdin(NFD)
+        .assert()
+        .success()
+        .stdout(concat!(
+            "1:4: warning: prohibited unnormalized text 'e' U+0065 LATIN SMALL LETTER E ",
+            "in 'e\u{301}' (suggest: '\u{e9}')\n",
+        ));
+}
+
+#[test]
+fn invalid_form_is_rejected() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--normalize", "nfkc"]).assert().code(2);
+}
+
+// EOF
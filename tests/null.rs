@@ -0,0 +1,50 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `-0` / `--null`
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+
+#[test]
+fn null_separates_records_with_nul_bytes() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let output = cmd
+        .arg("-0")
+        .write_stdin("a \u{2192} b\n\u{2713} done\n")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!stdout.contains('\n'));
+    let records: Vec<_> = stdout.split_terminator('\0').collect();
+    assert_eq!(records.len(), 2);
+    assert!(records[0].starts_with("1:3: error: prohibited arrow"));
+    assert!(records[1].starts_with("2:1: error: prohibited checkmark"));
+}
+
+#[test]
+fn null_applies_to_count() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--null", "--count"])
+        .write_stdin("a \u{2192} b\n")
+        .assert()
+        .code(1)
+        .stdout("1\0");
+}
+
+#[test]
+fn null_conflicts_with_group() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--null", "--group"])
+        .write_stdin("")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "options '--null' and '--group' cannot be used together",
+        ));
+}
+
+// EOF
@@ -0,0 +1,76 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--only` and `--ignore`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn only_arrows_ignores_emoji_but_flags_arrow() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--only", "arrows"])
+        .write_stdin("ship it \u{1F680}\na \u{2192} b\n")
+        .assert()
+        .code(1)
+        .stdout(
+            "2:3: error: prohibited arrow '\u{2192}' U+2192 RIGHTWARDS ARROW (suggest: '->')\n",
+        );
+}
+
+#[test]
+fn only_without_matches_is_clean() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--only=arrows,checkmarks"])
+        .write_stdin("ship it \u{1F680}\n")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn ignore_disables_listed_categories() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--ignore", "emoji,box-drawing"])
+        .write_stdin("\u{1F680} \u{2500}\n\u{2713}\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("prohibited checkmark"))
+        .stdout(predicate::str::contains("emoji").not());
+}
+
+#[test]
+fn cli_overrides_config_toggles() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "inherit = false\n\n[rules]\narrows = false\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a \u{2192} b\n").unwrap();
+
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .args(["--only", "arrows", "a.txt"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("1:3: error: prohibited arrow"));
+}
+
+#[test]
+fn unknown_category_lists_valid_names() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--only", "arrows,sparkles"])
+        .write_stdin("")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "unknown category 'sparkles' for '--only', expected one of: arrows, checkmarks,",
+        ));
+}
+
+// EOF
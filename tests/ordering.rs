@@ -0,0 +1,69 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for the guarantee that output follows argument order
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+/// Run noemoji in `dir` with `args` over `files`, returning its stdout
+fn run(dir: &TempDir, args: &[&str], files: &[String]) -> Vec<u8> {
+    let output = Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(dir.path())
+        .args(args)
+        .args(files)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    output.stdout
+}
+
+#[test]
+fn output_is_identical_across_runs_and_in_argument_order() {
+    let temp_dir = TempDir::new().unwrap();
+    // Named so that argument order differs from name order
+    let files: Vec<String> = (0..20).rev().map(|n| format!("f{:02}.txt", n)).collect();
+    for name in &files {
+        fs::write(temp_dir.path().join(name), "a \u{2192} b\nc \u{2713} d\n").unwrap();
+    }
+
+    let first = run(&temp_dir, &[], &files);
+    assert_eq!(first, run(&temp_dir, &[], &files));
+
+    let stdout = String::from_utf8(first).unwrap();
+    let names: Vec<&str> = stdout
+        .lines()
+        .map(|line| line.split(':').next().unwrap())
+        .collect();
+    // Two violations per file, in the order the files were given
+    let reported: Vec<&str> = names.chunks(2).map(|pair| pair[0]).collect();
+    assert_eq!(reported, files);
+}
+
+#[test]
+fn jobs_1_output_is_byte_identical_across_runs() {
+    let temp_dir = TempDir::new().unwrap();
+    let files: Vec<String> = (0..20).rev().map(|n| format!("f{:02}.txt", n)).collect();
+    for name in &files {
+        fs::write(temp_dir.path().join(name), "a \u{2192} b\nc \u{2713} d\n").unwrap();
+    }
+
+    let first = run(&temp_dir, &["--jobs", "1"], &files);
+    assert_eq!(first, run(&temp_dir, &["--jobs", "1"], &files));
+    assert_eq!(first, run(&temp_dir, &[], &files));
+}
+
+#[test]
+fn jobs_above_1_is_rejected() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--jobs", "4"])
+        .write_stdin("plain\n")
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("invalid value '4' for '--jobs'"));
+}
+
+// EOF
@@ -0,0 +1,97 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--relative` and `--absolute`
+
+use std::{fs, path::PathBuf};
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+const ARROW: &str = "1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n";
+
+/// Create a directory with an arrow in `sub/dir/a.txt`, returning its path
+fn nested_file() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path().join("sub").join("dir");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("a.txt");
+    fs::write(&path, "a → b\n").unwrap();
+    (temp_dir, path)
+}
+
+#[test]
+fn paths_are_reported_as_given_by_default() {
+    let (temp_dir, _) = nested_file();
+    let given = PathBuf::from("sub").join("dir").join("a.txt");
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("-H")
+        .arg(&given)
+        .assert()
+        .code(1)
+        .stdout(format!("{}:{}", given.display(), ARROW));
+}
+
+#[test]
+fn relative_strips_root_prefix() {
+    let (temp_dir, path) = nested_file();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("-H")
+        .arg("--relative")
+        .arg(temp_dir.path().join("sub"))
+        .arg(&path)
+        .assert()
+        .code(1)
+        .stdout(format!(
+            "{}:{}",
+            PathBuf::from("dir").join("a.txt").display(),
+            ARROW
+        ));
+}
+
+#[test]
+fn relative_falls_back_to_absolute_outside_root() {
+    let (temp_dir, path) = nested_file();
+    let other = TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("-H")
+        .arg("--relative")
+        .arg(other.path())
+        .arg(&path)
+        .assert()
+        .code(1)
+        .stdout(format!("{}:{}", path.display(), ARROW));
+}
+
+#[test]
+fn absolute_prefixes_relative_paths() {
+    let (temp_dir, path) = nested_file();
+    let given = PathBuf::from("sub").join("dir").join("a.txt");
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    let output = cmd
+        .current_dir(temp_dir.path())
+        .arg("-H")
+        .arg("--absolute")
+        .arg(&given)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let name = stdout.strip_suffix(&format!(":{}", ARROW)).unwrap();
+    assert!(PathBuf::from(name).is_absolute());
+    assert!(name.ends_with(&given.display().to_string()));
+    assert_eq!(
+        fs::canonicalize(name).unwrap(),
+        fs::canonicalize(path).unwrap()
+    );
+}
+
+// EOF
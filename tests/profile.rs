@@ -0,0 +1,62 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--profile`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn profile_reports_throughput_on_stderr() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--profile", "a.txt"])
+        .env("NOEMOJI_LOG", "off")
+        .assert()
+        .code(1)
+        .stdout("1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n")
+        .stderr(
+            predicate::str::starts_with(
+                "noemoji: profile: 1 file (8 bytes) scanned, 1 violation found, in ",
+            )
+            .and(predicate::str::contains("MB/s")),
+        );
+}
+
+#[test]
+fn profile_does_not_change_exit_code() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a -> b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--profile", "a.txt"])
+        .assert()
+        .success()
+        .stdout("")
+        .stderr(predicate::str::contains("MB/s"));
+}
+
+#[test]
+fn profile_applies_to_json_output() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--profile", "--format", "json", "a.txt"])
+        .assert()
+        .code(1)
+        .stderr(
+            predicate::str::contains("1 violation found").and(predicate::str::contains("MB/s")),
+        );
+}
+
+// EOF
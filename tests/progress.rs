@@ -0,0 +1,44 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for the progress bar shown on large scans
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+/// Create more files than the progress bar threshold, one with a violation
+fn many_files() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    for i in 0..150 {
+        fs::write(temp_dir.path().join(format!("{i:03}.txt")), "clean\n").unwrap();
+    }
+    fs::write(temp_dir.path().join("000.txt"), "a \u{2192} b\n").unwrap();
+    temp_dir
+}
+
+#[test]
+fn json_never_emits_progress() {
+    let temp_dir = many_files();
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .args(["--format", "json", "*.txt"])
+        .assert()
+        .code(1)
+        .stderr("noemoji: 1 violation in 1 file\n");
+}
+
+#[test]
+fn text_omits_progress_when_stderr_is_not_a_terminal() {
+    let temp_dir = many_files();
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .args(["*.txt"])
+        .assert()
+        .code(1)
+        .stderr("");
+}
+
+// EOF
@@ -0,0 +1,68 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--quiet-errors`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn quiet_errors_hides_open_failures_but_exits_with_error() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .env("NOEMOJI_LOG", "off")
+        .args(["--quiet-errors", "missing.txt"])
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr("");
+}
+
+#[test]
+fn quiet_errors_still_reports_violations_in_readable_files() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .env("NOEMOJI_LOG", "off")
+        .args(["--quiet-errors", "missing.txt", "a.txt"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("a.txt:1:3:"))
+        .stderr("");
+}
+
+#[test]
+fn quiet_errors_logs_failures_at_debug_level() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .env("NOEMOJI_LOG", "debug")
+        .args(["--quiet-errors", "missing.txt"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("[debug]").and(predicate::str::contains("missing.txt")));
+}
+
+#[test]
+fn open_failures_are_printed_without_quiet_errors() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .env("NOEMOJI_LOG", "off")
+        .arg("missing.txt")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("noemoji: ").and(predicate::str::contains("missing.txt")));
+}
+
+// EOF
@@ -0,0 +1,107 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for directory arguments and `-r` / `--recursive`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// Create `docs/` with violations in a nested file and a clean one
+fn docs_tree() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let docs = temp_dir.path().join("docs");
+    fs::create_dir_all(docs.join("guide")).unwrap();
+    fs::write(docs.join("b.md"), "clean\n").unwrap();
+    fs::write(docs.join("guide").join("a.md"), "a \u{2192} b\n").unwrap();
+    fs::write(docs.join("a.md"), "\u{2713}\n").unwrap();
+    temp_dir
+}
+
+#[test]
+fn directory_without_recursive_is_a_helpful_error() {
+    let temp_dir = docs_tree();
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .arg("docs")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "docs: is a directory; use -r to recurse",
+        ));
+}
+
+#[test]
+fn recursive_walks_directories_in_name_order() {
+    let temp_dir = docs_tree();
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .args(["-r", "docs"])
+        .assert()
+        .code(1)
+        .stdout(
+            "docs/a.md:1:1: error: prohibited checkmark '\u{2713}' U+2713 CHECK MARK (suggest: '[x]')\n\
+             docs/guide/a.md:1:3: error: prohibited arrow '\u{2192}' U+2192 RIGHTWARDS ARROW (suggest: '->')\n",
+        );
+}
+
+#[test]
+fn recursive_leaves_file_arguments_alone() {
+    let temp_dir = docs_tree();
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .args(["--recursive", "docs/b.md"])
+        .assert()
+        .success();
+}
+
+/// Create `tree/` holding `a.md`, a link to it, a link back to `tree/`
+/// itself, and a broken link
+#[cfg(unix)]
+fn linked_tree() -> TempDir {
+    use std::os::unix::fs::symlink;
+
+    let temp_dir = TempDir::new().unwrap();
+    let tree = temp_dir.path().join("tree");
+    fs::create_dir_all(tree.join("sub")).unwrap();
+    fs::write(tree.join("a.md"), "a \u{2192} b\n").unwrap();
+    symlink(tree.join("a.md"), tree.join("b.md")).unwrap();
+    symlink(&tree, tree.join("sub").join("loop")).unwrap();
+    symlink(tree.join("missing.md"), tree.join("broken.md")).unwrap();
+    temp_dir
+}
+
+#[cfg(unix)]
+#[test]
+fn recursive_skips_symlinks_by_default() {
+    let temp_dir = linked_tree();
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .args(["-r", "--follow-symlinks", "--no-follow-symlinks", "tree"])
+        .assert()
+        .code(1)
+        .stdout(
+            "tree/a.md:1:3: error: prohibited arrow '\u{2192}' U+2192 RIGHTWARDS ARROW (suggest: '->')\n",
+        );
+}
+
+#[cfg(unix)]
+#[test]
+fn follow_symlinks_checks_each_file_once_without_looping() {
+    let temp_dir = linked_tree();
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .args(["-r", "--follow-symlinks", "tree"])
+        .timeout(std::time::Duration::from_secs(10))
+        .assert()
+        .code(1)
+        .stdout(
+            "tree/a.md:1:3: error: prohibited arrow '\u{2192}' U+2192 RIGHTWARDS ARROW (suggest: '->')\n",
+        )
+        .stderr("");
+}
+
+// EOF
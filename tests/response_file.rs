@@ -0,0 +1,73 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `@FILE` response files
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn response_file_supplies_file_arguments() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a \u{2192} b\n").unwrap();
+    fs::write(temp_dir.path().join("b c.txt"), "done \u{2713}\n").unwrap();
+    fs::write(temp_dir.path().join("args.txt"), "a.txt\r\n\nb c.txt\n").unwrap();
+
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .arg("@args.txt")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            "a.txt:1:3: error: prohibited arrow",
+        ))
+        .stdout(predicate::str::contains(
+            "b c.txt:1:6: error: prohibited checkmark",
+        ));
+}
+
+#[test]
+fn response_file_may_contain_options() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "a \u{2192} b\n").unwrap();
+    fs::write(temp_dir.path().join("args.txt"), "--quiet\na.txt\n").unwrap();
+
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .arg("@args.txt")
+        .assert()
+        .code(1)
+        .stdout("");
+}
+
+#[test]
+fn nested_response_file_is_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("args.txt"), "@args.txt\n").unwrap();
+
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .arg("@args.txt")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "response file 'args.txt' cannot include another response file '@args.txt'",
+        ));
+}
+
+#[test]
+fn missing_response_file_is_an_error() {
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .arg("@nonexistent_args_12345.txt")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "cannot read response file 'nonexistent_args_12345.txt'",
+        ));
+}
+
+// EOF
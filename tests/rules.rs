@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for rule toggles and allow/deny lists in .noemoji.toml
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn disabled_punctuation_allows_curly_quotes() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "[rules]\npunctuation = false\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("quote.txt"), "\u{201C}hi\u{201D}\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("quote.txt")
+        .assert()
+        .success()
+        .stdout(predicates::str::is_empty());
+}
+
+#[test]
+fn disabled_punctuation_still_reports_arrows() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "[rules]\npunctuation = false\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("mixed.txt"), "\u{201C}a\u{201D} → b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("mixed.txt")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("1:5: error: prohibited arrow"))
+        .stdout(predicates::str::contains("punctuation").not());
+}
+
+#[test]
+fn allowlisted_char_is_not_reported() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join(".noemoji.toml"), "allow = [\"≤\"]\n").unwrap();
+    fs::write(temp_dir.path().join("bounds.txt"), "a ≤ b ≥ c\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("bounds.txt")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("1:7: error: prohibited math"))
+        .stdout(predicates::str::contains(":1:3:").not());
+}
+
+#[test]
+fn denied_char_is_reported_as_custom() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join(".noemoji.toml"), "deny = [\"·\"]\n").unwrap();
+    fs::write(temp_dir.path().join("dots.txt"), "a·b\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("dots.txt")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains(
+            "1:2: error: prohibited custom character '·' U+00B7 MIDDLE DOT (suggest: remove)",
+        ));
+}
+
+// EOF
@@ -0,0 +1,79 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `[severity]` and `--warnings-as-errors`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+/// Create a project whose config demotes punctuation to a warning
+fn project() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "inherit = false\n\n[severity]\npunctuation = \"warning\"\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("dash.txt"), "a \u{2014} b\n").unwrap();
+    temp_dir
+}
+
+#[test]
+fn warning_only_file_exits_zero() {
+    let temp_dir = project();
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .arg("dash.txt")
+        .assert()
+        .success()
+        .stdout(
+            "1:3: warning: prohibited punctuation '\u{2014}' U+2014 EM DASH \
+             (suggest: '--' for a break in a sentence)\n",
+        );
+}
+
+#[test]
+fn warnings_as_errors_fails_on_warnings() {
+    let temp_dir = project();
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .args(["--warnings-as-errors", "dash.txt"])
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("1:3: warning:"));
+}
+
+#[test]
+fn errors_still_fail_alongside_warnings() {
+    let temp_dir = project();
+    fs::write(
+        temp_dir.path().join("mixed.txt"),
+        "a \u{2014} b \u{2192} c\n",
+    )
+    .unwrap();
+    Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .arg("mixed.txt")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("1:3: warning:"))
+        .stdout(predicates::str::contains("1:7: error:"));
+}
+
+#[test]
+fn json_reports_severity() {
+    let temp_dir = project();
+    let output = Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .args(["--format=json", "dash.txt"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report[0]["violations"][0]["severity"], "warning");
+}
+
+// EOF
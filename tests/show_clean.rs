@@ -0,0 +1,69 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--show-clean`
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+/// Create a directory with a clean and a dirty file
+fn clean_and_dirty() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("clean.txt"), "plain text\n").unwrap();
+    fs::write(temp_dir.path().join("dirty.txt"), "a \u{2192} b\n").unwrap();
+    temp_dir
+}
+
+#[test]
+fn show_clean_prints_clean_line_and_succeeds() {
+    let temp_dir = clean_and_dirty();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--show-clean", "clean.txt"])
+        .assert()
+        .success()
+        .stdout("clean.txt: clean\n");
+}
+
+#[test]
+fn clean_files_are_silent_by_default() {
+    let temp_dir = clean_and_dirty();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("clean.txt")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn show_clean_with_group_lists_every_file() {
+    let temp_dir = clean_and_dirty();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--show-clean", "--group", "clean.txt", "dirty.txt"])
+        .assert()
+        .code(1)
+        .stdout(
+            "== clean.txt ==\n    clean\n\n== dirty.txt ==\n    \
+             1:3: error: prohibited arrow '\u{2192}' U+2192 RIGHTWARDS ARROW (suggest: '->')\n",
+        );
+}
+
+#[test]
+fn show_clean_conflicts_with_sort() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--show-clean", "--sort", "path"])
+        .write_stdin("plain\n")
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("--show-clean"));
+}
+
+// EOF
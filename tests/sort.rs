@@ -0,0 +1,82 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use tempfile::TempDir;
+
+/// Create three files whose violations differ in category and position
+fn write_files(temp_dir: &TempDir) {
+    fs::write(temp_dir.path().join("b.txt"), "x → y\n").unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "ok\n→ 🚀\n").unwrap();
+    fs::write(temp_dir.path().join("c.txt"), "\u{00A0}\n").unwrap();
+}
+
+/// Check the files in reverse name order, returning the location of each violation
+fn stdout_lines(temp_dir: &TempDir, args: &[&str]) -> Vec<String> {
+    let output = Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .args(args)
+        .args(["c.txt", "b.txt", "a.txt"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| line.split(' ').next().unwrap().to_owned())
+        .collect()
+}
+
+#[test]
+fn default_reports_in_input_order() {
+    let temp_dir = TempDir::new().unwrap();
+    write_files(&temp_dir);
+    assert_eq!(
+        stdout_lines(&temp_dir, &[]),
+        ["c.txt:1:1:", "b.txt:1:3:", "a.txt:2:1:", "a.txt:2:3:"]
+    );
+}
+
+#[test]
+fn sort_path_orders_by_name_then_position() {
+    let temp_dir = TempDir::new().unwrap();
+    write_files(&temp_dir);
+    assert_eq!(
+        stdout_lines(&temp_dir, &["--sort", "path"]),
+        ["a.txt:2:1:", "a.txt:2:3:", "b.txt:1:3:", "c.txt:1:1:"]
+    );
+}
+
+#[test]
+fn sort_severity_reports_emoji_first_and_whitespace_last() {
+    let temp_dir = TempDir::new().unwrap();
+    write_files(&temp_dir);
+    assert_eq!(
+        stdout_lines(&temp_dir, &["--sort=severity"]),
+        ["a.txt:2:3:", "a.txt:2:1:", "b.txt:1:3:", "c.txt:1:1:"]
+    );
+}
+
+#[test]
+fn sort_path_orders_json_files() {
+    let temp_dir = TempDir::new().unwrap();
+    write_files(&temp_dir);
+    let output = Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .args(["--format=json", "--sort=path", "c.txt", "b.txt", "a.txt"])
+        .output()
+        .unwrap();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let files: Vec<_> = report
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|file| file["file"].as_str().unwrap())
+        .collect();
+    assert_eq!(files, ["a.txt", "b.txt", "c.txt"]);
+}
+
+// EOF
@@ -0,0 +1,53 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--stats`
+
+use assert_cmd::{Command, cargo};
+
+#[test]
+fn stats_counts_each_character_most_frequent_first() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--stats")
+        .write_stdin("a → b\nc → d → e\nf 🚀\n")
+        .assert()
+        .code(1)
+        .stdout(concat!(
+            "3  arrow  U+2192  →\n",
+            "1  emoji  U+1F680  🚀\n",
+            "4  total\n",
+        ));
+}
+
+#[test]
+fn stats_names_invisible_characters() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--stats")
+        .write_stdin("a\u{00A0}b\n")
+        .assert()
+        .code(1)
+        .stdout("1  whitespace  U+00A0  NO-BREAK SPACE\n1  total\n");
+}
+
+#[test]
+fn stats_clean_input_prints_zero_total() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--stats")
+        .write_stdin("clean\n")
+        .assert()
+        .code(0)
+        .stdout("0  total\n");
+}
+
+#[test]
+fn stats_conflicts_with_json() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--stats", "--format", "json"])
+        .write_stdin("a → b\n")
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("--stats"));
+}
+
+// EOF
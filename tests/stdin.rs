@@ -80,7 +80,7 @@ fn stdin_with_violations() {
     cmd.write_stdin("Hello → world with Unicode arrow!")
         .assert()
         .code(1)
-        .stdout(predicates::str::contains("stdin:1:7:"))
+        .stdout(predicates::str::contains("1:7:"))
         .stdout(predicates::str::contains("→"));
 }
 
@@ -90,7 +90,7 @@ fn violation_output_shows_line_and_column() {
     cmd.write_stdin("line one\nline → two\nline three")
         .assert()
         .code(1)
-        .stdout(predicates::str::contains("stdin:2:6:"))
+        .stdout(predicates::str::contains("2:6:"))
         .stdout(predicates::str::contains("→"));
 }
 
@@ -100,9 +100,33 @@ fn multiple_violations_all_reported() {
     cmd.write_stdin("→ start\nmiddle ←\nend ↑")
         .assert()
         .code(1)
-        .stdout(predicates::str::contains("stdin:1:1:").and(predicates::str::contains("→")))
-        .stdout(predicates::str::contains("stdin:2:8:").and(predicates::str::contains("←")))
-        .stdout(predicates::str::contains("stdin:3:5:").and(predicates::str::contains("↑")));
+        .stdout(predicates::str::contains("1:1:").and(predicates::str::contains("→")))
+        .stdout(predicates::str::contains("2:8:").and(predicates::str::contains("←")))
+        .stdout(predicates::str::contains("3:5:").and(predicates::str::contains("↑")));
+}
+
+#[test]
+fn invisible_violation_is_named_by_code_point() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("hello\u{00A0}world")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("1:6:"))
+        .stdout(predicates::str::contains("U+00A0 NO-BREAK SPACE"));
+}
+
+#[test]
+fn violation_output_names_category_and_suggestion() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("a → b 🚀")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains(
+            "1:3: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')",
+        ))
+        .stdout(predicates::str::contains(
+            "1:7: error: prohibited emoji '🚀' U+1F680 ROCKET (suggest: remove)",
+        ));
 }
 
 #[test]
@@ -0,0 +1,32 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--stdin-filename`
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+
+#[test]
+fn stdin_filename_sets_reported_name() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--stdin-filename", "src/foo.rs"])
+        .write_stdin("fn main() {}\n// a → b\n")
+        .assert()
+        .code(1)
+        .stdout(
+            "src/foo.rs:2:6: error: prohibited arrow '→' U+2192 RIGHTWARDS ARROW (suggest: '->')\n",
+        );
+}
+
+#[test]
+fn stdin_filename_applies_to_json_output() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--stdin-filename", "src/foo.rs", "--format", "json"])
+        .write_stdin("→\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains(r#""file": "src/foo.rs""#));
+}
+
+// EOF
@@ -0,0 +1,89 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for the `[suggestions]` configuration section
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// Create a directory whose config suggests "up" for `↑`, with `↑` and `↓`
+/// in `a.txt`
+fn custom_up() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "[suggestions]\n\"↑\" = \"up\"\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("a.txt"), "go ↑ then ↓\n").unwrap();
+    temp_dir
+}
+
+#[test]
+fn custom_suggestion_appears_in_report() {
+    let temp_dir = custom_up();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("a.txt")
+        .assert()
+        .code(1)
+        .stdout(
+            "1:4: error: prohibited arrow '↑' U+2191 UPWARDS ARROW (suggest: 'up')\n\
+             1:11: error: prohibited arrow '↓' U+2193 DOWNWARDS ARROW (suggest: 'v')\n",
+        );
+}
+
+#[test]
+fn custom_suggestion_is_applied_by_fix() {
+    let temp_dir = custom_up();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--fix", "a.txt"])
+        .assert()
+        .success();
+
+    assert_eq!(
+        fs::read_to_string(temp_dir.path().join("a.txt")).unwrap(),
+        "go up then v\n"
+    );
+}
+
+#[test]
+fn empty_suggestion_is_a_config_error() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "[suggestions]\n\"↑\" = \"\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["--config", ".noemoji.toml"])
+        .write_stdin("go ↑\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("empty suggestion for '↑'"));
+}
+
+#[test]
+fn check_config_lists_suggestions() {
+    let temp_dir = custom_up();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .arg("--check-config")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "[suggestions]\n\"U+2191\" = \"up\"\n",
+        ));
+}
+
+// EOF
@@ -0,0 +1,91 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for the `--summary` report
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// Create a fixture set: two files with violations and two clean files
+fn fixture_dir() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("arrows.md"), "a → b\nc ← d\n").unwrap();
+    fs::write(temp_dir.path().join("mixed.md"), "done ✓ 🚀\n").unwrap();
+    fs::write(temp_dir.path().join("clean1.md"), "plain\n").unwrap();
+    fs::write(temp_dir.path().join("clean2.md"), "text\n").unwrap();
+    temp_dir
+}
+
+#[test]
+fn summary_reports_totals() {
+    let temp_dir = fixture_dir();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "--summary",
+            "arrows.md",
+            "mixed.md",
+            "clean1.md",
+            "clean2.md",
+        ])
+        .assert()
+        .code(1)
+        .stderr(predicates::str::contains(
+            "4 violations in 2 of 4 files (2 clean)",
+        ))
+        .stderr(predicates::str::contains("arrow:").not());
+}
+
+#[test]
+fn summary_with_verbose_breaks_down_by_category() {
+    let temp_dir = fixture_dir();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "--summary",
+            "--verbose",
+            "arrows.md",
+            "mixed.md",
+            "clean1.md",
+        ])
+        .assert()
+        .code(1)
+        .stderr(predicates::str::contains(
+            "4 violations in 2 of 3 files (1 clean)",
+        ))
+        .stderr(predicates::str::contains("arrow: 2"))
+        .stderr(predicates::str::contains("checkmark: 1"))
+        .stderr(predicates::str::contains("emoji: 1"));
+}
+
+#[test]
+fn summary_for_clean_input() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--summary")
+        .write_stdin("clean")
+        .assert()
+        .success()
+        .stderr(predicates::str::contains(
+            "0 violations in 0 of 1 file (1 clean)",
+        ));
+}
+
+#[test]
+fn no_summary_by_default() {
+    let temp_dir = fixture_dir();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .args(["arrows.md", "clean1.md"])
+        .assert()
+        .code(1)
+        .stderr("");
+}
+
+// EOF
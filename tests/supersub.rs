@@ -0,0 +1,87 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for superscripts, subscripts, and vulgar fractions
+
+use std::fs;
+
+use assert_cmd::{Command, cargo};
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn superscripts_and_fractions_are_reported() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.write_stdin("x² ½ y\n").assert().code(1).stdout(
+        "1:2: error: prohibited superscript or subscript '²' U+00B2 SUPERSCRIPT TWO (suggest: '^2')\n\
+         1:4: error: prohibited vulgar fraction '½' U+00BD VULGAR FRACTION ONE HALF (suggest: '1/2')\n",
+    );
+}
+
+#[test]
+fn fix_rewrites_subscripts_and_fractions() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("notes.txt");
+    fs::write(&path, "H₂O, aⁿ, ¾ cup\n").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.arg("--fix").arg(&path).assert().success().stdout("");
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "H_2O, a^n, 3/4 cup\n");
+}
+
+#[test]
+fn rules_can_be_disabled_separately() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "inherit = false\n\n[rules]\nfractions = false\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .write_stdin("x² ½ y\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("'²'").and(predicate::str::contains("'½'").not()));
+}
+
+#[test]
+fn severity_can_be_lowered() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".noemoji.toml"),
+        "inherit = false\n\n[severity]\nsupersub = \"warning\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.current_dir(temp_dir.path())
+        .write_stdin("x²\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1:2: warning: "));
+}
+
+#[test]
+fn only_selects_fractions() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--only", "fractions"])
+        .write_stdin("x² ½ y\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("'½'").and(predicate::str::contains("'²'").not()));
+}
+
+#[test]
+fn explain_supersub() {
+    let mut cmd = Command::new(cargo::cargo_bin!("noemoji"));
+    cmd.args(["--explain", "supersub"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("`x^2`"));
+}
+
+// EOF
@@ -0,0 +1,97 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for `--watch`
+
+use std::{
+    fs,
+    io::{BufRead, BufReader},
+    process::{Command, Stdio},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use assert_cmd::cargo;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn modifying_a_watched_file_triggers_a_recheck() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("notes.txt");
+    fs::write(&path, "clean\n").unwrap();
+
+    let mut child = Command::new(cargo::cargo_bin!("noemoji"))
+        .current_dir(temp_dir.path())
+        .args(["--watch", "notes.txt"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let (sender, lines) = mpsc::channel();
+    let stderr = child.stderr.take().unwrap();
+    let stdout = child.stdout.take().unwrap();
+    let stderr_sender = sender.clone();
+    thread::spawn(move || {
+        for line in BufReader::new(stderr).lines() {
+            let _ = stderr_sender.send(line.unwrap());
+        }
+    });
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines() {
+            let _ = sender.send(line.unwrap());
+        }
+    });
+
+    // Give the watcher time to start after the clean first pass
+    thread::sleep(Duration::from_millis(500));
+    fs::write(&path, "a \u{2192} b\n").unwrap();
+
+    let mut seen = Vec::new();
+    while let Ok(line) = lines.recv_timeout(Duration::from_secs(10)) {
+        let done = line.starts_with("1:3: error: prohibited arrow");
+        seen.push(line);
+        if done {
+            break;
+        }
+    }
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    assert!(
+        seen.iter()
+            .any(|line| line.contains("rechecking notes.txt")),
+        "{seen:?}"
+    );
+    assert!(
+        seen.iter()
+            .any(|line| line.starts_with("1:3: error: prohibited arrow")),
+        "{seen:?}"
+    );
+}
+
+#[test]
+fn watch_rejects_stdin() {
+    assert_cmd::Command::new(cargo::cargo_bin!("noemoji"))
+        .arg("--watch")
+        .write_stdin("a \u{2192} b\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "--watch cannot be used with stdin",
+        ));
+}
+
+#[test]
+fn watch_conflicts_with_fix() {
+    assert_cmd::Command::new(cargo::cargo_bin!("noemoji"))
+        .args(["--watch", "--fix", "notes.txt"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("cannot be used together"));
+}
+
+// EOF